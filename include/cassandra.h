@@ -144,6 +144,59 @@ typedef struct CassUuid_ {
   cass_uint64_t clock_seq_and_node;
 } CassUuid;
 
+/**
+ * A point in time broken down into the calendar date (in the proleptic
+ * Gregorian calendar) and the time of the day, in UTC.
+ *
+ * <b>Note:</b> This type is a Scylla extension.
+ *
+ * @see cass_date_time_from_timestamp()
+ * @see cass_date_time_from_date_and_time()
+ */
+typedef struct CassDateTime_ {
+  /**
+   * Year, where 0 is 1 BC, -1 is 2 BC and so on.
+   */
+  cass_int64_t year;
+  /**
+   * Month of the year, 1-12.
+   */
+  cass_uint8_t month;
+  /**
+   * Day of the month, 1-31.
+   */
+  cass_uint8_t day;
+  /**
+   * Hour of the day, 0-23.
+   */
+  cass_uint8_t hour;
+  /**
+   * Minute of the hour, 0-59.
+   */
+  cass_uint8_t minute;
+  /**
+   * Second of the minute, 0-59.
+   */
+  cass_uint8_t second;
+  /**
+   * Nanosecond of the second, 0-999999999.
+   */
+  cass_uint32_t nanosecond;
+} CassDateTime;
+
+/**
+ * A "duration" value. All the components must have the same sign.
+ *
+ * <b>Note:</b> This type is a Scylla extension.
+ *
+ * @see cass_timestamp_add_duration()
+ */
+typedef struct CassDuration_ {
+  cass_int32_t months;
+  cass_int32_t days;
+  cass_int64_t nanos;
+} CassDuration;
+
 /**
  * A cluster object describes the configuration of the Cassandra cluster and is used
  * to construct a session instance. Unlike other DataStax drivers the cluster object
@@ -199,6 +252,31 @@ typedef struct CassBatch_ CassBatch;
  */
 typedef struct CassFuture_ CassFuture;
 
+/**
+ * A queue of completed futures, with a file descriptor which is readable
+ * as long as the queue is not empty. It allows integrating the driver into
+ * event loops.
+ *
+ * @struct CassCompletionQueue
+ */
+typedef struct CassCompletionQueue_ CassCompletionQueue;
+
+/**
+ * The outcome of a single attempt to reach a host, made on behalf
+ * of a connection or a request.
+ *
+ * @struct CassErrorDetail
+ */
+typedef struct CassErrorDetail_ CassErrorDetail;
+
+/**
+ * A single attempt of a request's execution history, along with the
+ * speculative fiber it belongs to and the retry decision made upon its failure.
+ *
+ * @struct CassExecutionAttempt
+ */
+typedef struct CassExecutionAttempt_ CassExecutionAttempt;
+
 /**
  * A statement that has been prepared cluster-side (It has been pre-parsed
  * and cached).
@@ -366,6 +444,13 @@ typedef struct CassColumnMeta_ CassColumnMeta;
  */
 typedef struct CassIndexMeta_ CassIndexMeta;
 
+/**
+ * Schema change event
+ *
+ * @struct CassSchemaChangeEvent
+ */
+typedef struct CassSchemaChangeEvent_ CassSchemaChangeEvent;
+
 /**
  * A UUID generator object.
  *
@@ -510,6 +595,14 @@ typedef enum CassWriteType_ {
 #define CASS_WRITE_TYPE_MAP CASS_WRITE_TYPE_MAPPING /* Deprecated */
 /* @endcond */
 
+typedef enum CassRetryDecision_ {
+  CASS_RETRY_DECISION_NONE,
+  CASS_RETRY_DECISION_RETRY_SAME_NODE,
+  CASS_RETRY_DECISION_RETRY_NEXT_NODE,
+  CASS_RETRY_DECISION_DONT_RETRY,
+  CASS_RETRY_DECISION_IGNORE_WRITE_ERROR
+} CassRetryDecision;
+
 typedef enum CassColumnType_ {
   CASS_COLUMN_TYPE_REGULAR,
   CASS_COLUMN_TYPE_PARTITION_KEY,
@@ -525,6 +618,13 @@ typedef enum CassIndexType_ {
   CASS_INDEX_TYPE_COMPOSITES
 } CassIndexType;
 
+typedef enum CassReplicationStrategy_ {
+  CASS_REPLICATION_STRATEGY_SIMPLE,
+  CASS_REPLICATION_STRATEGY_NETWORK_TOPOLOGY,
+  CASS_REPLICATION_STRATEGY_LOCAL,
+  CASS_REPLICATION_STRATEGY_OTHER
+} CassReplicationStrategy;
+
 #define CASS_VALUE_TYPE_MAPPING(XX) \
   XX(CASS_VALUE_TYPE_CUSTOM,  0x0000, "", "") \
   XX(CASS_VALUE_TYPE_ASCII,  0x0001, "ascii", "org.apache.cassandra.db.marshal.AsciiType") \
@@ -685,6 +785,8 @@ typedef enum  CassErrorSource_ {
   XX(CASS_ERROR_SOURCE_LIB, CASS_ERROR_LIB_NO_CUSTOM_PAYLOAD, 33, "No custom payload") \
   XX(CASS_ERROR_SOURCE_LIB, CASS_ERROR_LIB_EXECUTION_PROFILE_INVALID, 34, "Invalid execution profile specified") \
   XX(CASS_ERROR_SOURCE_LIB, CASS_ERROR_LIB_NO_TRACING_ID, 35, "No tracing ID") \
+  XX(CASS_ERROR_SOURCE_LIB, CASS_ERROR_LIB_REQUEST_CANCELLED, 36, "Request cancelled") \
+  XX(CASS_ERROR_SOURCE_LIB, CASS_ERROR_LIB_DESERIALIZATION_TYPE_MISMATCH, 37, "Value does not match its column type") \
   XX(CASS_ERROR_SOURCE_SERVER, CASS_ERROR_SERVER_SERVER_ERROR, 0x0000, "Server error") \
   XX(CASS_ERROR_SOURCE_SERVER, CASS_ERROR_SERVER_PROTOCOL_ERROR, 0x000A, "Protocol error") \
   XX(CASS_ERROR_SOURCE_SERVER, CASS_ERROR_SERVER_BAD_CREDENTIALS, 0x0100, "Bad credentials") \
@@ -913,6 +1015,50 @@ typedef void(*CassHostListenerCallback)(CassHostListenerEvent event,
                                         const CassInet address,
                                         void* data);
 
+typedef enum CassSchemaChangeType_ {
+  CASS_SCHEMA_CHANGE_TYPE_CREATED,
+  CASS_SCHEMA_CHANGE_TYPE_UPDATED,
+  CASS_SCHEMA_CHANGE_TYPE_DROPPED
+} CassSchemaChangeType;
+
+typedef enum CassSchemaChangeTarget_ {
+  CASS_SCHEMA_CHANGE_TARGET_KEYSPACE,
+  CASS_SCHEMA_CHANGE_TARGET_TABLE,
+  CASS_SCHEMA_CHANGE_TARGET_TYPE,
+  CASS_SCHEMA_CHANGE_TARGET_FUNCTION,
+  CASS_SCHEMA_CHANGE_TARGET_AGGREGATE
+} CassSchemaChangeTarget;
+
+/**
+ * A callback used to notify about schema changes in the cluster.
+ *
+ * The event is only valid for the duration of the callback.
+ *
+ * @param[in] event
+ * @param[in] data
+ * @see cass_cluster_set_schema_change_listener_callback()
+ */
+typedef void(*CassSchemaChangeCallback)(const CassSchemaChangeEvent* event,
+                                        void* data);
+
+/**
+ * A future callback invocation, handed over to a callback executor.
+ *
+ * @struct CassCallbackTask
+ */
+typedef struct CassCallbackTask_ CassCallbackTask;
+
+/**
+ * A callback executor. It's responsible for running the task, exactly once,
+ * with cass_callback_task_run().
+ *
+ * @param[in] task
+ * @param[in] data
+ * @see cass_cluster_set_callback_executor()
+ */
+typedef void(*CassCallbackExecutor)(CassCallbackTask* task,
+                                    void* data);
+
 typedef enum CassCompressionType_ {
   CASS_COMPRESSION_LZ4,
   CASS_COMPRESSION_SNAPPY,
@@ -2352,6 +2498,28 @@ CASS_EXPORT void
 cass_cluster_set_token_aware_routing_shuffle_replicas(CassCluster* cluster,
                                                       cass_bool_t enabled);
 
+/**
+ * Enables/Disables LWT routing optimization. When enabled, statements
+ * the server reports as lightweight transactions at prepare time are routed
+ * to their replicas in a deterministic order, primary replica first, regardless
+ * of replica shuffling. This avoids Paxos contention between coordinators.
+ *
+ * <b>Note:</b> This function is a Scylla extension. Token-aware routing must
+ * be enabled for the setting to be applicable.
+ *
+ * <b>Default:</b> cass_true (enabled).
+ *
+ * @public @memberof CassCluster
+ *
+ * @param[in] cluster
+ * @param[in] enabled
+ *
+ * @see cass_prepared_is_lwt()
+ */
+CASS_EXPORT void
+cass_cluster_set_lwt_routing_optimization(CassCluster* cluster,
+                                          cass_bool_t enabled);
+
 /**
  * Configures the cluster to use latency-aware request routing or not.
  *
@@ -2888,6 +3056,93 @@ cass_cluster_set_host_listener_callback(CassCluster* cluster,
                                         CassHostListenerCallback callback,
                                         void* data);
 
+/**
+ * Sets a callback for handling schema changes in the cluster.
+ *
+ * The callback is invoked once the driver's schema metadata has been
+ * refreshed, so cass_session_get_schema_meta() called from within
+ * the callback already reflects the change. Events are derived by comparing
+ * consecutive metadata snapshots, so several changes applied in quick
+ * succession may be reported together, and materialized views are reported
+ * as tables.
+ *
+ * The callback is invoked on a driver thread, which may block (e.g. to call
+ * cass_session_get_schema_meta()), once per connected session. The events
+ * of a session are reported one at a time.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassCluster
+ *
+ * @param[in] cluster
+ * @param[in] callback NULL to unset the callback.
+ * @param[in] data
+ * @return CASS_OK if successful, otherwise an error occurred
+ */
+CASS_EXPORT CassError
+cass_cluster_set_schema_change_listener_callback(CassCluster* cluster,
+                                                 CassSchemaChangeCallback callback,
+                                                 void* data);
+
+/**
+ * Sets the number of threads of a dedicated pool, which future callbacks
+ * are dispatched to. This prevents slow or blocking callbacks from stalling
+ * the driver's I/O threads.
+ *
+ * <b>Note:</b> This function is a Scylla extension. Callbacks set on futures,
+ * which are already set, are invoked on the calling thread.
+ *
+ * <b>Default:</b> 0 (callbacks are invoked on the driver's I/O threads)
+ *
+ * @public @memberof CassCluster
+ *
+ * @param[in] cluster
+ * @param[in] num_threads 0 restores the default behaviour.
+ * @return CASS_OK if successful, otherwise an error occurred
+ *
+ * @see cass_cluster_set_callback_executor()
+ */
+CASS_EXPORT CassError
+cass_cluster_set_callback_thread_pool_size(CassCluster* cluster,
+                                           unsigned num_threads);
+
+/**
+ * Sets an executor, which future callbacks are dispatched to. The executor
+ * is invoked on the driver's I/O threads, and should only schedule the task,
+ * e.g. on the application's event loop.
+ *
+ * <b>Note:</b> This function is a Scylla extension. Callbacks set on futures,
+ * which are already set, are invoked on the calling thread.
+ *
+ * @public @memberof CassCluster
+ *
+ * @param[in] cluster
+ * @param[in] executor NULL restores the default behaviour.
+ * @param[in] data An opaque data object passed to the executor.
+ * @return CASS_OK if successful, otherwise an error occurred
+ *
+ * @see cass_callback_task_run()
+ * @see cass_cluster_set_callback_thread_pool_size()
+ */
+CASS_EXPORT CassError
+cass_cluster_set_callback_executor(CassCluster* cluster,
+                                   CassCallbackExecutor executor,
+                                   void* data);
+
+/**
+ * Runs the future callback, and frees the task.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassCallbackTask
+ *
+ * @param[in] task
+ *
+ * @see cass_cluster_set_callback_executor()
+ */
+CASS_EXPORT void
+cass_callback_task_run(CassCallbackTask* task);
+
 /**
  * Sets the secure connection bundle path for processing DBaaS credentials.
  *
@@ -3229,6 +3484,24 @@ CASS_EXPORT CassFuture*
 cass_session_execute(CassSession* session,
                      const CassStatement* statement);
 
+/**
+ * Waits for all the nodes in the cluster to agree on the schema version.
+ * The wait is bounded by the max schema wait time.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassSession
+ *
+ * @param[in] session
+ * @return A future that must be freed. It resolves with the agreed
+ * schema version, or with an error if agreement was not reached in time.
+ *
+ * @see cass_future_schema_version()
+ * @see cass_cluster_set_max_schema_wait_time()
+ */
+CASS_EXPORT CassFuture*
+cass_session_wait_for_schema_agreement(CassSession* session);
+
 /**
  * Execute a batch statement.
  *
@@ -3337,6 +3610,24 @@ cass_schema_meta_snapshot_version(const CassSchemaMeta* schema_meta);
 CASS_EXPORT CassVersion
 cass_schema_meta_version(const CassSchemaMeta* schema_meta);
 
+/**
+ * Gets the schema version UUID reported by the node the metadata
+ * snapshot was fetched from. Comparing it with a previously seen
+ * version is a cheap way to detect schema changes.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassSchemaMeta
+ *
+ * @param[in] schema_meta
+ * @param[out] schema_version
+ * @return CASS_OK if successful, CASS_ERROR_LIB_NULL_VALUE if the schema
+ * version is unknown.
+ */
+CASS_EXPORT CassError
+cass_schema_meta_schema_version(const CassSchemaMeta* schema_meta,
+                                CassUuid* schema_version);
+
 /**
  * Gets the keyspace metadata for the provided keyspace name.
  *
@@ -3395,6 +3686,165 @@ cass_keyspace_meta_name(const CassKeyspaceMeta* keyspace_meta,
 CASS_EXPORT cass_bool_t
 cass_keyspace_meta_is_virtual(const CassKeyspaceMeta* keyspace_meta);
 
+/**
+ * Gets the replication strategy of the keyspace. Virtual keyspaces are
+ * reported as CASS_REPLICATION_STRATEGY_LOCAL.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassKeyspaceMeta
+ *
+ * @param[in] keyspace_meta
+ * @return The replication strategy.
+ */
+CASS_EXPORT CassReplicationStrategy
+cass_keyspace_meta_replication_strategy(const CassKeyspaceMeta* keyspace_meta);
+
+/**
+ * Gets the class name of the keyspace's replication strategy, e.g.
+ * "org.apache.cassandra.locator.NetworkTopologyStrategy".
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassKeyspaceMeta
+ *
+ * @param[in] keyspace_meta
+ * @param[out] class_name
+ * @param[out] class_name_length
+ */
+CASS_EXPORT void
+cass_keyspace_meta_replication_strategy_class(const CassKeyspaceMeta* keyspace_meta,
+                                              const char** class_name,
+                                              size_t* class_name_length);
+
+/**
+ * Gets the total number of replicas of each partition of the keyspace.
+ * For CASS_REPLICATION_STRATEGY_NETWORK_TOPOLOGY it's the sum of
+ * the replication factors of all datacenters.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassKeyspaceMeta
+ *
+ * @param[in] keyspace_meta
+ * @return The replication factor. 0 if it's unknown, e.g. for
+ * a CASS_REPLICATION_STRATEGY_OTHER strategy without a "replication_factor"
+ * option.
+ */
+CASS_EXPORT cass_uint32_t
+cass_keyspace_meta_replication_factor(const CassKeyspaceMeta* keyspace_meta);
+
+/**
+ * Gets the number of datacenters with a replication factor configured.
+ * It's non-zero only for CASS_REPLICATION_STRATEGY_NETWORK_TOPOLOGY.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassKeyspaceMeta
+ *
+ * @param[in] keyspace_meta
+ * @return The number of datacenters.
+ */
+CASS_EXPORT size_t
+cass_keyspace_meta_datacenter_count(const CassKeyspaceMeta* keyspace_meta);
+
+/**
+ * Gets the name and the replication factor of the datacenter at the
+ * specified index. Datacenters are ordered by name.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassKeyspaceMeta
+ *
+ * @param[in] keyspace_meta
+ * @param[in] index
+ * @param[out] name
+ * @param[out] name_length
+ * @param[out] replication_factor
+ * @return CASS_OK if successful, otherwise an error occurred
+ */
+CASS_EXPORT CassError
+cass_keyspace_meta_datacenter(const CassKeyspaceMeta* keyspace_meta,
+                              size_t index,
+                              const char** name,
+                              size_t* name_length,
+                              cass_uint32_t* replication_factor);
+
+/**
+ * Gets the replication factor of the datacenter with the provided name.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassKeyspaceMeta
+ *
+ * @param[in] keyspace_meta
+ * @param[in] datacenter
+ * @return The replication factor. 0 if the datacenter has no replicas.
+ */
+CASS_EXPORT cass_uint32_t
+cass_keyspace_meta_datacenter_replication_factor(const CassKeyspaceMeta* keyspace_meta,
+                                                 const char* datacenter);
+
+/**
+ * Same as cass_keyspace_meta_datacenter_replication_factor(), but with
+ * lengths for string parameters.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassKeyspaceMeta
+ *
+ * @param[in] keyspace_meta
+ * @param[in] datacenter
+ * @param[in] datacenter_length
+ * @return same as cass_keyspace_meta_datacenter_replication_factor()
+ *
+ * @see cass_keyspace_meta_datacenter_replication_factor()
+ */
+CASS_EXPORT cass_uint32_t
+cass_keyspace_meta_datacenter_replication_factor_n(const CassKeyspaceMeta* keyspace_meta,
+                                                   const char* datacenter,
+                                                   size_t datacenter_length);
+
+/**
+ * Determine if writes to the keyspace go through the commit log.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassKeyspaceMeta
+ *
+ * @param[in] keyspace_meta
+ * @return cass_true if durable writes are enabled, otherwise cass_false
+ */
+CASS_EXPORT cass_bool_t
+cass_keyspace_meta_durable_writes(const CassKeyspaceMeta* keyspace_meta);
+
+/**
+ * Determine if the keyspace uses tablets to distribute its data.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassKeyspaceMeta
+ *
+ * @param[in] keyspace_meta
+ * @return cass_true if the keyspace uses tablets, otherwise cass_false
+ */
+CASS_EXPORT cass_bool_t
+cass_keyspace_meta_tablets_enabled(const CassKeyspaceMeta* keyspace_meta);
+
+/**
+ * Gets the initial number of tablets of the keyspace's tables.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassKeyspaceMeta
+ *
+ * @param[in] keyspace_meta
+ * @return The initial tablet count. 0 if it's chosen automatically by
+ * the cluster, or if the keyspace doesn't use tablets.
+ */
+CASS_EXPORT cass_uint32_t
+cass_keyspace_meta_initial_tablets(const CassKeyspaceMeta* keyspace_meta);
+
 /**
  * Gets the table metadata for the provided table name.
  *
@@ -4216,6 +4666,10 @@ cass_index_meta_type(const CassIndexMeta* index_meta);
 /**
  * Gets the target of the index.
  *
+ * <b>Note:</b> For Scylla's local secondary indexes the target describes
+ * both the partition key and the indexed column,
+ * e.g. {"pk":["p"],"ck":["v"]}.
+ *
  * @public @memberof CassIndexMeta
  *
  * @param[in] index_meta
@@ -4633,23 +5087,211 @@ cass_aggregate_meta_field_by_name_n(const CassAggregateMeta* aggregate_meta,
                                     const char* name,
                                     size_t name_length);
 
-/***********************************************************************************
+/**
+ * Renders the keyspace, along with its user types, functions, aggregates,
+ * tables, indexes and materialized views, as CQL DDL statements into
+ * a caller-supplied buffer, in the format of cqlsh's DESCRIBE. The output is
+ * null-terminated.
  *
- * SSL
+ * User types are ordered so that each type is preceded by the types it uses.
+ * Virtual keyspaces and tables are rendered inside a comment, for reference only.
  *
- ************************************************************************************/
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassKeyspaceMeta
+ *
+ * @param[in] keyspace_meta
+ * @param[out] output Buffer for the CQL. May be NULL to only query the length.
+ * @param[in] output_size Size of the output buffer, including the terminating null byte.
+ * @param[out] cql_length Length of the CQL, excluding the terminating null
+ * byte. It's set even if the buffer is too small. May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the output
+ * buffer is NULL or too small.
+ */
+CASS_EXPORT CassError
+cass_keyspace_meta_as_cql(const CassKeyspaceMeta* keyspace_meta,
+                          char* output,
+                          size_t output_size,
+                          size_t* cql_length);
 
 /**
- * Creates a new SSL context.
+ * Renders the table, along with its indexes and materialized views, as CQL
+ * DDL statements into a caller-supplied buffer, in the format of cqlsh's
+ * DESCRIBE. The output is null-terminated.
  *
- * @public @memberof CassSsl
+ * <b>Note:</b> This function is a Scylla extension.
  *
- * @return Returns a SSL context that must be freed.
+ * @public @memberof CassTableMeta
  *
- * @see cass_ssl_free()
+ * @param[in] table_meta
+ * @param[out] output Buffer for the CQL. May be NULL to only query the length.
+ * @param[in] output_size Size of the output buffer, including the terminating null byte.
+ * @param[out] cql_length Length of the CQL, excluding the terminating null
+ * byte. It's set even if the buffer is too small. May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the output
+ * buffer is NULL or too small.
  */
-CASS_EXPORT CassSsl*
-cass_ssl_new();
+CASS_EXPORT CassError
+cass_table_meta_as_cql(const CassTableMeta* table_meta,
+                       char* output,
+                       size_t output_size,
+                       size_t* cql_length);
+
+/**
+ * Renders the materialized view as CQL DDL statements into a caller-supplied
+ * buffer, in the format of cqlsh's DESCRIBE. The output is null-terminated.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassMaterializedViewMeta
+ *
+ * @param[in] view_meta
+ * @param[out] output Buffer for the CQL. May be NULL to only query the length.
+ * @param[in] output_size Size of the output buffer, including the terminating null byte.
+ * @param[out] cql_length Length of the CQL, excluding the terminating null
+ * byte. It's set even if the buffer is too small. May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the output
+ * buffer is NULL or too small.
+ */
+CASS_EXPORT CassError
+cass_materialized_view_meta_as_cql(const CassMaterializedViewMeta* view_meta,
+                                   char* output,
+                                   size_t output_size,
+                                   size_t* cql_length);
+
+/**
+ * Renders the user defined type as CQL DDL statements into a caller-supplied
+ * buffer, in the format of cqlsh's DESCRIBE. The output is null-terminated.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassDataType
+ *
+ * @param[in] data_type
+ * @param[out] output Buffer for the CQL. May be NULL to only query the length.
+ * @param[in] output_size Size of the output buffer, including the terminating null byte.
+ * @param[out] cql_length Length of the CQL, excluding the terminating null
+ * byte. It's set even if the buffer is too small. May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_INVALID_VALUE_TYPE if the data
+ * type is not a user defined type, CASS_ERROR_LIB_BAD_PARAMS if the output
+ * buffer is NULL or too small.
+ */
+CASS_EXPORT CassError
+cass_user_type_as_cql(const CassDataType* data_type,
+                      char* output,
+                      size_t output_size,
+                      size_t* cql_length);
+
+/**
+ * Gets the kind of the schema change.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassSchemaChangeEvent
+ *
+ * @param[in] event
+ * @return The kind of the schema change.
+ */
+CASS_EXPORT CassSchemaChangeType
+cass_schema_change_event_type(const CassSchemaChangeEvent* event);
+
+/**
+ * Gets the kind of the schema object affected by the change.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassSchemaChangeEvent
+ *
+ * @param[in] event
+ * @return The kind of the changed schema object.
+ */
+CASS_EXPORT CassSchemaChangeTarget
+cass_schema_change_event_target(const CassSchemaChangeEvent* event);
+
+/**
+ * Gets the name of the keyspace affected by the change.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassSchemaChangeEvent
+ *
+ * @param[in] event
+ * @param[out] keyspace
+ * @param[out] keyspace_length
+ */
+CASS_EXPORT void
+cass_schema_change_event_keyspace(const CassSchemaChangeEvent* event,
+                                  const char** keyspace,
+                                  size_t* keyspace_length);
+
+/**
+ * Gets the name of the changed table, type, function or aggregate.
+ * The name is empty for keyspace changes.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassSchemaChangeEvent
+ *
+ * @param[in] event
+ * @param[out] name
+ * @param[out] name_length
+ */
+CASS_EXPORT void
+cass_schema_change_event_name(const CassSchemaChangeEvent* event,
+                              const char** name,
+                              size_t* name_length);
+
+/**
+ * Gets the number of argument types of the changed function or aggregate.
+ * Returns 0 for other targets.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassSchemaChangeEvent
+ *
+ * @param[in] event
+ * @return The number of argument types.
+ */
+CASS_EXPORT size_t
+cass_schema_change_event_argument_count(const CassSchemaChangeEvent* event);
+
+/**
+ * Gets the CQL name of the argument type at the specified index
+ * of the changed function or aggregate.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassSchemaChangeEvent
+ *
+ * @param[in] event
+ * @param[in] index
+ * @param[out] type
+ * @param[out] type_length
+ * @return CASS_OK if successful, otherwise an error occurred
+ */
+CASS_EXPORT CassError
+cass_schema_change_event_argument_type(const CassSchemaChangeEvent* event,
+                                       size_t index,
+                                       const char** type,
+                                       size_t* type_length);
+
+/***********************************************************************************
+ *
+ * SSL
+ *
+ ************************************************************************************/
+
+/**
+ * Creates a new SSL context.
+ *
+ * @public @memberof CassSsl
+ *
+ * @return Returns a SSL context that must be freed.
+ *
+ * @see cass_ssl_free()
+ */
+CASS_EXPORT CassSsl*
+cass_ssl_new();
 
 /**
  * Creates a new SSL context <b>without</b> initializing the underlying library
@@ -5016,6 +5658,159 @@ CASS_EXPORT cass_bool_t
 cass_future_wait_timed(CassFuture* future,
                        cass_duration_t timeout_us);
 
+/**
+ * Cancels the request associated with the future. The future is set with
+ * the CASS_ERROR_LIB_REQUEST_CANCELLED error, and its callback (if any)
+ * is invoked. Cancelling a future which is already set has no effect.
+ *
+ * <b>Note:</b> This function is a Scylla extension. A cancelled request
+ * may have already been sent to, and applied by the server.
+ *
+ * @public @memberof CassFuture
+ *
+ * @param[in] future
+ * @return cass_true if the future was cancelled, cass_false if it was
+ * already set.
+ */
+CASS_EXPORT cass_bool_t
+cass_future_cancel(CassFuture* future);
+
+/**
+ * Waits for any of the futures to be set or timeout.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassFuture
+ *
+ * @param[in] futures
+ * @param[in] futures_count
+ * @param[in] timeout_us wait time in microseconds
+ * @param[out] index The index of a future which is set.
+ * @return false if returned due to timeout
+ */
+CASS_EXPORT cass_bool_t
+cass_future_wait_any(CassFuture* const* futures,
+                     size_t futures_count,
+                     cass_duration_t timeout_us,
+                     size_t* index);
+
+/**
+ * Waits for all the futures to be set or timeout.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassFuture
+ *
+ * @param[in] futures
+ * @param[in] futures_count
+ * @param[in] timeout_us wait time in microseconds
+ * @return false if returned due to timeout
+ */
+CASS_EXPORT cass_bool_t
+cass_future_wait_all(CassFuture* const* futures,
+                     size_t futures_count,
+                     cass_duration_t timeout_us);
+
+/**
+ * Creates a future, which is set once all the given futures are set.
+ * It completes successfully regardless of the results of the given
+ * futures, which need to be inspected separately. The given futures
+ * may be freed before the returned one is set.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassFuture
+ *
+ * @param[in] futures
+ * @param[in] futures_count
+ * @return A future that must be freed.
+ */
+CASS_EXPORT CassFuture*
+cass_future_all(CassFuture* const* futures,
+                size_t futures_count);
+
+/***********************************************************************************
+ *
+ * Completion queue
+ *
+ ***********************************************************************************/
+
+/**
+ * Creates a new completion queue.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassCompletionQueue
+ *
+ * @return Returns a completion queue that must be freed. NULL is returned
+ * if the file descriptor could not be created.
+ *
+ * @see cass_completion_queue_free()
+ */
+CASS_EXPORT CassCompletionQueue*
+cass_completion_queue_new();
+
+/**
+ * Frees a completion queue instance. Futures attached to the queue, which
+ * are not set yet, won't be queued anymore.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassCompletionQueue
+ *
+ * @param[in] queue
+ */
+CASS_EXPORT void
+cass_completion_queue_free(CassCompletionQueue* queue);
+
+/**
+ * Gets the file descriptor of the queue. It becomes readable when
+ * an attached future is set, and stays readable as long as the queue
+ * is not empty. It's an eventfd on Linux, and the read end of a pipe
+ * on other platforms. Do not read from, nor close the descriptor.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassCompletionQueue
+ *
+ * @param[in] queue
+ * @return The file descriptor, bound to the lifetime of the queue.
+ */
+CASS_EXPORT int
+cass_completion_queue_fd(const CassCompletionQueue* queue);
+
+/**
+ * Attaches the future to the queue. The future is pushed to the queue
+ * once it's set, or right away if it's already set. A future can be
+ * attached to a single queue only.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassCompletionQueue
+ *
+ * @param[in] queue
+ * @param[in] future
+ * @return CASS_OK if successful, CASS_ERROR_LIB_CALLBACK_ALREADY_SET if
+ * the future is already attached to a queue.
+ */
+CASS_EXPORT CassError
+cass_completion_queue_attach(const CassCompletionQueue* queue,
+                             CassFuture* future);
+
+/**
+ * Dequeues a set future. This function doesn't block.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassCompletionQueue
+ *
+ * @param[in] queue
+ * @return A future that must be freed, independently from the future
+ * passed to cass_completion_queue_attach(). NULL if the queue is empty.
+ */
+CASS_EXPORT CassFuture*
+cass_completion_queue_pop(const CassCompletionQueue* queue);
+
 /**
  * Gets the result of a successful future. If the future is not ready this method will
  * wait for the future to be set.
@@ -5107,118 +5902,341 @@ cass_future_tracing_id(CassFuture* future,
                        CassUuid* tracing_id);
 
 /**
- * Gets a the number of custom payload items from a response future. If the future is not
- * ready this method will wait for the future to be set.
+ * Gets the agreed schema version. This function will block until
+ * the future is set.
  *
- * @cassandra{2.2+}
+ * <b>Note:</b> This function is a Scylla extension.
  *
  * @public @memberof CassFuture
  *
- * @param[in] future
- * @return the number of custom payload items.
+ * @param[in] future A future returned by cass_session_wait_for_schema_agreement()
+ * @param[out] schema_version
+ * @return CASS_OK if successful, otherwise an error occurred.
+ *
+ * @see cass_session_wait_for_schema_agreement()
  */
-CASS_EXPORT size_t
-cass_future_custom_payload_item_count(CassFuture* future);
+CASS_EXPORT CassError
+cass_future_schema_version(CassFuture* future,
+                           CassUuid* schema_version);
 
 /**
- * Gets a custom payload item from a response future at the specified index. If the future is not
- * ready this method will wait for the future to be set.
+ * Gets whether the schema was in agreement after the request completed.
+ * Schema altering statements (CREATE, ALTER and DROP of keyspaces, tables,
+ * types, indexes, materialized views, functions, aggregates and triggers,
+ * but not of roles or users) executed via
+ * cass_session_execute() await schema agreement for up to the max schema
+ * wait time; they still succeed if the agreement is not reached in time.
+ * This function will block until the future is set.
  *
- * @cassandra{2.2+}
+ * <b>Note:</b> This function is a Scylla extension.
  *
  * @public @memberof CassFuture
  *
  * @param[in] future
- * @param[in] index
- * @param[out] name
- * @param[out] name_length
- * @param[out] value
- * @param[out] value_size
- * @return CASS_OK if successful, otherwise an error occurred.
+ * @return cass_true if the schema is in agreement or the statement did not
+ * alter the schema, cass_false if agreement was not reached or the
+ * request failed.
+ *
+ * @see cass_cluster_set_max_schema_wait_time()
  */
-CASS_EXPORT CassError
-cass_future_custom_payload_item(CassFuture* future,
-                                size_t index,
-                                const char** name,
-                                size_t* name_length,
-                                const cass_byte_t** value,
-                                size_t* value_size);
+CASS_EXPORT cass_bool_t
+cass_future_schema_in_agreement(CassFuture* future);
 
 /**
- * Gets the node that acted as coordinator for this query. If the future is not
- * ready this method will wait for the future to be set.
+ * Gets the host of the attempt. It's the node's address for requests,
+ * and the contact point (along with the port) for connections.
  *
- * @public @memberof CassFuture
+ * <b>Note:</b> This function is a Scylla extension.
  *
- * @param future
- * @return The coordinator node that handled the query. The lifetime of this
- * object is the same as the result object it came from. NULL can be returned
- * if the future is not a response future or if an error occurs before a
- * coordinator responds.
+ * @public @memberof CassErrorDetail
  *
- * @see cass_statement_set_node()
+ * @param[in] detail
+ * @param[out] host
+ * @param[out] host_length
+ *
+ * @see cass_iterator_error_details_from_future()
  */
-CASS_EXPORT const CassNode*
-cass_future_coordinator(CassFuture* future);
+CASS_EXPORT void
+cass_error_detail_host(const CassErrorDetail* detail,
+                       const char** host,
+                       size_t* host_length);
 
-/***********************************************************************************
+/**
+ * Gets the error code of the attempt.
  *
- * Statement
+ * <b>Note:</b> This function is a Scylla extension.
  *
- ***********************************************************************************/
+ * @public @memberof CassErrorDetail
+ *
+ * @param[in] detail
+ * @return CASS_OK if the attempt succeeded, otherwise an error code
+ * (e.g. CASS_ERROR_LIB_HOST_RESOLUTION, CASS_ERROR_SSL_PROTOCOL_ERROR or
+ * CASS_ERROR_SERVER_BAD_CREDENTIALS). Attempts which got no response before
+ * the request completed are reported as CASS_ERROR_LIB_REQUEST_TIMED_OUT.
+ */
+CASS_EXPORT CassError
+cass_error_detail_error_code(const CassErrorDetail* detail);
 
 /**
- * Creates a new query statement.
+ * Gets the error message of the attempt. It's empty if the attempt succeeded.
  *
- * @public @memberof CassStatement
+ * <b>Note:</b> This function is a Scylla extension.
  *
- * @param[in] query The query is copied into the statement object; the
- * memory pointed to by this parameter can be freed after this call.
- * @param[in] parameter_count The number of bound parameters.
- * @return Returns a statement that must be freed.
+ * @public @memberof CassErrorDetail
  *
- * @see cass_statement_free()
+ * @param[in] detail
+ * @param[out] message
+ * @param[out] message_length
  */
-CASS_EXPORT CassStatement*
-cass_statement_new(const char* query,
-                   size_t parameter_count);
+CASS_EXPORT void
+cass_error_detail_message(const CassErrorDetail* detail,
+                          const char** message,
+                          size_t* message_length);
 
 /**
- * Same as cass_statement_new(), but with lengths for string
- * parameters.
+ * Gets the latency of the attempt.
  *
- * @public @memberof CassStatement
+ * <b>Note:</b> This function is a Scylla extension.
  *
- * @param[in] query
- * @param[in] query_length
- * @param[in] parameter_count
- * @return same as cass_statement_new()
+ * @public @memberof CassErrorDetail
  *
- * @see cass_statement_new()
+ * @param[in] detail
+ * @return The latency in microseconds.
  */
-CASS_EXPORT CassStatement*
-cass_statement_new_n(const char* query,
-                     size_t query_length,
-                     size_t parameter_count);
+CASS_EXPORT cass_uint64_t
+cass_error_detail_latency(const CassErrorDetail* detail);
 
 /**
- * Clear and/or resize the statement's parameters.
+ * Gets the address of the node the attempt was sent to.
  *
- * @public @memberof CassStatement
+ * <b>Note:</b> This function is a Scylla extension.
  *
- * @param[in] statement
- * @param[in] count
- * @return CASS_OK if successful, otherwise an error occurred.
+ * @public @memberof CassExecutionAttempt
+ *
+ * @param[in] attempt
+ * @param[out] host
+ * @param[out] host_length
+ *
+ * @see cass_iterator_execution_history_from_future()
  */
-CASS_EXPORT CassError
-cass_statement_reset_parameters(CassStatement* statement,
-                                 size_t count);
+CASS_EXPORT void
+cass_execution_attempt_host(const CassExecutionAttempt* attempt,
+                            const char** host,
+                            size_t* host_length);
 
 /**
- * Frees a statement instance. Statements can be immediately freed after
- * being prepared, executed or added to a batch.
+ * Gets the fiber the attempt was made in.
  *
- * @public @memberof CassStatement
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassExecutionAttempt
+ *
+ * @param[in] attempt
+ * @return 0 for the regular execution, n for the n-th speculative execution.
+ *
+ * @see cass_cluster_set_constant_speculative_execution_policy()
+ */
+CASS_EXPORT cass_uint32_t
+cass_execution_attempt_speculative_fiber(const CassExecutionAttempt* attempt);
+
+/**
+ * Gets the time from the start of the request to the start of the attempt.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassExecutionAttempt
+ *
+ * @param[in] attempt
+ * @return The offset in microseconds.
+ */
+CASS_EXPORT cass_uint64_t
+cass_execution_attempt_start_offset(const CassExecutionAttempt* attempt);
+
+/**
+ * Gets the latency of the attempt.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassExecutionAttempt
+ *
+ * @param[in] attempt
+ * @return The latency in microseconds.
+ */
+CASS_EXPORT cass_uint64_t
+cass_execution_attempt_latency(const CassExecutionAttempt* attempt);
+
+/**
+ * Gets the error code of the attempt.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassExecutionAttempt
+ *
+ * @param[in] attempt
+ * @return CASS_OK if the attempt succeeded, otherwise an error code.
+ * Attempts which got no response before the request completed are reported
+ * as CASS_ERROR_LIB_REQUEST_TIMED_OUT.
+ */
+CASS_EXPORT CassError
+cass_execution_attempt_error_code(const CassExecutionAttempt* attempt);
+
+/**
+ * Gets the error message of the attempt. It's empty if the attempt succeeded.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassExecutionAttempt
+ *
+ * @param[in] attempt
+ * @param[out] message
+ * @param[out] message_length
+ */
+CASS_EXPORT void
+cass_execution_attempt_message(const CassExecutionAttempt* attempt,
+                               const char** message,
+                               size_t* message_length);
+
+/**
+ * Gets the decision of the retry policy, made upon the attempt's failure.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassExecutionAttempt
+ *
+ * @param[in] attempt
+ * @return The retry decision, or CASS_RETRY_DECISION_NONE if the attempt
+ * did not fail.
+ */
+CASS_EXPORT CassRetryDecision
+cass_execution_attempt_retry_decision(const CassExecutionAttempt* attempt);
+
+/**
+ * Gets the consistency the request was retried with, upon the attempt's failure.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassExecutionAttempt
+ *
+ * @param[in] attempt
+ * @return The consistency, or CASS_CONSISTENCY_UNKNOWN if the request was
+ * not retried, or was retried with the unchanged consistency.
+ */
+CASS_EXPORT CassConsistency
+cass_execution_attempt_retry_consistency(const CassExecutionAttempt* attempt);
+
+/**
+ * Gets a the number of custom payload items from a response future. If the future is not
+ * ready this method will wait for the future to be set.
+ *
+ * @cassandra{2.2+}
+ *
+ * @public @memberof CassFuture
+ *
+ * @param[in] future
+ * @return the number of custom payload items.
+ */
+CASS_EXPORT size_t
+cass_future_custom_payload_item_count(CassFuture* future);
+
+/**
+ * Gets a custom payload item from a response future at the specified index. If the future is not
+ * ready this method will wait for the future to be set.
+ *
+ * @cassandra{2.2+}
+ *
+ * @public @memberof CassFuture
+ *
+ * @param[in] future
+ * @param[in] index
+ * @param[out] name
+ * @param[out] name_length
+ * @param[out] value
+ * @param[out] value_size
+ * @return CASS_OK if successful, otherwise an error occurred.
+ */
+CASS_EXPORT CassError
+cass_future_custom_payload_item(CassFuture* future,
+                                size_t index,
+                                const char** name,
+                                size_t* name_length,
+                                const cass_byte_t** value,
+                                size_t* value_size);
+
+/**
+ * Gets the node that acted as coordinator for this query. If the future is not
+ * ready this method will wait for the future to be set.
+ *
+ * @public @memberof CassFuture
+ *
+ * @param future
+ * @return The coordinator node that handled the query. The lifetime of this
+ * object is the same as the result object it came from. NULL can be returned
+ * if the future is not a response future or if an error occurs before a
+ * coordinator responds.
+ *
+ * @see cass_statement_set_node()
+ */
+CASS_EXPORT const CassNode*
+cass_future_coordinator(CassFuture* future);
+
+/***********************************************************************************
+ *
+ * Statement
+ *
+ ***********************************************************************************/
+
+/**
+ * Creates a new query statement.
+ *
+ * @public @memberof CassStatement
+ *
+ * @param[in] query The query is copied into the statement object; the
+ * memory pointed to by this parameter can be freed after this call.
+ * @param[in] parameter_count The number of bound parameters.
+ * @return Returns a statement that must be freed.
+ *
+ * @see cass_statement_free()
+ */
+CASS_EXPORT CassStatement*
+cass_statement_new(const char* query,
+                   size_t parameter_count);
+
+/**
+ * Same as cass_statement_new(), but with lengths for string
+ * parameters.
+ *
+ * @public @memberof CassStatement
+ *
+ * @param[in] query
+ * @param[in] query_length
+ * @param[in] parameter_count
+ * @return same as cass_statement_new()
+ *
+ * @see cass_statement_new()
+ */
+CASS_EXPORT CassStatement*
+cass_statement_new_n(const char* query,
+                     size_t query_length,
+                     size_t parameter_count);
+
+/**
+ * Clear and/or resize the statement's parameters.
+ *
+ * @public @memberof CassStatement
+ *
+ * @param[in] statement
+ * @param[in] count
+ * @return CASS_OK if successful, otherwise an error occurred.
+ */
+CASS_EXPORT CassError
+cass_statement_reset_parameters(CassStatement* statement,
+                                 size_t count);
+
+/**
+ * Frees a statement instance. Statements can be immediately freed after
+ * being prepared, executed or added to a batch.
+ *
+ * @public @memberof CassStatement
  *
  * @param[in] statement
  */
@@ -5499,6 +6517,27 @@ CASS_EXPORT CassError
 cass_statement_set_tracing(CassStatement* statement,
                            cass_bool_t enabled);
 
+/**
+ * Sets whether the execution history of the statement should be recorded.
+ * It consists of every attempt, along with its target node, speculative
+ * fiber, latency, error and the retry decision made upon its failure.
+ *
+ * <b>Default:</b> cass_false
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassStatement
+ *
+ * @param[in] statement
+ * @param[in] enabled
+ * @return CASS_OK if successful, otherwise an error occurred.
+ *
+ * @see cass_iterator_execution_history_from_future()
+ */
+CASS_EXPORT CassError
+cass_statement_set_execution_history(CassStatement* statement,
+                                     cass_bool_t enabled);
+
 /**
  * Sets a specific host that should run the query.
  *
@@ -6459,6 +7498,122 @@ cass_statement_bind_decimal_by_name_n(CassStatement* statement,
                                       size_t varint_size,
                                       cass_int32_t scale);
 
+/**
+ * Binds a "varint", given as a string, to a query or bound statement at the
+ * specified index.
+ *
+ * @public @memberof CassStatement
+ *
+ * @param[in] statement
+ * @param[in] index
+ * @param[in] value A base-10 integer, e.g. "-123".
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the string
+ * is malformed, otherwise an error occurred.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ */
+CASS_EXPORT CassError
+cass_statement_bind_varint_string(CassStatement* statement,
+                                  size_t index,
+                                  const char* value);
+
+/**
+ * Binds a "varint", given as a string, to all the values with the specified name.
+ *
+ * @public @memberof CassStatement
+ *
+ * @param[in] statement
+ * @param[in] name
+ * @param[in] value A base-10 integer, e.g. "-123".
+ * @return same as cass_statement_bind_varint_string()
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ */
+CASS_EXPORT CassError
+cass_statement_bind_varint_string_by_name(CassStatement* statement,
+                                          const char* name,
+                                          const char* value);
+
+/**
+ * Same as cass_statement_bind_varint_string_by_name(), but with lengths for string
+ * parameters.
+ *
+ * @public @memberof CassStatement
+ *
+ * @param[in] statement
+ * @param[in] name
+ * @param[in] name_length
+ * @param[in] value
+ * @return same as cass_statement_bind_varint_string_by_name()
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @see cass_statement_bind_varint_string_by_name()
+ */
+CASS_EXPORT CassError
+cass_statement_bind_varint_string_by_name_n(CassStatement* statement,
+                                            const char* name,
+                                            size_t name_length,
+                                            const char* value);
+
+/**
+ * Binds a "decimal", given as a string, to a query or bound statement at the
+ * specified index.
+ *
+ * @public @memberof CassStatement
+ *
+ * @param[in] statement
+ * @param[in] index
+ * @param[in] value A base-10 decimal, e.g. "-1.23" or "1.23E-7".
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the string
+ * is malformed, otherwise an error occurred.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ */
+CASS_EXPORT CassError
+cass_statement_bind_decimal_string(CassStatement* statement,
+                                   size_t index,
+                                   const char* value);
+
+/**
+ * Binds a "decimal", given as a string, to all the values with the specified name.
+ *
+ * @public @memberof CassStatement
+ *
+ * @param[in] statement
+ * @param[in] name
+ * @param[in] value A base-10 decimal, e.g. "-1.23" or "1.23E-7".
+ * @return same as cass_statement_bind_decimal_string()
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ */
+CASS_EXPORT CassError
+cass_statement_bind_decimal_string_by_name(CassStatement* statement,
+                                           const char* name,
+                                           const char* value);
+
+/**
+ * Same as cass_statement_bind_decimal_string_by_name(), but with lengths for string
+ * parameters.
+ *
+ * @public @memberof CassStatement
+ *
+ * @param[in] statement
+ * @param[in] name
+ * @param[in] name_length
+ * @param[in] value
+ * @return same as cass_statement_bind_decimal_string_by_name()
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @see cass_statement_bind_decimal_string_by_name()
+ */
+CASS_EXPORT CassError
+cass_statement_bind_decimal_string_by_name_n(CassStatement* statement,
+                                             const char* name,
+                                             size_t name_length,
+                                             const char* value);
+
 /**
  * Binds a "duration" to a query or bound statement at the specified index.
  *
@@ -6642,6 +7797,12 @@ cass_statement_bind_tuple_by_name_n(CassStatement* statement,
  * Bind a user defined type to a query or bound statement at the
  * specified index.
  *
+ * The fields of the user type are matched by name against the type
+ * definition of the bound statement, so they may be listed in a different
+ * order. Fields missing from the user type, not only the trailing ones
+ * (e.g. added by ALTER TYPE), are bound as null. Binding fails if the user
+ * type has a field which the type definition lacks.
+ *
  * @cassandra{2.1+}
  *
  * @public @memberof CassStatement
@@ -6788,9 +7949,120 @@ cass_prepared_parameter_data_type_by_name_n(const CassPrepared* prepared,
                                             const char* name,
                                             size_t name_length);
 
-/***********************************************************************************
+/**
+ * Gets the number of columns in the result of the prepared statement.
  *
- * Batch
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassPrepared
+ *
+ * @param[in] prepared
+ * @return The number of result columns. Zero for statements
+ * which do not return rows.
+ */
+CASS_EXPORT size_t
+cass_prepared_result_column_count(const CassPrepared* prepared);
+
+/**
+ * Gets the name of a result column at the specified index.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassPrepared
+ *
+ * @param[in] prepared
+ * @param[in] index
+ * @param[out] name
+ * @param[out] name_length
+ * @return CASS_OK if successful, otherwise an error occurred.
+ */
+CASS_EXPORT CassError
+cass_prepared_result_column_name(const CassPrepared* prepared,
+                                 size_t index,
+                                 const char** name,
+                                 size_t* name_length);
+
+/**
+ * Gets the data type of a result column at the specified index.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassPrepared
+ *
+ * @param[in] prepared
+ * @param[in] index
+ * @return Returns a reference to the data type of the column. Do not free
+ * this reference as it is bound to the lifetime of the prepared.
+ */
+CASS_EXPORT const CassDataType*
+cass_prepared_result_column_data_type(const CassPrepared* prepared,
+                                      size_t index);
+
+/**
+ * Gets the id assigned to the prepared statement by the server.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassPrepared
+ *
+ * @param[in] prepared
+ * @param[out] id The id bytes. Bound to the lifetime of the prepared.
+ * @param[out] id_length
+ */
+CASS_EXPORT void
+cass_prepared_id(const CassPrepared* prepared,
+                 const cass_byte_t** id,
+                 size_t* id_length);
+
+/**
+ * Gets the number of bound parameters which make up the partition key.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassPrepared
+ *
+ * @param[in] prepared
+ * @return The number of partition key parameters. Zero if the statement
+ * does not bind the whole partition key.
+ */
+CASS_EXPORT size_t
+cass_prepared_partition_key_index_count(const CassPrepared* prepared);
+
+/**
+ * Gets the index of the bound parameter holding the partition key
+ * component at the specified position.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassPrepared
+ *
+ * @param[in] prepared
+ * @param[in] index Position of the component within the partition key.
+ * @param[out] parameter_index
+ * @return CASS_OK if successful, otherwise an error occurred.
+ */
+CASS_EXPORT CassError
+cass_prepared_partition_key_index(const CassPrepared* prepared,
+                                  size_t index,
+                                  size_t* parameter_index);
+
+/**
+ * Gets whether the prepared statement is a lightweight transaction,
+ * as reported by the server.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassPrepared
+ *
+ * @param[in] prepared
+ * @return cass_true if the statement is an LWT, otherwise cass_false.
+ */
+CASS_EXPORT cass_bool_t
+cass_prepared_is_lwt(const CassPrepared* prepared);
+
+/***********************************************************************************
+ *
+ * Batch
  *
  ***********************************************************************************/
 
@@ -6985,6 +8257,26 @@ CASS_EXPORT CassError
 cass_batch_set_tracing(CassBatch* batch,
                        cass_bool_t enabled);
 
+/**
+ * Sets whether the execution history of the batch should be recorded.
+ *
+ * <b>Default:</b> cass_false
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassBatch
+ *
+ * @param[in] batch
+ * @param[in] enabled
+ * @return CASS_OK if successful, otherwise an error occurred.
+ *
+ * @see cass_statement_set_execution_history()
+ * @see cass_iterator_execution_history_from_future()
+ */
+CASS_EXPORT CassError
+cass_batch_set_execution_history(CassBatch* batch,
+                                 cass_bool_t enabled);
+
 /**
  * Adds a statement to a batch.
  *
@@ -7000,6 +8292,68 @@ CASS_EXPORT CassError
 cass_batch_add_statement(CassBatch* batch,
                          CassStatement* statement);
 
+/**
+ * Sets the estimated batch size (in bytes) past which a warning is logged
+ * when the batch is executed. The estimate covers the statements and their
+ * bound values, as serialized in the request.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * <b>Default:</b> 0 (disabled)
+ *
+ * @public @memberof CassBatch
+ *
+ * @param[in] batch
+ * @param[in] threshold_bytes 0 disables the warning.
+ * @return CASS_OK if successful, otherwise an error occurred.
+ *
+ * @see cass_batch_estimated_size()
+ */
+CASS_EXPORT CassError
+cass_batch_set_size_warning_threshold(CassBatch* batch,
+                                      size_t threshold_bytes);
+
+/**
+ * Sets whether an UNLOGGED batch is split into per-partition sub-batches,
+ * which are executed concurrently. Statements are grouped by the token of
+ * their partition key; unprepared statements, whose token is not known,
+ * are grouped together. The returned future fails with the first error
+ * encountered, otherwise it resolves with the result of the first sub-batch.
+ *
+ * The sub-batches are executed as independent requests, so the batch may be
+ * applied partially: when the returned future fails, some of the sub-batches
+ * may have been applied nevertheless. Retrying the whole batch is safe only
+ * if its statements are idempotent.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * <b>Default:</b> cass_false (disabled)
+ *
+ * @public @memberof CassBatch
+ *
+ * @param[in] batch
+ * @param[in] enabled
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if enabled
+ * for a batch which is not UNLOGGED.
+ */
+CASS_EXPORT CassError
+cass_batch_set_split_by_partition(CassBatch* batch,
+                                  cass_bool_t enabled);
+
+/**
+ * Gets the estimated size (in bytes) of the batch statements and their
+ * bound values, as serialized in the request.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassBatch
+ *
+ * @param[in] batch
+ * @return The estimated size in bytes.
+ */
+CASS_EXPORT size_t
+cass_batch_estimated_size(const CassBatch* batch);
+
 /**
  * Sets the execution profile to execute the batch with.
  *
@@ -7767,6 +9121,38 @@ cass_collection_append_decimal(CassCollection* collection,
                                size_t varint_size,
                                cass_int32_t scale);
 
+/**
+ * Appends a "varint", given as a string, to the collection.
+ *
+ * @public @memberof CassCollection
+ *
+ * @param[in] collection
+ * @param[in] value A base-10 integer, e.g. "-123".
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the string
+ * is malformed, otherwise an error occurred.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ */
+CASS_EXPORT CassError
+cass_collection_append_varint_string(CassCollection* collection,
+                                     const char* value);
+
+/**
+ * Appends a "decimal", given as a string, to the collection.
+ *
+ * @public @memberof CassCollection
+ *
+ * @param[in] collection
+ * @param[in] value A base-10 decimal, e.g. "-1.23" or "1.23E-7".
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the string
+ * is malformed, otherwise an error occurred.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ */
+CASS_EXPORT CassError
+cass_collection_append_decimal_string(CassCollection* collection,
+                                      const char* value);
+
 /**
  * Appends a "duration" to the collection.
  *
@@ -8202,6 +9588,46 @@ cass_tuple_set_decimal(CassTuple* tuple,
                        size_t varint_size,
                        cass_int32_t scale);
 
+/**
+ * Sets a "varint", given as a string, in a tuple at the specified index.
+ *
+ * @cassandra{2.1+}
+ *
+ * @public @memberof CassTuple
+ *
+ * @param[in] tuple
+ * @param[in] index
+ * @param[in] value A base-10 integer, e.g. "-123".
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the string
+ * is malformed, otherwise an error occurred.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ */
+CASS_EXPORT CassError
+cass_tuple_set_varint_string(CassTuple* tuple,
+                             size_t index,
+                             const char* value);
+
+/**
+ * Sets a "decimal", given as a string, in a tuple at the specified index.
+ *
+ * @cassandra{2.1+}
+ *
+ * @public @memberof CassTuple
+ *
+ * @param[in] tuple
+ * @param[in] index
+ * @param[in] value A base-10 decimal, e.g. "-1.23" or "1.23E-7".
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the string
+ * is malformed, otherwise an error occurred.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ */
+CASS_EXPORT CassError
+cass_tuple_set_decimal_string(CassTuple* tuple,
+                              size_t index,
+                              const char* value);
+
 /**
  * Sets a "duration" in a tuple at the specified index.
  *
@@ -9235,6 +10661,136 @@ cass_user_type_set_decimal_by_name_n(CassUserType* user_type,
                                      size_t varint_size,
                                      int scale);
 
+/**
+ * Sets a "varint", given as a string, in a user defined type at the specified
+ * index.
+ *
+ * @cassandra{2.1+}
+ *
+ * @public @memberof CassUserType
+ *
+ * @param[in] user_type
+ * @param[in] index
+ * @param[in] value A base-10 integer, e.g. "-123".
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the string
+ * is malformed, otherwise an error occurred.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ */
+CASS_EXPORT CassError
+cass_user_type_set_varint_string(CassUserType* user_type,
+                                 size_t index,
+                                 const char* value);
+
+/**
+ * Sets a "varint", given as a string, in a user defined type at the specified
+ * name.
+ *
+ * @cassandra{2.1+}
+ *
+ * @public @memberof CassUserType
+ *
+ * @param[in] user_type
+ * @param[in] name
+ * @param[in] value A base-10 integer, e.g. "-123".
+ * @return same as cass_user_type_set_varint_string()
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ */
+CASS_EXPORT CassError
+cass_user_type_set_varint_string_by_name(CassUserType* user_type,
+                                         const char* name,
+                                         const char* value);
+
+/**
+ * Same as cass_user_type_set_varint_string_by_name(), but with lengths for string
+ * parameters.
+ *
+ * @cassandra{2.1+}
+ *
+ * @public @memberof CassUserType
+ *
+ * @param[in] user_type
+ * @param[in] name
+ * @param[in] name_length
+ * @param[in] value
+ * @return same as cass_user_type_set_varint_string_by_name()
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @see cass_user_type_set_varint_string_by_name()
+ */
+CASS_EXPORT CassError
+cass_user_type_set_varint_string_by_name_n(CassUserType* user_type,
+                                           const char* name,
+                                           size_t name_length,
+                                           const char* value);
+
+/**
+ * Sets a "decimal", given as a string, in a user defined type at the specified
+ * index.
+ *
+ * @cassandra{2.1+}
+ *
+ * @public @memberof CassUserType
+ *
+ * @param[in] user_type
+ * @param[in] index
+ * @param[in] value A base-10 decimal, e.g. "-1.23" or "1.23E-7".
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the string
+ * is malformed, otherwise an error occurred.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ */
+CASS_EXPORT CassError
+cass_user_type_set_decimal_string(CassUserType* user_type,
+                                  size_t index,
+                                  const char* value);
+
+/**
+ * Sets a "decimal", given as a string, in a user defined type at the specified
+ * name.
+ *
+ * @cassandra{2.1+}
+ *
+ * @public @memberof CassUserType
+ *
+ * @param[in] user_type
+ * @param[in] name
+ * @param[in] value A base-10 decimal, e.g. "-1.23" or "1.23E-7".
+ * @return same as cass_user_type_set_decimal_string()
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ */
+CASS_EXPORT CassError
+cass_user_type_set_decimal_string_by_name(CassUserType* user_type,
+                                          const char* name,
+                                          const char* value);
+
+/**
+ * Same as cass_user_type_set_decimal_string_by_name(), but with lengths for string
+ * parameters.
+ *
+ * @cassandra{2.1+}
+ *
+ * @public @memberof CassUserType
+ *
+ * @param[in] user_type
+ * @param[in] name
+ * @param[in] name_length
+ * @param[in] value
+ * @return same as cass_user_type_set_decimal_string_by_name()
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @see cass_user_type_set_decimal_string_by_name()
+ */
+CASS_EXPORT CassError
+cass_user_type_set_decimal_string_by_name_n(CassUserType* user_type,
+                                            const char* name,
+                                            size_t name_length,
+                                            const char* value);
+
 /**
  * Sets a "duration" in a user defined type at the specified index.
  *
@@ -9515,97 +11071,570 @@ CASS_EXPORT size_t
 cass_result_column_count(const CassResult* result);
 
 /**
- * Gets the column name at index for the specified result.
+ * Gets the column name at index for the specified result.
+ *
+ * @public @memberof CassResult
+ *
+ * @param[in] result
+ * @param[in] index
+ * @param[out] name The column name at the specified index.
+ * @param[out] name_length
+ * @return CASS_OK if successful, otherwise error occurred
+ */
+CASS_EXPORT CassError
+cass_result_column_name(const CassResult *result,
+                        size_t index,
+                        const char** name,
+                        size_t* name_length);
+
+/**
+ * Gets the column type at index for the specified result.
+ *
+ * @public @memberof CassResult
+ *
+ * @param[in] result
+ * @param[in] index
+ * @return The column type at the specified index. CASS_VALUE_TYPE_UNKNOWN
+ * is returned if the index is out of bounds.
+ */
+CASS_EXPORT CassValueType
+cass_result_column_type(const CassResult* result,
+                        size_t index);
+
+/**
+ * Gets the column data type at index for the specified result.
+ *
+ * @public @memberof CassResult
+ *
+ * @param[in] result
+ * @param[in] index
+ * @return The column type at the specified index. NULL is returned if the
+ * index is out of bounds.
+ */
+CASS_EXPORT const CassDataType*
+cass_result_column_data_type(const CassResult* result, size_t index);
+
+/**
+ * Gets the first row of the result.
+ *
+ * @public @memberof CassResult
+ *
+ * @param[in] result
+ * @return The first row of the result. NULL if there are no rows.
+ */
+CASS_EXPORT const CassRow*
+cass_result_first_row(const CassResult* result);
+
+/**
+ * Gets whether a conditional statement (lightweight transaction) or
+ * a conditional batch was applied, based on the "[applied]" column
+ * of the result.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassResult
+ *
+ * @param[in] result
+ * @return cass_false if the condition was not met, otherwise cass_true.
+ * Results of non-conditional statements are always applied.
+ */
+CASS_EXPORT cass_bool_t
+cass_result_lwt_applied(const CassResult* result);
+
+/**
+ * Returns true if there are more pages.
+ *
+ * @cassandra{2.0+}
+ *
+ * @public @memberof CassResult
+ *
+ * @param[in] result
+ * @return cass_true if there are more pages
+ */
+CASS_EXPORT cass_bool_t
+cass_result_has_more_pages(const CassResult* result);
+
+/**
+ * Gets the raw paging state from the result. The paging state is bound to the
+ * lifetime of the result object. If paging state needs to live beyond the
+ * lifetime of the result object it must be copied.
+ *
+ * <b>Warning:</b> The paging state should not be exposed to or come from
+ * untrusted environments. The paging state could be spoofed and potentially
+ * used to gain access to other data.
+ *
+ * @cassandra{2.0+}
+ *
+ * @public @memberof CassResult
+ *
+ * @param[in] result
+ * @param[out] paging_state
+ * @param[out] paging_state_size
+ * @return CASS_OK if successful, otherwise error occurred
+ *
+ * @see cass_statement_set_paging_state_token()
+ */
+CASS_EXPORT CassError
+cass_result_paging_state_token(const CassResult* result,
+                               const char** paging_state,
+                               size_t* paging_state_size);
+
+/**
+ * Gets the size in bytes of a single element of the fixed-width column
+ * at index. Use it to size the buffer passed to
+ * cass_result_column_copy_fixed().
+ *
+ * Supported column types and their element representation:
+ * <ul>
+ *   <li>tinyint - cass_int8_t</li>
+ *   <li>smallint - cass_int16_t</li>
+ *   <li>int - cass_int32_t</li>
+ *   <li>date - cass_uint32_t</li>
+ *   <li>bigint, counter, time, timestamp - cass_int64_t</li>
+ *   <li>float - cass_float_t</li>
+ *   <li>double - cass_double_t</li>
+ *   <li>uuid, timeuuid - CassUuid</li>
+ * </ul>
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassResult
+ *
+ * @param[in] result
+ * @param[in] index
+ * @param[out] width
+ * @return CASS_OK if successful, CASS_ERROR_LIB_INVALID_VALUE_TYPE if the
+ * column is not fixed-width, CASS_ERROR_LIB_INDEX_OUT_OF_BOUNDS if the index
+ * is out of bounds.
+ */
+CASS_EXPORT CassError
+cass_result_column_fixed_width(const CassResult* result,
+                               size_t index,
+                               size_t* width);
+
+/**
+ * Copies the whole fixed-width column at index into a caller-supplied array,
+ * avoiding per-cell iteration. The i-th element of the array corresponds
+ * to the i-th row of the result. NULL values are written as zeroes.
+ *
+ * If validity is not NULL, an Arrow-compatible validity bitmap is written
+ * into it: bit (i % 8) of byte (i / 8) is set if the value in the i-th row
+ * is not NULL.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassResult
+ *
+ * @param[in] result
+ * @param[in] index
+ * @param[out] values Array of at least row count elements, of the type
+ * described in cass_result_column_fixed_width().
+ * @param[in] values_size Size of the values array in bytes.
+ * @param[out] validity Validity bitmap of at least ceil(row count / 8) bytes. May be NULL.
+ * @param[in] validity_size Size of the validity bitmap in bytes.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if any of the
+ * buffers is too small, otherwise an error occurred.
+ *
+ * @see cass_result_column_fixed_width()
+ * @see cass_result_row_count()
+ */
+CASS_EXPORT CassError
+cass_result_column_copy_fixed(const CassResult* result,
+                              size_t index,
+                              void* values,
+                              size_t values_size,
+                              cass_uint8_t* validity,
+                              size_t validity_size);
+
+/**
+ * Gets the total size in bytes of all values of the text (ascii, text,
+ * varchar) or blob column at index. Use it to size the data buffer passed to
+ * cass_result_column_copy_variable().
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassResult
+ *
+ * @param[in] result
+ * @param[in] index
+ * @param[out] data_size
+ * @return CASS_OK if successful, otherwise an error occurred.
+ */
+CASS_EXPORT CassError
+cass_result_column_data_size(const CassResult* result,
+                             size_t index,
+                             size_t* data_size);
+
+/**
+ * Copies the whole text (ascii, text, varchar) or blob column at index into
+ * caller-supplied buffers, using the Arrow variable-size binary layout: the
+ * value in the i-th row occupies bytes [offsets[i], offsets[i + 1]) of data.
+ * NULL values are represented as empty ranges.
+ *
+ * If validity is not NULL, an Arrow-compatible validity bitmap is written
+ * into it: bit (i % 8) of byte (i / 8) is set if the value in the i-th row
+ * is not NULL.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassResult
+ *
+ * @param[in] result
+ * @param[in] index
+ * @param[out] offsets Array of at least row count + 1 offsets.
+ * @param[in] offsets_count Number of elements in the offsets array.
+ * @param[out] data Buffer of at least the size returned by cass_result_column_data_size().
+ * @param[in] data_size Size of the data buffer in bytes.
+ * @param[out] validity Validity bitmap of at least ceil(row count / 8) bytes. May be NULL.
+ * @param[in] validity_size Size of the validity bitmap in bytes.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if any of the
+ * buffers is too small, otherwise an error occurred.
+ *
+ * @see cass_result_column_data_size()
+ * @see cass_result_row_count()
+ */
+CASS_EXPORT CassError
+cass_result_column_copy_variable(const CassResult* result,
+                                 size_t index,
+                                 cass_int32_t* offsets,
+                                 size_t offsets_count,
+                                 cass_byte_t* data,
+                                 size_t data_size,
+                                 cass_uint8_t* validity,
+                                 size_t validity_size);
+
+/***********************************************************************************
+ *
+ * Varint and decimal
+ *
+ * Conversions of varints and decimals from and to base-10 strings, int64
+ * and double. A varint is represented as signed big-endian two's complement
+ * bytes and a decimal as a varint (the unscaled value) and a scale, the same
+ * way they are returned by cass_value_get_bytes() and cass_value_get_decimal().
+ * Decimal strings use the format of Java's BigDecimal, e.g. "-1.23" or "1.23E-7".
+ *
+ * Functions writing to a caller-supplied buffer always report the required
+ * size, even if the buffer is too small (CASS_ERROR_LIB_BAD_PARAMS is returned
+ * then). Conversions to int64 fail with CASS_ERROR_LIB_INVALID_DATA if the
+ * value can't be represented exactly. The same applies to conversions to
+ * double, so e.g. 0.1 can't be converted (see cass_decimal_to_double()).
+ *
+ * <b>Note:</b> These functions are a Scylla extension.
+ *
+ ***********************************************************************************/
+
+/**
+ * Parses a base-10 integer, e.g. "-123", into a varint.
+ *
+ * @param[in] value
+ * @param[out] output Buffer for the varint.
+ * @param[in] output_size
+ * @param[out] varint_size Size of the varint. May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the string
+ * is malformed or the buffer is too small.
+ */
+CASS_EXPORT CassError
+cass_varint_from_string(const char* value,
+                        cass_byte_t* output,
+                        size_t output_size,
+                        size_t* varint_size);
+
+/**
+ * Formats a varint in base 10. The output is null-terminated.
+ *
+ * @param[in] varint
+ * @param[in] varint_size
+ * @param[out] output Buffer for the string. May be NULL to only query the length.
+ * @param[in] output_size Size of the output buffer, including the terminating null byte.
+ * @param[out] string_length Length of the string, excluding the terminating
+ * null byte. May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the buffer is
+ * NULL or too small.
+ */
+CASS_EXPORT CassError
+cass_varint_to_string(const cass_byte_t* varint,
+                      size_t varint_size,
+                      char* output,
+                      size_t output_size,
+                      size_t* string_length);
+
+/**
+ * Converts an int64 to a varint.
+ *
+ * @param[in] value
+ * @param[out] output Buffer for the varint. 8 bytes are always enough.
+ * @param[in] output_size
+ * @param[out] varint_size Size of the varint. May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the buffer is
+ * too small.
+ */
+CASS_EXPORT CassError
+cass_varint_from_int64(cass_int64_t value,
+                       cass_byte_t* output,
+                       size_t output_size,
+                       size_t* varint_size);
+
+/**
+ * Converts a varint to an int64.
+ *
+ * @param[in] varint
+ * @param[in] varint_size
+ * @param[out] output
+ * @return CASS_OK if successful, CASS_ERROR_LIB_INVALID_DATA if the value
+ * is out of the int64 range.
+ */
+CASS_EXPORT CassError
+cass_varint_to_int64(const cass_byte_t* varint,
+                     size_t varint_size,
+                     cass_int64_t* output);
+
+/**
+ * Parses a decimal string, e.g. "-1.23" or "1.23E-7".
+ *
+ * @param[in] value
+ * @param[out] varint Buffer for the unscaled value.
+ * @param[in] varint_buffer_size
+ * @param[out] varint_size Size of the unscaled value. May be NULL.
+ * @param[out] scale May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the string
+ * is malformed or the buffer is too small.
+ */
+CASS_EXPORT CassError
+cass_decimal_from_string(const char* value,
+                         cass_byte_t* varint,
+                         size_t varint_buffer_size,
+                         size_t* varint_size,
+                         cass_int32_t* scale);
+
+/**
+ * Formats a decimal the same way as Java's BigDecimal.toString() does.
+ * The output is null-terminated.
+ *
+ * @param[in] varint
+ * @param[in] varint_size
+ * @param[in] scale
+ * @param[out] output Buffer for the string. May be NULL to only query the length.
+ * @param[in] output_size Size of the output buffer, including the terminating null byte.
+ * @param[out] string_length Length of the string, excluding the terminating
+ * null byte. May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the buffer is
+ * NULL or too small.
+ */
+CASS_EXPORT CassError
+cass_decimal_to_string(const cass_byte_t* varint,
+                       size_t varint_size,
+                       cass_int32_t scale,
+                       char* output,
+                       size_t output_size,
+                       size_t* string_length);
+
+/**
+ * Converts an int64 to a decimal with a scale of 0.
+ *
+ * @param[in] value
+ * @param[out] varint Buffer for the unscaled value. 8 bytes are always enough.
+ * @param[in] varint_buffer_size
+ * @param[out] varint_size Size of the unscaled value. May be NULL.
+ * @param[out] scale May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the buffer is
+ * too small.
+ */
+CASS_EXPORT CassError
+cass_decimal_from_int64(cass_int64_t value,
+                        cass_byte_t* varint,
+                        size_t varint_buffer_size,
+                        size_t* varint_size,
+                        cass_int32_t* scale);
+
+/**
+ * Converts a decimal to an int64.
+ *
+ * @param[in] varint
+ * @param[in] varint_size
+ * @param[in] scale
+ * @param[out] output
+ * @return CASS_OK if successful, CASS_ERROR_LIB_INVALID_DATA if the value
+ * has a fractional part or is out of the int64 range.
+ */
+CASS_EXPORT CassError
+cass_decimal_to_int64(const cass_byte_t* varint,
+                      size_t varint_size,
+                      cass_int32_t scale,
+                      cass_int64_t* output);
+
+/**
+ * Converts a double to the shortest decimal that converts back to the same
+ * double, e.g. 0.1 is converted to 1E-1 (and not to the exact binary value).
+ *
+ * @param[in] value
+ * @param[out] varint Buffer for the unscaled value.
+ * @param[in] varint_buffer_size
+ * @param[out] varint_size Size of the unscaled value. May be NULL.
+ * @param[out] scale May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_INVALID_DATA for NaN and
+ * infinities, CASS_ERROR_LIB_BAD_PARAMS if the buffer is too small.
+ */
+CASS_EXPORT CassError
+cass_decimal_from_double(cass_double_t value,
+                         cass_byte_t* varint,
+                         size_t varint_buffer_size,
+                         size_t* varint_size,
+                         cass_int32_t* scale);
+
+/**
+ * Converts a decimal to a double. The conversion must be lossless, i.e. the
+ * double must be exactly equal to the decimal. For example, 1E-1 can't be
+ * converted, because the double nearest to it is
+ * 0.1000000000000000055511151231257827021181583404541015625.
+ *
+ * @param[in] varint
+ * @param[in] varint_size
+ * @param[in] scale
+ * @param[out] output
+ * @return CASS_OK if successful, CASS_ERROR_LIB_INVALID_DATA if the value
+ * can't be represented exactly by a double.
+ */
+CASS_EXPORT CassError
+cass_decimal_to_double(const cass_byte_t* varint,
+                       size_t varint_size,
+                       cass_int32_t scale,
+                       cass_double_t* output);
+
+/***********************************************************************************
+ *
+ * JSON
+ *
+ * Rendering of values, rows and results as JSON, in the same format as CQL's
+ * toJson() function: numbers as JSON numbers, UUIDs, inet addresses, dates,
+ * times and durations as strings, blobs as 0x-prefixed hex strings, timestamps
+ * as "yyyy-mm-dd hh:mm:ss.fffZ" strings (UTC), lists, sets and tuples as
+ * arrays, maps and user types as objects (UDT fields by name).
+ *
+ * <b>Note:</b> These functions are a Scylla extension.
+ *
+ ***********************************************************************************/
+
+/**
+ * Renders a value as JSON into a caller-supplied buffer. The output is
+ * null-terminated.
  *
- * @public @memberof CassResult
+ * @public @memberof CassValue
  *
- * @param[in] result
- * @param[in] index
- * @param[out] name The column name at the specified index.
- * @param[out] name_length
- * @return CASS_OK if successful, otherwise error occurred
+ * @param[in] value
+ * @param[out] output Buffer for the JSON. May be NULL to only query the length.
+ * @param[in] output_size Size of the output buffer, including the terminating null byte.
+ * @param[out] json_length Length of the JSON, excluding the terminating null
+ * byte. It's set even if the buffer is too small. May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the output
+ * buffer is NULL or too small.
  */
 CASS_EXPORT CassError
-cass_result_column_name(const CassResult *result,
-                        size_t index,
-                        const char** name,
-                        size_t* name_length);
+cass_value_to_json(const CassValue* value,
+                   char* output,
+                   size_t output_size,
+                   size_t* json_length);
 
 /**
- * Gets the column type at index for the specified result.
+ * Renders a value as JSON into a null-terminated string allocated by the
+ * driver.
  *
- * @public @memberof CassResult
+ * @public @memberof CassValue
  *
- * @param[in] result
- * @param[in] index
- * @return The column type at the specified index. CASS_VALUE_TYPE_UNKNOWN
- * is returned if the index is out of bounds.
+ * @param[in] value
+ * @param[out] output The JSON. It must be freed with cass_json_free().
+ * @param[out] json_length Length of the JSON, excluding the terminating null byte. May be NULL.
+ * @return CASS_OK if successful, otherwise an error occurred.
+ *
+ * @see cass_json_free()
  */
-CASS_EXPORT CassValueType
-cass_result_column_type(const CassResult* result,
-                        size_t index);
+CASS_EXPORT CassError
+cass_value_to_json_alloc(const CassValue* value,
+                         char** output,
+                         size_t* json_length);
 
 /**
- * Gets the column data type at index for the specified result.
+ * Renders a row as a JSON object, mapping column names to the values, into a
+ * caller-supplied buffer. The output is null-terminated.
  *
- * @public @memberof CassResult
+ * @public @memberof CassRow
  *
- * @param[in] result
- * @param[in] index
- * @return The column type at the specified index. NULL is returned if the
- * index is out of bounds.
+ * @param[in] row
+ * @param[out] output Buffer for the JSON. May be NULL to only query the length.
+ * @param[in] output_size Size of the output buffer, including the terminating null byte.
+ * @param[out] json_length Length of the JSON, excluding the terminating null
+ * byte. It's set even if the buffer is too small. May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the output
+ * buffer is NULL or too small.
  */
-CASS_EXPORT const CassDataType*
-cass_result_column_data_type(const CassResult* result, size_t index);
+CASS_EXPORT CassError
+cass_row_to_json(const CassRow* row,
+                 char* output,
+                 size_t output_size,
+                 size_t* json_length);
 
 /**
- * Gets the first row of the result.
+ * Renders a row as a JSON object, mapping column names to the values, into a
+ * null-terminated string allocated by the driver.
  *
- * @public @memberof CassResult
+ * @public @memberof CassRow
  *
- * @param[in] result
- * @return The first row of the result. NULL if there are no rows.
+ * @param[in] row
+ * @param[out] output The JSON. It must be freed with cass_json_free().
+ * @param[out] json_length Length of the JSON, excluding the terminating null byte. May be NULL.
+ * @return CASS_OK if successful, otherwise an error occurred.
+ *
+ * @see cass_json_free()
  */
-CASS_EXPORT const CassRow*
-cass_result_first_row(const CassResult* result);
+CASS_EXPORT CassError
+cass_row_to_json_alloc(const CassRow* row,
+                       char** output,
+                       size_t* json_length);
 
 /**
- * Returns true if there are more pages.
- *
- * @cassandra{2.0+}
+ * Renders a result as a JSON array of row objects into a caller-supplied
+ * buffer. The output is null-terminated.
  *
  * @public @memberof CassResult
  *
  * @param[in] result
- * @return cass_true if there are more pages
+ * @param[out] output Buffer for the JSON. May be NULL to only query the length.
+ * @param[in] output_size Size of the output buffer, including the terminating null byte.
+ * @param[out] json_length Length of the JSON, excluding the terminating null
+ * byte. It's set even if the buffer is too small. May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the output
+ * buffer is NULL or too small.
  */
-CASS_EXPORT cass_bool_t
-cass_result_has_more_pages(const CassResult* result);
+CASS_EXPORT CassError
+cass_result_to_json(const CassResult* result,
+                    char* output,
+                    size_t output_size,
+                    size_t* json_length);
 
 /**
- * Gets the raw paging state from the result. The paging state is bound to the
- * lifetime of the result object. If paging state needs to live beyond the
- * lifetime of the result object it must be copied.
- *
- * <b>Warning:</b> The paging state should not be exposed to or come from
- * untrusted environments. The paging state could be spoofed and potentially
- * used to gain access to other data.
- *
- * @cassandra{2.0+}
+ * Renders a result as a JSON array of row objects into a null-terminated
+ * string allocated by the driver.
  *
  * @public @memberof CassResult
  *
  * @param[in] result
- * @param[out] paging_state
- * @param[out] paging_state_size
- * @return CASS_OK if successful, otherwise error occurred
+ * @param[out] output The JSON. It must be freed with cass_json_free().
+ * @param[out] json_length Length of the JSON, excluding the terminating null byte. May be NULL.
+ * @return CASS_OK if successful, otherwise an error occurred.
  *
- * @see cass_statement_set_paging_state_token()
+ * @see cass_json_free()
  */
 CASS_EXPORT CassError
-cass_result_paging_state_token(const CassResult* result,
-                               const char** paging_state,
-                               size_t* paging_state_size);
+cass_result_to_json_alloc(const CassResult* result,
+                          char** output,
+                          size_t* json_length);
+
+/**
+ * Frees a JSON string allocated by the driver.
+ *
+ * @param[in] json
+ */
+CASS_EXPORT void
+cass_json_free(char* json);
 
 /***********************************************************************************
  *
@@ -9882,6 +11911,57 @@ cass_iterator_type(CassIterator* iterator);
 CASS_EXPORT CassIterator*
 cass_iterator_from_result(const CassResult* result);
 
+/**
+ * Creates a new iterator over the attempts made on behalf of the future's
+ * connection or request, in the order they were started. It tells why each
+ * of the hosts failed, e.g. when the future's error is
+ * CASS_ERROR_LIB_NO_HOSTS_AVAILABLE or CASS_ERROR_LIB_UNABLE_TO_CONNECT.
+ *
+ * Requests list the attempts made by cass_session_execute() and
+ * cass_session_execute_batch(). Hosts without an open connection are
+ * skipped by the driver, so they are not listed.
+ *
+ * If the connection of a session fails, the contact points are listed instead,
+ * or only the hostnames which failed to resolve. As the driver reports a single
+ * error for the whole session, each contact point carries that error.
+ *
+ * This function will block until the future is set.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassFuture
+ *
+ * @param[in] future
+ * @return A new iterator that must be freed.
+ *
+ * @see cass_iterator_get_error_detail()
+ * @see cass_iterator_free()
+ */
+CASS_EXPORT CassIterator*
+cass_iterator_error_details_from_future(CassFuture* future);
+
+/**
+ * Creates a new iterator over the execution history of the future's request,
+ * in the order the attempts were started. The iterator is empty, unless
+ * the history was enabled for the statement or batch.
+ *
+ * This function will block until the future is set.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassFuture
+ *
+ * @param[in] future
+ * @return A new iterator that must be freed.
+ *
+ * @see cass_statement_set_execution_history()
+ * @see cass_batch_set_execution_history()
+ * @see cass_iterator_get_execution_attempt()
+ * @see cass_iterator_free()
+ */
+CASS_EXPORT CassIterator*
+cass_iterator_execution_history_from_future(CassFuture* future);
+
 /**
  * Creates a new iterator for the specified row. This can be
  * used to iterate over columns in a row.
@@ -10522,6 +12602,42 @@ cass_iterator_get_meta_field_name(const CassIterator* iterator,
 CASS_EXPORT const CassValue*
 cass_iterator_get_meta_field_value(const CassIterator* iterator);
 
+/**
+ * Gets the error detail at the iterator's current position.
+ *
+ * Calling cass_iterator_next() will invalidate the previous
+ * value returned by this method.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassIterator
+ *
+ * @param[in] iterator
+ * @return An error detail
+ *
+ * @see cass_iterator_error_details_from_future()
+ */
+CASS_EXPORT const CassErrorDetail*
+cass_iterator_get_error_detail(const CassIterator* iterator);
+
+/**
+ * Gets the execution attempt at the iterator's current position.
+ *
+ * Calling cass_iterator_next() will invalidate the previous
+ * value returned by this method.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @public @memberof CassIterator
+ *
+ * @param[in] iterator
+ * @return An execution attempt
+ *
+ * @see cass_iterator_execution_history_from_future()
+ */
+CASS_EXPORT const CassExecutionAttempt*
+cass_iterator_get_execution_attempt(const CassIterator* iterator);
+
 /***********************************************************************************
  *
  * Row
@@ -11603,6 +13719,281 @@ CASS_EXPORT cass_int64_t
 cass_date_time_to_epoch(cass_uint32_t date,
                         cass_int64_t time);
 
+/**
+ * Parses a "date" literal in the format accepted by CQL: yyyy-mm-dd
+ * (the year may be negative) or the raw number of days since -5877641-06-23.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @param[in] value
+ * @param[out] output
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the string is
+ * malformed or the date is out of range.
+ */
+CASS_EXPORT CassError
+cass_date_from_string(const char* value,
+                      cass_uint32_t* output);
+
+/**
+ * Formats a "date" as yyyy-mm-dd. The output is null-terminated.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @param[in] date
+ * @param[out] output Buffer for the string. May be NULL to only query the length.
+ * @param[in] output_size Size of the output buffer, including the terminating null byte.
+ * @param[out] length Length of the string, excluding the terminating null byte.
+ * It's set even if the buffer is too small. May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the output
+ * buffer is NULL or too small.
+ */
+CASS_EXPORT CassError
+cass_date_to_string(cass_uint32_t date,
+                    char* output,
+                    size_t output_size,
+                    size_t* length);
+
+/**
+ * Parses a "time" literal in the format accepted by CQL: hh:mm:ss[.fffffffff]
+ * or the raw number of nanoseconds since midnight.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @param[in] value
+ * @param[out] output
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the string is
+ * malformed or the time is out of range.
+ */
+CASS_EXPORT CassError
+cass_time_from_string(const char* value,
+                      cass_int64_t* output);
+
+/**
+ * Formats a "time" as hh:mm:ss.fffffffff. The output is null-terminated.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @param[in] time
+ * @param[out] output Buffer for the string. May be NULL to only query the length.
+ * @param[in] output_size Size of the output buffer, including the terminating null byte.
+ * @param[out] length Length of the string, excluding the terminating null byte.
+ * It's set even if the buffer is too small. May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the time is
+ * out of range or the output buffer is NULL or too small.
+ */
+CASS_EXPORT CassError
+cass_time_to_string(cass_int64_t time,
+                    char* output,
+                    size_t output_size,
+                    size_t* length);
+
+/**
+ * Parses a "timestamp" literal in one of the formats accepted by CQL
+ * (which include ISO-8601): yyyy-mm-dd[(T| )hh:mm[:ss[.fff]]][offset], where
+ * offset is Z, +hh, +hhmm or +hh:mm, or the raw number of milliseconds since
+ * the Epoch. Timestamps without an offset are in UTC.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @param[in] value
+ * @param[out] output Milliseconds since the Epoch.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the string is
+ * malformed or the timestamp is out of range.
+ */
+CASS_EXPORT CassError
+cass_timestamp_from_string(const char* value,
+                           cass_int64_t* output);
+
+/**
+ * Formats a "timestamp" in ISO-8601, in the given offset from UTC, e.g.
+ * 2011-02-03T04:05:06.789Z or 2011-02-03T06:05:06.789+02:00. The output
+ * is null-terminated and is a valid CQL literal.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @param[in] timestamp Milliseconds since the Epoch.
+ * @param[in] offset_minutes Offset from UTC in minutes, at most 18 hours.
+ * @param[out] output Buffer for the string. May be NULL to only query the length.
+ * @param[in] output_size Size of the output buffer, including the terminating null byte.
+ * @param[out] length Length of the string, excluding the terminating null byte.
+ * It's set even if the buffer is too small. May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the offset is
+ * out of range or the output buffer is NULL or too small.
+ */
+CASS_EXPORT CassError
+cass_timestamp_to_string(cass_int64_t timestamp,
+                         cass_int32_t offset_minutes,
+                         char* output,
+                         size_t output_size,
+                         size_t* length);
+
+/**
+ * Same as cass_timestamp_from_string(), but with up to nanosecond precision.
+ * The raw number is in nanoseconds since the Epoch.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @param[in] value
+ * @param[out] output Nanoseconds since the Epoch.
+ * @return same as cass_timestamp_from_string()
+ *
+ * @see cass_timestamp_from_string()
+ */
+CASS_EXPORT CassError
+cass_timestamp_ns_from_string(const char* value,
+                              cass_int64_t* output);
+
+/**
+ * Same as cass_timestamp_to_string(), but with nanosecond precision.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @param[in] timestamp_ns Nanoseconds since the Epoch.
+ * @param[in] offset_minutes
+ * @param[out] output
+ * @param[in] output_size
+ * @param[out] length
+ * @return same as cass_timestamp_to_string()
+ *
+ * @see cass_timestamp_to_string()
+ */
+CASS_EXPORT CassError
+cass_timestamp_ns_to_string(cass_int64_t timestamp_ns,
+                            cass_int32_t offset_minutes,
+                            char* output,
+                            size_t output_size,
+                            size_t* length);
+
+/**
+ * Breaks down a "timestamp" into the calendar and clock fields (in UTC).
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @param[in] timestamp Milliseconds since the Epoch.
+ * @param[out] output
+ * @return CASS_OK if successful, otherwise an error occurred.
+ */
+CASS_EXPORT CassError
+cass_date_time_from_timestamp(cass_int64_t timestamp,
+                              CassDateTime* output);
+
+/**
+ * Combines a "date" and a "time" and breaks them down into the calendar and
+ * clock fields.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @param[in] date
+ * @param[in] time
+ * @param[out] output
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the time is
+ * out of range.
+ */
+CASS_EXPORT CassError
+cass_date_time_from_date_and_time(cass_uint32_t date,
+                                  cass_int64_t time,
+                                  CassDateTime* output);
+
+/**
+ * Converts broken down calendar and clock fields (in UTC) to a "timestamp".
+ * The sub-millisecond part is truncated.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @param[in] date_time
+ * @param[out] output Milliseconds since the Epoch.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if any of the
+ * fields is out of range, CASS_ERROR_LIB_INVALID_DATA if the timestamp
+ * can't be represented.
+ */
+CASS_EXPORT CassError
+cass_date_time_to_timestamp(const CassDateTime* date_time,
+                            cass_int64_t* output);
+
+/**
+ * Converts broken down calendar and clock fields to a "date" and a "time".
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @param[in] date_time
+ * @param[out] date May be NULL.
+ * @param[out] time May be NULL.
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if any of the
+ * fields is out of range, CASS_ERROR_LIB_INVALID_DATA if the date can't be
+ * represented.
+ */
+CASS_EXPORT CassError
+cass_date_time_to_date_and_time(const CassDateTime* date_time,
+                                cass_uint32_t* date,
+                                cass_int64_t* time);
+
+/**
+ * Adds a "duration" to a "timestamp" the same way as CQL does: the months
+ * are added first (if the day doesn't exist in the resulting month, the last
+ * day of the month is used, e.g. 2011-01-31 + 1mo is 2011-02-28), then the
+ * days and the nanoseconds (truncated to milliseconds).
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @param[in] timestamp Milliseconds since the Epoch.
+ * @param[in] duration
+ * @param[out] output
+ * @return CASS_OK if successful, CASS_ERROR_LIB_INVALID_DATA if the result
+ * is out of range.
+ */
+CASS_EXPORT CassError
+cass_timestamp_add_duration(cass_int64_t timestamp,
+                            CassDuration duration,
+                            cass_int64_t* output);
+
+/**
+ * Subtracts a "duration" from a "timestamp". Inverse of
+ * cass_timestamp_add_duration().
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @param[in] timestamp Milliseconds since the Epoch.
+ * @param[in] duration
+ * @param[out] output
+ * @return same as cass_timestamp_add_duration()
+ */
+CASS_EXPORT CassError
+cass_timestamp_subtract_duration(cass_int64_t timestamp,
+                                 CassDuration duration,
+                                 cass_int64_t* output);
+
+/**
+ * Adds a "duration" to a "date" the same way as cass_timestamp_add_duration()
+ * does. As in CQL, the duration must not have a time part.
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @param[in] date
+ * @param[in] duration
+ * @param[out] output
+ * @return CASS_OK if successful, CASS_ERROR_LIB_BAD_PARAMS if the duration
+ * has a time part, CASS_ERROR_LIB_INVALID_DATA if the result is out of range.
+ */
+CASS_EXPORT CassError
+cass_date_add_duration(cass_uint32_t date,
+                       CassDuration duration,
+                       cass_uint32_t* output);
+
+/**
+ * Subtracts a "duration" from a "date". Inverse of cass_date_add_duration().
+ *
+ * <b>Note:</b> This function is a Scylla extension.
+ *
+ * @param[in] date
+ * @param[in] duration
+ * @param[out] output
+ * @return same as cass_date_add_duration()
+ */
+CASS_EXPORT CassError
+cass_date_subtract_duration(cass_uint32_t date,
+                            CassDuration duration,
+                            cass_uint32_t* output);
+
 /***********************************************************************************
  *
  * Allocator
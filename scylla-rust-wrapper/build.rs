@@ -95,6 +95,8 @@ fn main() {
             "CassError",
             "CassWriteType",
             "CassWriteType_",
+            "CassRetryDecision",
+            "CassRetryDecision_",
         ],
         &out_path,
     );
@@ -115,7 +117,32 @@ fn main() {
     );
     prepare_cppdriver_data(
         "cppdriver_column_type.rs",
-        &["CassColumnType_", "CassColumnType"],
+        &[
+            "CassColumnType_",
+            "CassColumnType",
+            "CassClusteringOrder_",
+            "CassClusteringOrder",
+        ],
+        &out_path,
+    );
+    prepare_cppdriver_data(
+        "cppdriver_index_type.rs",
+        &["CassIndexType_", "CassIndexType"],
+        &out_path,
+    );
+    prepare_cppdriver_data(
+        "cppdriver_replication_strategy.rs",
+        &["CassReplicationStrategy_", "CassReplicationStrategy"],
+        &out_path,
+    );
+    prepare_cppdriver_data(
+        "cppdriver_schema_change_types.rs",
+        &[
+            "CassSchemaChangeType_",
+            "CassSchemaChangeType",
+            "CassSchemaChangeTarget_",
+            "CassSchemaChangeTarget",
+        ],
         &out_path,
     );
     prepare_cppdriver_data(
@@ -138,6 +165,21 @@ fn main() {
         &["CassUuid_", "CassUuid"],
         &out_path,
     );
+    prepare_cppdriver_data(
+        "cppdriver_version_types.rs",
+        &["CassVersion_", "CassVersion"],
+        &out_path,
+    );
+    prepare_cppdriver_data(
+        "cppdriver_date_time_types.rs",
+        &[
+            "CassDateTime_",
+            "CassDateTime",
+            "CassDuration_",
+            "CassDuration",
+        ],
+        &out_path,
+    );
     prepare_cppdriver_data(
         "cppdriver_data_types.rs",
         &["CassValueType_", "CassValueType"],
@@ -1,3 +1,4 @@
+use crate::cass_error::CassError;
 use crate::types::size_t;
 use std::cmp::min;
 use std::ffi::CStr;
@@ -43,6 +44,29 @@ pub unsafe fn write_str_to_c(s: &str, c_str: *mut *const c_char, c_strlen: *mut
     *c_strlen = s.len() as u64;
 }
 
+/// Copies `s` into a caller-supplied, null-terminated buffer.
+/// The length of `s` is always written to `length` (if it's not null),
+/// so the caller can learn the required size of the buffer.
+pub unsafe fn write_str_to_buffer(
+    s: &str,
+    output: *mut c_char,
+    output_size: size_t,
+    length: *mut size_t,
+) -> CassError {
+    if !length.is_null() {
+        std::ptr::write(length, s.len() as size_t);
+    }
+
+    if output.is_null() || (output_size as usize) < s.len() + 1 {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+
+    std::ptr::copy_nonoverlapping(s.as_ptr() as *const c_char, output, s.len());
+    std::ptr::write(output.add(s.len()), 0);
+
+    CassError::CASS_OK
+}
+
 pub unsafe fn strlen(ptr: *const c_char) -> size_t {
     if ptr.is_null() {
         return 0;
@@ -7,8 +7,9 @@ use crate::retry_policy::CassRetryPolicy;
 use crate::statement::{BoundStatement, CassStatement};
 use crate::types::*;
 use crate::value::CassCqlValue;
-use scylla::batch::Batch;
+use scylla::batch::{Batch, BatchStatement, BatchType};
 use scylla::frame::value::MaybeUnset;
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::sync::Arc;
 
@@ -17,6 +18,14 @@ pub struct CassBatch {
     pub batch_request_timeout_ms: Option<cass_uint64_t>,
 
     pub(crate) exec_profile: Option<PerStatementExecProfile>,
+
+    // Estimated batch size (in bytes), past which a warning is logged.
+    pub(crate) size_warning_threshold: Option<usize>,
+    // Whether to execute UNLOGGED batch as concurrent per-partition sub-batches.
+    pub(crate) split_by_partition: bool,
+    // Whether to expose the attempts, speculative fibers and retry decisions
+    // made when executing the batch.
+    pub(crate) record_execution_history: bool,
 }
 
 impl BoxFFI for CassBatch {}
@@ -27,6 +36,81 @@ pub struct CassBatchState {
     pub bound_values: Vec<Vec<MaybeUnset<Option<CassCqlValue>>>>,
 }
 
+impl CassBatchState {
+    /// Estimates the size of the statements and their bound values,
+    /// as serialized in the BATCH request.
+    pub(crate) fn estimated_size(&self) -> usize {
+        self.batch
+            .statements
+            .iter()
+            .zip(self.bound_values.iter())
+            .map(|(statement, values)| {
+                let statement_size = match statement {
+                    // [byte kind][long string]
+                    BatchStatement::Query(query) => 1 + 4 + query.contents.len(),
+                    // [byte kind][short bytes]
+                    BatchStatement::PreparedStatement(prepared) => 1 + 2 + prepared.get_id().len(),
+                };
+                let values_size: usize = values
+                    .iter()
+                    .map(|value| match value {
+                        MaybeUnset::Set(Some(value)) => value.serialized_size(),
+                        // Null and unset values are represented by the length only.
+                        MaybeUnset::Set(None) | MaybeUnset::Unset => 4,
+                    })
+                    .sum();
+
+                // [short] values count
+                statement_size + 2 + values_size
+            })
+            .sum()
+    }
+
+    /// Splits the batch into sub-batches, one per partition key token.
+    /// Statements whose token cannot be computed (e.g. unprepared ones)
+    /// are grouped together. The sub-batches share the batch settings.
+    pub(crate) fn split_by_token(&self) -> Vec<CassBatchState> {
+        self.split_by(|statement, values| match statement {
+            BatchStatement::Query(_) => None,
+            BatchStatement::PreparedStatement(prepared) => prepared
+                .calculate_token(values)
+                .ok()
+                .flatten()
+                .map(|token| token.value()),
+        })
+    }
+
+    /// Splits the batch into sub-batches of statements with the same key,
+    /// keeping the order of statements within each sub-batch.
+    fn split_by<K: Eq + std::hash::Hash>(
+        &self,
+        key_of: impl Fn(&BatchStatement, &Vec<MaybeUnset<Option<CassCqlValue>>>) -> K,
+    ) -> Vec<CassBatchState> {
+        let mut sub_batches: Vec<CassBatchState> = Vec::new();
+        let mut key_to_sub_batch: HashMap<K, usize> = HashMap::new();
+
+        for (statement, values) in self.batch.statements.iter().zip(self.bound_values.iter()) {
+            let index = *key_to_sub_batch
+                .entry(key_of(statement, values))
+                .or_insert_with(|| {
+                    let mut batch = self.batch.clone();
+                    batch.statements.clear();
+                    sub_batches.push(CassBatchState {
+                        batch,
+                        bound_values: Vec::new(),
+                    });
+                    sub_batches.len() - 1
+                });
+
+            let sub_batch = &mut sub_batches[index];
+            sub_batch.batch.append_statement(statement.clone());
+            sub_batch.bound_values.push(values.clone());
+        }
+
+        sub_batches
+    }
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_batch_new(type_: CassBatchType) -> *mut CassBatch {
     if let Some(batch_type) = make_batch_type(type_) {
@@ -37,6 +121,9 @@ pub unsafe extern "C" fn cass_batch_new(type_: CassBatchType) -> *mut CassBatch
             }),
             batch_request_timeout_ms: None,
             exec_profile: None,
+            size_warning_threshold: None,
+            split_by_partition: false,
+            record_execution_history: false,
         }))
     } else {
         std::ptr::null_mut()
@@ -130,6 +217,17 @@ pub unsafe extern "C" fn cass_batch_set_request_timeout(
     CassError::CASS_OK
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_batch_set_execution_history(
+    batch: *mut CassBatch,
+    enabled: cass_bool_t,
+) -> CassError {
+    let batch = BoxFFI::as_mut_ref(batch);
+    batch.record_execution_history = enabled != 0;
+
+    CassError::CASS_OK
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_batch_set_is_idempotent(
     batch: *mut CassBatch,
@@ -178,3 +276,155 @@ pub unsafe extern "C" fn cass_batch_add_statement(
 
     CassError::CASS_OK
 }
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_batch_set_size_warning_threshold(
+    batch: *mut CassBatch,
+    threshold_bytes: size_t,
+) -> CassError {
+    let batch = BoxFFI::as_mut_ref(batch);
+    batch.size_warning_threshold = match threshold_bytes {
+        0 => None,
+        threshold => Some(threshold as usize),
+    };
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_batch_set_split_by_partition(
+    batch: *mut CassBatch,
+    enabled: cass_bool_t,
+) -> CassError {
+    let batch = BoxFFI::as_mut_ref(batch);
+
+    // Splitting would break the atomicity of LOGGED batches,
+    // and COUNTER batches are not idempotent.
+    if enabled != 0 && !matches!(batch.state.batch.get_type(), BatchType::Unlogged) {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+    batch.split_by_partition = enabled != 0;
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_batch_estimated_size(batch: *const CassBatch) -> size_t {
+    let batch = BoxFFI::as_ref(batch);
+
+    batch.state.estimated_size() as size_t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scylla::query::Query;
+    use scylla::statement::Consistency;
+
+    fn query_contents(statement: &BatchStatement) -> &str {
+        match statement {
+            BatchStatement::Query(query) => &query.contents,
+            BatchStatement::PreparedStatement(prepared) => prepared.get_statement(),
+        }
+    }
+
+    fn make_state(statements: &[&str]) -> CassBatchState {
+        let mut batch = Batch::new(BatchType::Unlogged);
+        batch.set_consistency(Consistency::LocalQuorum);
+        for statement in statements {
+            batch.append_statement(Query::new(*statement));
+        }
+        CassBatchState {
+            batch,
+            bound_values: statements
+                .iter()
+                .map(|_| vec![MaybeUnset::Set(Some(CassCqlValue::Int(1)))])
+                .collect(),
+        }
+    }
+
+    fn sub_batch_contents(sub_batches: &[CassBatchState]) -> Vec<Vec<&str>> {
+        sub_batches
+            .iter()
+            .map(|sub_batch| {
+                assert_eq!(
+                    sub_batch.batch.statements.len(),
+                    sub_batch.bound_values.len()
+                );
+                sub_batch
+                    .batch
+                    .statements
+                    .iter()
+                    .map(query_contents)
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn split_by_test() {
+        let state = make_state(&["a1", "b1", "c1", "a2", "c2", "b2", "a3"]);
+
+        // Statements are grouped by their key, keeping their order.
+        let sub_batches = state.split_by(|statement, _| query_contents(statement).as_bytes()[0]);
+        assert_eq!(
+            sub_batch_contents(&sub_batches),
+            vec![vec!["a1", "a2", "a3"], vec!["b1", "b2"], vec!["c1", "c2"],]
+        );
+        for sub_batch in &sub_batches {
+            assert!(matches!(sub_batch.batch.get_type(), BatchType::Unlogged));
+            assert_eq!(
+                sub_batch.batch.get_consistency(),
+                Some(Consistency::LocalQuorum)
+            );
+        }
+
+        // A batch of statements with the same key is not split.
+        let sub_batches = state.split_by(|_, _| 0);
+        assert_eq!(
+            sub_batch_contents(&sub_batches),
+            vec![vec!["a1", "b1", "c1", "a2", "c2", "b2", "a3"]]
+        );
+    }
+
+    #[test]
+    fn split_by_token_test() {
+        // The token of unprepared statements is not known, so they are grouped together.
+        let state = make_state(&[
+            "INSERT INTO ks.t (pk) VALUES (?)",
+            "DELETE FROM ks.t WHERE pk = ?",
+        ]);
+        assert_eq!(
+            sub_batch_contents(&state.split_by_token()),
+            vec![vec![
+                "INSERT INTO ks.t (pk) VALUES (?)",
+                "DELETE FROM ks.t WHERE pk = ?"
+            ]]
+        );
+
+        assert!(make_state(&[]).split_by_token().is_empty());
+    }
+
+    #[test]
+    fn estimated_size_test() {
+        let statement = "INSERT INTO ks.t (pk, v1, v2) VALUES (?, ?, ?)";
+        let mut state = make_state(&[statement, statement]);
+        state.bound_values[1] = vec![
+            MaybeUnset::Set(Some(CassCqlValue::Text("abc".to_owned()))),
+            MaybeUnset::Set(None),
+            MaybeUnset::Unset,
+        ];
+
+        let query_size = 1 + 4 + statement.len();
+        assert_eq!(
+            state.estimated_size(),
+            // [byte kind][long string][short count][int length][int value]
+            (query_size + 2 + 4 + 4)
+                // [byte kind][long string][short count]
+                // [int length][3 bytes][int length = -1][int length = -2]
+                + (query_size + 2 + 4 + 3 + 4 + 4)
+        );
+
+        assert_eq!(make_state(&[]).estimated_size(), 0);
+    }
+}
@@ -297,6 +297,35 @@ macro_rules! invoke_binder_maker_macro_with_type {
             [v @ *const cass_byte_t, v_size @ size_t, scale @ cass_int32_t]
         );
     };
+    (varint_string, $macro_name:ident, $this:ty, $consume_v:expr, $fn:ident) => {
+        $macro_name!(
+            $this,
+            $consume_v,
+            $fn,
+            |v| {
+                use scylla::frame::value::CqlVarint;
+                match ptr_to_cstr(v).and_then(crate::numeric::varint_from_string) {
+                    Some(bytes) => Ok(Some(Varint(CqlVarint::from_signed_bytes_be(bytes)))),
+                    None => Err(CassError::CASS_ERROR_LIB_BAD_PARAMS),
+                }
+            },
+            [v @ *const std::os::raw::c_char]
+        );
+    };
+    (decimal_string, $macro_name:ident, $this:ty, $consume_v:expr, $fn:ident) => {
+        $macro_name!(
+            $this,
+            $consume_v,
+            $fn,
+            |v| {
+                match ptr_to_cstr(v).and_then(crate::numeric::decimal_from_string) {
+                    Some(decimal) => Ok(Some(Decimal(decimal))),
+                    None => Err(CassError::CASS_ERROR_LIB_BAD_PARAMS),
+                }
+            },
+            [v @ *const std::os::raw::c_char]
+        );
+    };
     (collection, $macro_name:ident, $this:ty, $consume_v:expr, $fn:ident) => {
         $macro_name!(
             $this,
@@ -0,0 +1,168 @@
+//! Executors of the future callbacks. By default, callbacks are invoked inline,
+//! on the driver's thread which completed the future. Slow or blocking callbacks
+//! would then stall the driver's I/O, so they can be dispatched to a dedicated
+//! thread pool, or to an executor provided by the user, instead.
+
+use crate::argconv::*;
+use std::os::raw::c_void;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// A callback invocation, handed over to the user's executor.
+pub struct CassCallbackTask {
+    job: Job,
+}
+
+impl BoxFFI for CassCallbackTask {}
+
+pub type CassCallbackExecutorFunc =
+    Option<unsafe extern "C" fn(task: *mut CassCallbackTask, data: *mut c_void)>;
+
+#[derive(Clone, Copy)]
+pub struct UserCallbackExecutor {
+    func: unsafe extern "C" fn(task: *mut CassCallbackTask, data: *mut c_void),
+    data: *mut c_void,
+}
+
+// *mut c_void is not Send, so Rust will have to take our word
+// that we won't screw something up
+unsafe impl Send for UserCallbackExecutor {}
+unsafe impl Sync for UserCallbackExecutor {}
+
+/// Fixed size pool of threads, running the callbacks.
+/// The threads exit once the pool is dropped and the pending callbacks are run.
+pub struct CallbackThreadPool {
+    sender: Mutex<mpsc::Sender<Job>>,
+}
+
+impl CallbackThreadPool {
+    fn new(num_threads: usize) -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        for index in 0..num_threads {
+            let receiver = Arc::clone(&receiver);
+            thread::Builder::new()
+                .name(format!("cass-callback-{}", index))
+                .spawn(move || loop {
+                    // The lock is released before running the job.
+                    let job = receiver.lock().unwrap().recv();
+                    match job {
+                        Ok(job) => job(),
+                        Err(mpsc::RecvError) => break,
+                    }
+                })
+                .expect("Failed to spawn a callback thread");
+        }
+
+        Self {
+            sender: Mutex::new(sender),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub enum CallbackExecutor {
+    #[default]
+    Inline,
+    ThreadPool(Arc<CallbackThreadPool>),
+    User(UserCallbackExecutor),
+}
+
+impl CallbackExecutor {
+    pub(crate) fn thread_pool(num_threads: usize) -> Self {
+        match num_threads {
+            0 => CallbackExecutor::Inline,
+            num_threads => {
+                CallbackExecutor::ThreadPool(Arc::new(CallbackThreadPool::new(num_threads)))
+            }
+        }
+    }
+
+    pub(crate) fn user(func: CassCallbackExecutorFunc, data: *mut c_void) -> Self {
+        match func {
+            Some(func) => CallbackExecutor::User(UserCallbackExecutor { func, data }),
+            None => CallbackExecutor::Inline,
+        }
+    }
+
+    pub(crate) fn execute(&self, job: impl FnOnce() + Send + 'static) {
+        match self {
+            CallbackExecutor::Inline => job(),
+            CallbackExecutor::ThreadPool(pool) => {
+                // Sending fails only if all the threads are gone, which is not
+                // possible while we hold the pool.
+                let _ = pool.sender.lock().unwrap().send(Box::new(job));
+            }
+            CallbackExecutor::User(executor) => {
+                let task = BoxFFI::into_ptr(Box::new(CassCallbackTask { job: Box::new(job) }));
+                unsafe { (executor.func)(task, executor.data) }
+            }
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_callback_task_run(task_raw: *mut CassCallbackTask) {
+    let CassCallbackTask { job } = *BoxFFI::from_ptr(task_raw);
+    job();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Condvar;
+
+    #[test]
+    #[ntest::timeout(500)]
+    fn thread_pool_executor_test() {
+        const JOBS: usize = 16;
+        let done = Arc::new((Mutex::new(0), Condvar::new()));
+        let main_thread = thread::current().id();
+
+        let executor = CallbackExecutor::thread_pool(2);
+        for _ in 0..JOBS {
+            let done = Arc::clone(&done);
+            executor.execute(move || {
+                assert_ne!(main_thread, thread::current().id());
+                *done.0.lock().unwrap() += 1;
+                done.1.notify_all();
+            });
+        }
+
+        let guard = done.0.lock().unwrap();
+        let _guard = done.1.wait_while(guard, |count| *count < JOBS).unwrap();
+    }
+
+    #[test]
+    fn user_executor_test() {
+        static EXECUTED_TASKS: AtomicUsize = AtomicUsize::new(0);
+
+        unsafe extern "C" fn run_later(task: *mut CassCallbackTask, data: *mut c_void) {
+            let tasks = data as *mut Vec<usize>;
+            (*tasks).push(task as usize);
+        }
+
+        let mut tasks: Vec<usize> = Vec::new();
+        let executor = CallbackExecutor::user(Some(run_later), &mut tasks as *mut _ as *mut c_void);
+        executor.execute(|| {
+            EXECUTED_TASKS.fetch_add(1, Ordering::SeqCst);
+        });
+
+        // The job is run only once the user runs the task.
+        assert_eq!(1, tasks.len());
+        assert_eq!(0, EXECUTED_TASKS.load(Ordering::SeqCst));
+        unsafe { cass_callback_task_run(tasks[0] as *mut CassCallbackTask) };
+        assert_eq!(1, EXECUTED_TASKS.load(Ordering::SeqCst));
+
+        // No executor means inline execution.
+        CallbackExecutor::user(None, std::ptr::null_mut()).execute(|| {
+            EXECUTED_TASKS.fetch_add(1, Ordering::SeqCst);
+        });
+        assert_eq!(2, EXECUTED_TASKS.load(Ordering::SeqCst));
+    }
+}
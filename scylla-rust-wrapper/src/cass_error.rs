@@ -14,14 +14,13 @@ impl ToCassError for CassErrorResult {
         match self {
             CassErrorResult::Query(query_error) => query_error.to_cass_error(),
 
-            // TODO:
-            // For now let's leave these as LIB_INVALID_DATA.
-            // I don't see any variants that would make more sense.
-            // TBH, I'm almost sure that we should introduce additional enum variants
-            // of CassError in the future ~ muzarski.
+            // The metadata of the result could not be parsed at all.
             CassErrorResult::ResultMetadataLazyDeserialization(_) => {
                 CassError::CASS_ERROR_LIB_INVALID_DATA
             }
+            // Rows are deserialized into `CqlValue`s, which match any column type
+            // from the metadata, so a failure means that a received value is malformed,
+            // rather than of a mismatched type.
             CassErrorResult::Deserialization(_) => CassError::CASS_ERROR_LIB_INVALID_DATA,
         }
     }
@@ -43,15 +42,11 @@ impl ToCassError for QueryError {
             QueryError::EmptyPlan => CassError::CASS_ERROR_LIB_INVALID_STATE,
             QueryError::CqlResultParseError(_) => CassError::CASS_ERROR_LIB_UNEXPECTED_RESPONSE,
             QueryError::CqlErrorParseError(_) => CassError::CASS_ERROR_LIB_UNEXPECTED_RESPONSE,
-            QueryError::MetadataError(_) => CassError::CASS_ERROR_LIB_INVALID_STATE,
-            // I know that TranslationError (corresponding to CASS_ERROR_LIB_HOST_RESOLUTION)
-            // is hidden under the ConnectionPoolError.
-            // However, we still have a lot work to do when it comes to error conversion.
-            // I will address it, once we start resolving all issues related to error conversion.
-            QueryError::ConnectionPoolError(_) => CassError::CASS_ERROR_LIB_UNABLE_TO_CONNECT,
+            QueryError::MetadataError(metadata_error) => metadata_error.to_cass_error(),
+            QueryError::ConnectionPoolError(pool_error) => pool_error.to_cass_error(),
             QueryError::BrokenConnection(_) => CassError::CASS_ERROR_LIB_UNABLE_TO_CONNECT,
             // QueryError is non_exhaustive
-            _ => CassError::CASS_ERROR_LAST_ENTRY,
+            _ => CassError::CASS_ERROR_LIB_INTERNAL_ERROR,
         }
     }
 }
@@ -90,24 +85,24 @@ impl ToCassError for BadQuery {
     fn to_cass_error(&self) -> CassError {
         match self {
             BadQuery::SerializeValuesError(_serialize_values_error) => {
-                CassError::CASS_ERROR_LAST_ENTRY
+                CassError::CASS_ERROR_LIB_MESSAGE_ENCODE
             }
-            BadQuery::ValuesTooLongForKey(_usize, _usize2) => CassError::CASS_ERROR_LAST_ENTRY,
-            BadQuery::BadKeyspaceName(_bad_keyspace_name) => CassError::CASS_ERROR_LAST_ENTRY,
-            BadQuery::Other(_other_query) => CassError::CASS_ERROR_LAST_ENTRY,
+            BadQuery::ValuesTooLongForKey(_usize, _usize2) => {
+                CassError::CASS_ERROR_LIB_MESSAGE_ENCODE
+            }
+            BadQuery::BadKeyspaceName(bad_keyspace_name) => bad_keyspace_name.to_cass_error(),
+            BadQuery::Other(_other_query) => CassError::CASS_ERROR_LIB_BAD_PARAMS,
             BadQuery::SerializationError(e) => {
                 if e.downcast_ref::<UnknownNamedParameterError>().is_some() {
                     // It means that our custom `UnknownNamedParameterError` was returned.
                     CassError::CASS_ERROR_LIB_NAME_DOES_NOT_EXIST
                 } else {
-                    CassError::CASS_ERROR_LAST_ENTRY
+                    CassError::CASS_ERROR_LIB_MESSAGE_ENCODE
                 }
             }
-            BadQuery::TooManyQueriesInBatchStatement(_) => CassError::CASS_ERROR_LAST_ENTRY,
+            BadQuery::TooManyQueriesInBatchStatement(_) => CassError::CASS_ERROR_LIB_BAD_PARAMS,
             // BadQuery is non_exhaustive
-            // For now, since all other variants return LAST_ENTRY,
-            // let's do it here as well.
-            _ => CassError::CASS_ERROR_LAST_ENTRY,
+            _ => CassError::CASS_ERROR_LIB_BAD_PARAMS,
         }
     }
 }
@@ -119,12 +114,12 @@ impl ToCassError for NewSessionError {
                 CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE
             }
             NewSessionError::EmptyKnownNodesList => CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
-            NewSessionError::DbError(_db_error, _string) => CassError::CASS_ERROR_LAST_ENTRY,
-            NewSessionError::BadQuery(_bad_query) => CassError::CASS_ERROR_LAST_ENTRY,
+            NewSessionError::DbError(db_error, _string) => db_error.to_cass_error(),
+            NewSessionError::BadQuery(bad_query) => bad_query.to_cass_error(),
             NewSessionError::ProtocolError(_str) => {
                 CassError::CASS_ERROR_LIB_UNABLE_TO_DETERMINE_PROTOCOL
             }
-            NewSessionError::UnableToAllocStreamId => CassError::CASS_ERROR_LAST_ENTRY,
+            NewSessionError::UnableToAllocStreamId => CassError::CASS_ERROR_LIB_NO_STREAMS,
             NewSessionError::RequestTimeout(_) => CassError::CASS_ERROR_LIB_REQUEST_TIMED_OUT,
             NewSessionError::CqlRequestSerialization(_) => CassError::CASS_ERROR_LIB_MESSAGE_ENCODE,
             NewSessionError::BodyExtensionsParseError(_) => {
@@ -135,27 +130,80 @@ impl ToCassError for NewSessionError {
                 CassError::CASS_ERROR_LIB_UNEXPECTED_RESPONSE
             }
             NewSessionError::CqlErrorParseError(_) => CassError::CASS_ERROR_LIB_UNEXPECTED_RESPONSE,
-            NewSessionError::MetadataError(_) => CassError::CASS_ERROR_LIB_INVALID_STATE,
-            // I know that TranslationError (corresponding to CASS_ERROR_LIB_HOST_RESOLUTION)
-            // is hidden under the ConnectionPoolError.
-            // However, we still have a lot work to do when it comes to error conversion.
-            // I will address it, once we start resolving all issues related to error conversion.
-            NewSessionError::ConnectionPoolError(_) => CassError::CASS_ERROR_LIB_UNABLE_TO_CONNECT,
+            NewSessionError::MetadataError(metadata_error) => metadata_error.to_cass_error(),
+            NewSessionError::ConnectionPoolError(pool_error) => pool_error.to_cass_error(),
             NewSessionError::BrokenConnection(_) => CassError::CASS_ERROR_LIB_UNABLE_TO_CONNECT,
             // NS error is non_exhaustive
-            _ => CassError::CASS_ERROR_LAST_ENTRY,
+            _ => CassError::CASS_ERROR_LIB_INTERNAL_ERROR,
+        }
+    }
+}
+
+impl ToCassError for MetadataError {
+    fn to_cass_error(&self) -> CassError {
+        match self {
+            // The control connection could not be established, e.g. when creating the session.
+            MetadataError::ConnectionPoolError(pool_error) => pool_error.to_cass_error(),
+            _ => CassError::CASS_ERROR_LIB_INVALID_STATE,
+        }
+    }
+}
+
+impl ToCassError for ConnectionPoolError {
+    fn to_cass_error(&self) -> CassError {
+        match self {
+            ConnectionPoolError::Broken {
+                last_connection_error,
+            } => last_connection_error.to_cass_error(),
+            // ConnectionPoolError is non_exhaustive
+            _ => CassError::CASS_ERROR_LIB_UNABLE_TO_CONNECT,
+        }
+    }
+}
+
+impl ToCassError for ConnectionError {
+    fn to_cass_error(&self) -> CassError {
+        match self {
+            ConnectionError::TranslationError(_) => CassError::CASS_ERROR_LIB_HOST_RESOLUTION,
+            ConnectionError::IoError(io_error) if is_ssl_error(io_error) => {
+                CassError::CASS_ERROR_SSL_PROTOCOL_ERROR
+            }
+            ConnectionError::ConnectionSetupRequestError(setup_error) => {
+                match setup_error.get_error() {
+                    // The node rejected the protocol version in OPTIONS/STARTUP.
+                    ConnectionSetupRequestErrorKind::DbError(DbError::ProtocolError, _) => {
+                        CassError::CASS_ERROR_LIB_UNABLE_TO_DETERMINE_PROTOCOL
+                    }
+                    ConnectionSetupRequestErrorKind::DbError(db_error, _) => {
+                        db_error.to_cass_error()
+                    }
+                    // ConnectionSetupRequestErrorKind is non_exhaustive
+                    _ => CassError::CASS_ERROR_LIB_UNABLE_TO_CONNECT,
+                }
+            }
+            // ConnectionError is non_exhaustive
+            _ => CassError::CASS_ERROR_LIB_UNABLE_TO_CONNECT,
         }
     }
 }
 
+/// Tells whether the I/O error was caused by a failed TLS handshake.
+fn is_ssl_error(io_error: &std::io::Error) -> bool {
+    io_error.get_ref().is_some_and(|inner| {
+        inner.is::<openssl::ssl::Error>() || inner.is::<openssl::error::ErrorStack>()
+    })
+}
+
 impl ToCassError for BadKeyspaceName {
     fn to_cass_error(&self) -> CassError {
         match self {
-            BadKeyspaceName::Empty => CassError::CASS_ERROR_LAST_ENTRY,
-            BadKeyspaceName::TooLong(_string, _usize) => CassError::CASS_ERROR_LAST_ENTRY,
-            BadKeyspaceName::IllegalCharacter(_string, _char) => CassError::CASS_ERROR_LAST_ENTRY,
+            BadKeyspaceName::Empty => CassError::CASS_ERROR_LIB_BAD_PARAMS,
+            BadKeyspaceName::TooLong(_string, _usize) => CassError::CASS_ERROR_LIB_BAD_PARAMS,
+            BadKeyspaceName::IllegalCharacter(_string, _char) => {
+                CassError::CASS_ERROR_LIB_BAD_PARAMS
+            }
             // non_exhaustive
-            _ => CassError::CASS_ERROR_LAST_ENTRY,
+            _ => CassError::CASS_ERROR_LIB_BAD_PARAMS,
         }
     }
 }
@@ -205,3 +253,436 @@ impl CassErrorMessage for str {
         self.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::tests::connection_setup_error;
+    use crate::testing::assert_cass_error_eq;
+    use scylla::deserialize::DeserializationError;
+    use scylla::frame::frame_errors::{
+        BodyExtensionsParseError, CqlErrorParseError, CqlResultParseError,
+        LowLevelDeserializationError,
+    };
+    use scylla::frame::value::SerializeValuesError;
+    use scylla::serialize::SerializationError;
+    use scylla::statement::Consistency;
+    use scylla_proxy::{RequestOpcode, RequestReaction};
+    use std::io;
+    use std::net::{Ipv4Addr, SocketAddr};
+    use std::sync::Arc;
+
+    #[test]
+    fn db_error_to_cass_error_test() {
+        let db_errors = [
+            (
+                DbError::ServerError,
+                CassError::CASS_ERROR_SERVER_SERVER_ERROR,
+            ),
+            (
+                DbError::ProtocolError,
+                CassError::CASS_ERROR_SERVER_PROTOCOL_ERROR,
+            ),
+            (
+                DbError::AuthenticationError,
+                CassError::CASS_ERROR_SERVER_BAD_CREDENTIALS,
+            ),
+            (
+                DbError::Unavailable {
+                    consistency: Consistency::Quorum,
+                    required: 2,
+                    alive: 1,
+                },
+                CassError::CASS_ERROR_SERVER_UNAVAILABLE,
+            ),
+            (DbError::Overloaded, CassError::CASS_ERROR_SERVER_OVERLOADED),
+            (
+                DbError::IsBootstrapping,
+                CassError::CASS_ERROR_SERVER_IS_BOOTSTRAPPING,
+            ),
+            (
+                DbError::TruncateError,
+                CassError::CASS_ERROR_SERVER_TRUNCATE_ERROR,
+            ),
+            (
+                DbError::WriteTimeout {
+                    consistency: Consistency::Quorum,
+                    received: 1,
+                    required: 2,
+                    write_type: WriteType::Simple,
+                },
+                CassError::CASS_ERROR_SERVER_WRITE_TIMEOUT,
+            ),
+            (
+                DbError::ReadTimeout {
+                    consistency: Consistency::Quorum,
+                    received: 1,
+                    required: 2,
+                    data_present: false,
+                },
+                CassError::CASS_ERROR_SERVER_READ_TIMEOUT,
+            ),
+            (
+                DbError::ReadFailure {
+                    consistency: Consistency::Quorum,
+                    received: 1,
+                    required: 2,
+                    numfailures: 1,
+                    data_present: false,
+                },
+                CassError::CASS_ERROR_SERVER_READ_FAILURE,
+            ),
+            (
+                DbError::FunctionFailure {
+                    keyspace: "ks".to_owned(),
+                    function: "fn".to_owned(),
+                    arg_types: vec!["int".to_owned()],
+                },
+                CassError::CASS_ERROR_SERVER_FUNCTION_FAILURE,
+            ),
+            (
+                DbError::WriteFailure {
+                    consistency: Consistency::Quorum,
+                    received: 1,
+                    required: 2,
+                    numfailures: 1,
+                    write_type: WriteType::Batch,
+                },
+                CassError::CASS_ERROR_SERVER_WRITE_FAILURE,
+            ),
+            (
+                DbError::SyntaxError,
+                CassError::CASS_ERROR_SERVER_SYNTAX_ERROR,
+            ),
+            (
+                DbError::Unauthorized,
+                CassError::CASS_ERROR_SERVER_UNAUTHORIZED,
+            ),
+            (DbError::Invalid, CassError::CASS_ERROR_SERVER_INVALID_QUERY),
+            (
+                DbError::ConfigError,
+                CassError::CASS_ERROR_SERVER_CONFIG_ERROR,
+            ),
+            (
+                DbError::AlreadyExists {
+                    keyspace: "ks".to_owned(),
+                    table: "t".to_owned(),
+                },
+                CassError::CASS_ERROR_SERVER_ALREADY_EXISTS,
+            ),
+            (
+                DbError::Unprepared {
+                    statement_id: Default::default(),
+                },
+                CassError::CASS_ERROR_SERVER_UNPREPARED,
+            ),
+            (
+                DbError::RateLimitReached {
+                    op_type: OperationType::Write,
+                    rejected_by_coordinator: true,
+                },
+                CassError::CASS_ERROR_SERVER_UNAVAILABLE,
+            ),
+            (
+                DbError::Other(0x1234),
+                CassError((CassErrorSource::CASS_ERROR_SOURCE_SERVER.0 << 24) | 0x1234),
+            ),
+        ];
+
+        for (db_error, expected) in db_errors {
+            unsafe {
+                assert_cass_error_eq!(db_error.to_cass_error(), expected);
+                assert_cass_error_eq!(
+                    QueryError::DbError(db_error.clone(), String::new()).to_cass_error(),
+                    expected
+                );
+                assert_cass_error_eq!(
+                    NewSessionError::DbError(db_error, String::new()).to_cass_error(),
+                    expected
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    #[ntest::timeout(5000)]
+    async fn query_error_to_cass_error_test() {
+        let broken_pool = |last_connection_error| {
+            QueryError::ConnectionPoolError(ConnectionPoolError::Broken {
+                last_connection_error,
+            })
+        };
+        let ssl_io_error = io::Error::other(openssl::error::ErrorStack::get());
+        // The node rejects the protocol version.
+        let protocol_rejected = connection_setup_error(
+            RequestOpcode::Options,
+            RequestReaction::forge().protocol_error(),
+        )
+        .await;
+        let startup_failed = connection_setup_error(
+            RequestOpcode::Startup,
+            RequestReaction::forge().server_error(),
+        )
+        .await;
+        let unexpected_eof = || {
+            LowLevelDeserializationError::IoError(Arc::new(io::Error::from(
+                io::ErrorKind::UnexpectedEof,
+            )))
+        };
+
+        let query_errors = [
+            (
+                QueryError::BadQuery(BadQuery::ValuesTooLongForKey(70000, 65535)),
+                CassError::CASS_ERROR_LIB_MESSAGE_ENCODE,
+            ),
+            (
+                QueryError::BadQuery(BadQuery::SerializeValuesError(
+                    SerializeValuesError::TooManyValues,
+                )),
+                CassError::CASS_ERROR_LIB_MESSAGE_ENCODE,
+            ),
+            (
+                QueryError::BadQuery(BadQuery::SerializationError(SerializationError::new(
+                    UnknownNamedParameterError("v".to_owned()),
+                ))),
+                CassError::CASS_ERROR_LIB_NAME_DOES_NOT_EXIST,
+            ),
+            (
+                QueryError::BadQuery(BadQuery::SerializationError(SerializationError::new(
+                    io::Error::from(io::ErrorKind::InvalidInput),
+                ))),
+                CassError::CASS_ERROR_LIB_MESSAGE_ENCODE,
+            ),
+            (
+                QueryError::BadQuery(BadQuery::BadKeyspaceName(BadKeyspaceName::Empty)),
+                CassError::CASS_ERROR_LIB_BAD_PARAMS,
+            ),
+            (
+                QueryError::BadQuery(BadQuery::TooManyQueriesInBatchStatement(70000)),
+                CassError::CASS_ERROR_LIB_BAD_PARAMS,
+            ),
+            (
+                QueryError::BadQuery(BadQuery::Other("bad query".to_owned())),
+                CassError::CASS_ERROR_LIB_BAD_PARAMS,
+            ),
+            (
+                QueryError::ProtocolError("protocol error"),
+                CassError::CASS_ERROR_SERVER_PROTOCOL_ERROR,
+            ),
+            (
+                QueryError::TimeoutError,
+                CassError::CASS_ERROR_LIB_REQUEST_TIMED_OUT,
+            ),
+            (
+                QueryError::RequestTimeout("timed out".to_owned()),
+                CassError::CASS_ERROR_LIB_REQUEST_TIMED_OUT,
+            ),
+            (
+                QueryError::UnableToAllocStreamId,
+                CassError::CASS_ERROR_LIB_NO_STREAMS,
+            ),
+            (
+                QueryError::EmptyPlan,
+                CassError::CASS_ERROR_LIB_INVALID_STATE,
+            ),
+            (
+                QueryError::CqlRequestSerialization(
+                    CqlRequestSerializationError::SnapCompressError(Arc::new(io::Error::from(
+                        io::ErrorKind::InvalidData,
+                    ))),
+                ),
+                CassError::CASS_ERROR_LIB_MESSAGE_ENCODE,
+            ),
+            (
+                QueryError::BodyExtensionsParseError(
+                    BodyExtensionsParseError::SnapDecompressError(Arc::new(io::Error::from(
+                        io::ErrorKind::InvalidData,
+                    ))),
+                ),
+                CassError::CASS_ERROR_LIB_UNEXPECTED_RESPONSE,
+            ),
+            (
+                QueryError::CqlResultParseError(CqlResultParseError::UnknownResultId(0x42)),
+                CassError::CASS_ERROR_LIB_UNEXPECTED_RESPONSE,
+            ),
+            (
+                QueryError::CqlErrorParseError(CqlErrorParseError::ErrorCodeParseError(
+                    unexpected_eof(),
+                )),
+                CassError::CASS_ERROR_LIB_UNEXPECTED_RESPONSE,
+            ),
+            (
+                QueryError::MetadataError(MetadataError::Peers(PeersMetadataError::EmptyPeers)),
+                CassError::CASS_ERROR_LIB_INVALID_STATE,
+            ),
+            (
+                QueryError::MetadataError(MetadataError::ConnectionPoolError(
+                    ConnectionPoolError::Broken {
+                        last_connection_error: ConnectionError::ConnectTimeout,
+                    },
+                )),
+                CassError::CASS_ERROR_LIB_UNABLE_TO_CONNECT,
+            ),
+            (
+                QueryError::BrokenConnection(BrokenConnectionErrorKind::ChannelError.into()),
+                CassError::CASS_ERROR_LIB_UNABLE_TO_CONNECT,
+            ),
+            (
+                QueryError::ConnectionPoolError(ConnectionPoolError::Initializing),
+                CassError::CASS_ERROR_LIB_UNABLE_TO_CONNECT,
+            ),
+            (
+                QueryError::ConnectionPoolError(ConnectionPoolError::NodeDisabledByHostFilter),
+                CassError::CASS_ERROR_LIB_UNABLE_TO_CONNECT,
+            ),
+            (
+                broken_pool(ConnectionError::ConnectTimeout),
+                CassError::CASS_ERROR_LIB_UNABLE_TO_CONNECT,
+            ),
+            (
+                broken_pool(ConnectionError::NoSourcePortForShard(1)),
+                CassError::CASS_ERROR_LIB_UNABLE_TO_CONNECT,
+            ),
+            (
+                broken_pool(ConnectionError::IoError(Arc::new(io::Error::from(
+                    io::ErrorKind::ConnectionRefused,
+                )))),
+                CassError::CASS_ERROR_LIB_UNABLE_TO_CONNECT,
+            ),
+            (
+                broken_pool(ConnectionError::IoError(Arc::new(ssl_io_error))),
+                CassError::CASS_ERROR_SSL_PROTOCOL_ERROR,
+            ),
+            (
+                broken_pool(ConnectionError::TranslationError(
+                    TranslationError::NoRuleForAddress(SocketAddr::from((
+                        Ipv4Addr::LOCALHOST,
+                        9042,
+                    ))),
+                )),
+                CassError::CASS_ERROR_LIB_HOST_RESOLUTION,
+            ),
+            (
+                broken_pool(protocol_rejected),
+                CassError::CASS_ERROR_LIB_UNABLE_TO_DETERMINE_PROTOCOL,
+            ),
+            (
+                broken_pool(startup_failed),
+                CassError::CASS_ERROR_SERVER_SERVER_ERROR,
+            ),
+        ];
+
+        for (query_error, expected) in query_errors {
+            unsafe {
+                assert_cass_error_eq!(query_error.to_cass_error(), expected);
+                assert_cass_error_eq!(
+                    CassErrorResult::Query(query_error).to_cass_error(),
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn new_session_error_to_cass_error_test() {
+        let new_session_errors = [
+            (
+                NewSessionError::FailedToResolveAnyHostname(vec!["unknown.host".to_owned()]),
+                CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
+            ),
+            (
+                NewSessionError::EmptyKnownNodesList,
+                CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
+            ),
+            (
+                NewSessionError::BadQuery(BadQuery::BadKeyspaceName(BadKeyspaceName::Empty)),
+                CassError::CASS_ERROR_LIB_BAD_PARAMS,
+            ),
+            (
+                NewSessionError::ProtocolError("protocol error"),
+                CassError::CASS_ERROR_LIB_UNABLE_TO_DETERMINE_PROTOCOL,
+            ),
+            (
+                NewSessionError::UnableToAllocStreamId,
+                CassError::CASS_ERROR_LIB_NO_STREAMS,
+            ),
+            (
+                NewSessionError::RequestTimeout("timed out".to_owned()),
+                CassError::CASS_ERROR_LIB_REQUEST_TIMED_OUT,
+            ),
+            (
+                NewSessionError::CqlRequestSerialization(
+                    CqlRequestSerializationError::SnapCompressError(Arc::new(io::Error::from(
+                        io::ErrorKind::InvalidData,
+                    ))),
+                ),
+                CassError::CASS_ERROR_LIB_MESSAGE_ENCODE,
+            ),
+            (
+                NewSessionError::BodyExtensionsParseError(
+                    BodyExtensionsParseError::SnapDecompressError(Arc::new(io::Error::from(
+                        io::ErrorKind::InvalidData,
+                    ))),
+                ),
+                CassError::CASS_ERROR_LIB_UNEXPECTED_RESPONSE,
+            ),
+            (
+                NewSessionError::EmptyPlan,
+                CassError::CASS_ERROR_LIB_INVALID_STATE,
+            ),
+            (
+                NewSessionError::CqlResultParseError(CqlResultParseError::UnknownResultId(0x42)),
+                CassError::CASS_ERROR_LIB_UNEXPECTED_RESPONSE,
+            ),
+            (
+                NewSessionError::CqlErrorParseError(CqlErrorParseError::ErrorCodeParseError(
+                    LowLevelDeserializationError::IoError(Arc::new(io::Error::from(
+                        io::ErrorKind::UnexpectedEof,
+                    ))),
+                )),
+                CassError::CASS_ERROR_LIB_UNEXPECTED_RESPONSE,
+            ),
+            (
+                NewSessionError::MetadataError(MetadataError::Peers(
+                    PeersMetadataError::EmptyPeers,
+                )),
+                CassError::CASS_ERROR_LIB_INVALID_STATE,
+            ),
+            (
+                NewSessionError::MetadataError(MetadataError::ConnectionPoolError(
+                    ConnectionPoolError::Broken {
+                        last_connection_error: ConnectionError::ConnectTimeout,
+                    },
+                )),
+                CassError::CASS_ERROR_LIB_UNABLE_TO_CONNECT,
+            ),
+            (
+                NewSessionError::ConnectionPoolError(ConnectionPoolError::Initializing),
+                CassError::CASS_ERROR_LIB_UNABLE_TO_CONNECT,
+            ),
+            (
+                NewSessionError::BrokenConnection(BrokenConnectionErrorKind::ChannelError.into()),
+                CassError::CASS_ERROR_LIB_UNABLE_TO_CONNECT,
+            ),
+        ];
+
+        for (new_session_error, expected) in new_session_errors {
+            unsafe {
+                assert_cass_error_eq!(new_session_error.to_cass_error(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn deserialization_error_to_cass_error_test() {
+        let error = CassErrorResult::Deserialization(DeserializationError::new(io::Error::from(
+            io::ErrorKind::InvalidData,
+        )));
+        unsafe {
+            assert_cass_error_eq!(
+                error.to_cass_error(),
+                CassError::CASS_ERROR_LIB_INVALID_DATA
+            );
+        }
+    }
+}
@@ -107,18 +107,27 @@ impl UDTDataType {
         //// treated as equivalent in this scenario, by simply looking at the first N
         //// mutual fields they should share.
         //
-        // Iterator returned from zip() is perfect for checking the first mutual fields.
-        for (field, other_field) in self.field_types.iter().zip(other.field_types.iter()) {
-            // Compare field names.
-            if field.0 != other_field.0 {
+        // Unlike cpp-driver, fields are matched by name, as UDT values are serialized
+        // by field name against the server's definition (see `serialize_udt`).
+        // Thus, every field of the type with fewer fields must be present
+        // in the other type, but not necessarily at the same position.
+        let (fields, other_fields) = if self.field_types.len() <= other.field_types.len() {
+            (&self.field_types, &other.field_types)
+        } else {
+            (&other.field_types, &self.field_types)
+        };
+        for (field_name, field_type) in fields {
+            let Some((_, other_field_type)) = other_fields
+                .iter()
+                .find(|(other_field_name, _)| other_field_name == field_name)
+            else {
                 return false;
-            }
+            };
             // Compare field types.
             if unsafe {
-                !field
-                    .1
+                !field_type
                     .get_unchecked()
-                    .typecheck_equals(other_field.1.get_unchecked())
+                    .typecheck_equals(other_field_type.get_unchecked())
             } {
                 return false;
             }
@@ -391,6 +400,183 @@ pub fn get_column_type_from_cql_type(
     CassDataType::new(inner)
 }
 
+/// Parses a CQL type in the format used by the `system_schema` tables, e.g. `frozen<map<text, int>>`.
+/// User defined types are resolved using `user_defined_types`. Types that can't be parsed
+/// are returned as custom types.
+pub fn get_column_type_from_cql_type_string(
+    type_string: &str,
+    user_defined_types: &HashMap<String, Arc<UserDefinedType>>,
+    keyspace_name: &str,
+) -> CassDataType {
+    let mut parser = CqlTypeParser {
+        input: type_string,
+        pos: 0,
+        user_defined_types,
+        keyspace_name,
+    };
+    let inner = parser
+        .parse_type(false)
+        .filter(|_| parser.is_at_end())
+        .unwrap_or_else(|| CassDataTypeInner::Custom(type_string.to_owned()));
+
+    CassDataType::new(inner)
+}
+
+struct CqlTypeParser<'a> {
+    input: &'a str,
+    pos: usize,
+    user_defined_types: &'a HashMap<String, Arc<UserDefinedType>>,
+    keyspace_name: &'a str,
+}
+
+impl CqlTypeParser<'_> {
+    fn rest(&self) -> &str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn is_at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.rest().is_empty()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Parses an identifier, either unquoted or double-quoted (with `""` as an escaped quote).
+    fn identifier(&mut self) -> Option<String> {
+        self.skip_whitespace();
+        if self.eat('"') {
+            let mut name = String::new();
+            loop {
+                let c = self.rest().chars().next()?;
+                self.pos += c.len_utf8();
+                if c == '"' {
+                    if !self.rest().starts_with('"') {
+                        return Some(name);
+                    }
+                    self.pos += 1;
+                }
+                name.push(c);
+            }
+        }
+
+        let len = self
+            .rest()
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(self.rest().len());
+        let name = self.rest()[..len].to_owned();
+        self.pos += len;
+        (!name.is_empty()).then_some(name)
+    }
+
+    /// Parses `<type, ...>`.
+    fn parameters(&mut self, frozen: bool) -> Option<Vec<Arc<CassDataType>>> {
+        if !self.eat('<') {
+            return None;
+        }
+        let mut parameters = Vec::new();
+        loop {
+            parameters.push(Arc::new(CassDataType::new(self.parse_type(frozen)?)));
+            if self.eat('>') {
+                return Some(parameters);
+            }
+            if !self.eat(',') {
+                return None;
+            }
+        }
+    }
+
+    fn parse_type(&mut self, frozen: bool) -> Option<CassDataTypeInner> {
+        self.skip_whitespace();
+        if self.rest().starts_with('\'') {
+            // Custom type, given by its class name.
+            let end = self.rest()[1..].find('\'')? + 1;
+            let class_name = self.rest()[1..end].to_owned();
+            self.pos += end + 1;
+            return Some(CassDataTypeInner::Custom(class_name));
+        }
+
+        let name = self.identifier()?;
+        let value_type = match name.to_lowercase().as_str() {
+            "frozen" => {
+                if !self.eat('<') {
+                    return None;
+                }
+                let inner = self.parse_type(true)?;
+                return self.eat('>').then_some(inner);
+            }
+            "list" | "set" => {
+                let mut parameters = self.parameters(frozen)?;
+                if parameters.len() != 1 {
+                    return None;
+                }
+                let typ = parameters.pop();
+                return Some(if name.eq_ignore_ascii_case("list") {
+                    CassDataTypeInner::List { typ, frozen }
+                } else {
+                    CassDataTypeInner::Set { typ, frozen }
+                });
+            }
+            "map" => {
+                let mut parameters = self.parameters(frozen)?;
+                if parameters.len() != 2 {
+                    return None;
+                }
+                let value = parameters.pop()?;
+                let key = parameters.pop()?;
+                return Some(CassDataTypeInner::Map {
+                    typ: MapDataType::KeyAndValue(key, value),
+                    frozen,
+                });
+            }
+            "tuple" => return Some(CassDataTypeInner::Tuple(self.parameters(frozen)?)),
+            "ascii" => CassValueType::CASS_VALUE_TYPE_ASCII,
+            "bigint" => CassValueType::CASS_VALUE_TYPE_BIGINT,
+            "blob" => CassValueType::CASS_VALUE_TYPE_BLOB,
+            "boolean" => CassValueType::CASS_VALUE_TYPE_BOOLEAN,
+            "counter" => CassValueType::CASS_VALUE_TYPE_COUNTER,
+            "date" => CassValueType::CASS_VALUE_TYPE_DATE,
+            "decimal" => CassValueType::CASS_VALUE_TYPE_DECIMAL,
+            "double" => CassValueType::CASS_VALUE_TYPE_DOUBLE,
+            "duration" => CassValueType::CASS_VALUE_TYPE_DURATION,
+            "float" => CassValueType::CASS_VALUE_TYPE_FLOAT,
+            "inet" => CassValueType::CASS_VALUE_TYPE_INET,
+            "int" => CassValueType::CASS_VALUE_TYPE_INT,
+            "smallint" => CassValueType::CASS_VALUE_TYPE_SMALL_INT,
+            "text" => CassValueType::CASS_VALUE_TYPE_TEXT,
+            "varchar" => CassValueType::CASS_VALUE_TYPE_VARCHAR,
+            "time" => CassValueType::CASS_VALUE_TYPE_TIME,
+            "timestamp" => CassValueType::CASS_VALUE_TYPE_TIMESTAMP,
+            "timeuuid" => CassValueType::CASS_VALUE_TYPE_TIMEUUID,
+            "tinyint" => CassValueType::CASS_VALUE_TYPE_TINY_INT,
+            "uuid" => CassValueType::CASS_VALUE_TYPE_UUID,
+            "varint" => CassValueType::CASS_VALUE_TYPE_VARINT,
+            _ => {
+                return Some(CassDataTypeInner::UDT(UDTDataType::create_with_params(
+                    self.user_defined_types,
+                    self.keyspace_name,
+                    &name,
+                    frozen,
+                )))
+            }
+        };
+
+        Some(CassDataTypeInner::Value(value_type))
+    }
+}
+
 impl CassDataTypeInner {
     fn get_sub_data_type(&self, index: usize) -> Option<&Arc<CassDataType>> {
         match self {
@@ -964,3 +1150,75 @@ pub fn make_batch_type(type_: CassBatchType) -> Option<BatchType> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(type_string: &str) -> CassDataTypeInner {
+        let data_type = get_column_type_from_cql_type_string(type_string, &HashMap::new(), "ks");
+        unsafe { data_type.get_unchecked() }.clone()
+    }
+
+    fn value(value_type: CassValueType) -> Arc<CassDataType> {
+        CassDataType::new_arced(CassDataTypeInner::Value(value_type))
+    }
+
+    #[test]
+    fn cql_type_string_test() {
+        assert_eq!(
+            parse("int"),
+            CassDataTypeInner::Value(CassValueType::CASS_VALUE_TYPE_INT)
+        );
+        assert_eq!(
+            parse(" list<text>"),
+            CassDataTypeInner::List {
+                typ: Some(value(CassValueType::CASS_VALUE_TYPE_TEXT)),
+                frozen: false,
+            }
+        );
+        assert_eq!(
+            parse("frozen<map<uuid, set<bigint>>>"),
+            CassDataTypeInner::Map {
+                typ: MapDataType::KeyAndValue(
+                    value(CassValueType::CASS_VALUE_TYPE_UUID),
+                    CassDataType::new_arced(CassDataTypeInner::Set {
+                        typ: Some(value(CassValueType::CASS_VALUE_TYPE_BIGINT)),
+                        frozen: true,
+                    }),
+                ),
+                frozen: true,
+            }
+        );
+        assert_eq!(
+            parse("tuple<int, varchar>"),
+            CassDataTypeInner::Tuple(vec![
+                value(CassValueType::CASS_VALUE_TYPE_INT),
+                value(CassValueType::CASS_VALUE_TYPE_VARCHAR),
+            ])
+        );
+        assert_eq!(
+            parse("frozen<\"Address\">"),
+            CassDataTypeInner::UDT(UDTDataType {
+                field_types: vec![],
+                keyspace: "ks".to_owned(),
+                name: "Address".to_owned(),
+                frozen: true,
+            })
+        );
+        assert_eq!(
+            parse("'org.apache.cassandra.db.marshal.DynamicCompositeType'"),
+            CassDataTypeInner::Custom(
+                "org.apache.cassandra.db.marshal.DynamicCompositeType".to_owned()
+            )
+        );
+
+        // Malformed types are kept as custom types.
+        for malformed in ["list<int", "map<int>", "int>", ""] {
+            assert_eq!(
+                parse(malformed),
+                CassDataTypeInner::Custom(malformed.to_owned())
+            );
+        }
+    }
+}
@@ -1,10 +1,12 @@
 use crate::argconv::*;
+use crate::callback_executor::{CallbackExecutor, CassCallbackExecutorFunc};
 use crate::cass_error::CassError;
 use crate::cass_types::CassConsistency;
 use crate::exec_profile::{exec_profile_builder_modify, CassExecProfile, ExecProfileName};
 use crate::future::CassFuture;
 use crate::retry_policy::CassRetryPolicy;
 use crate::retry_policy::RetryPolicy::*;
+use crate::schema_change::{CassSchemaChangeCallback, SchemaChangeListener};
 use crate::ssl::CassSsl;
 use crate::types::*;
 use crate::uuid::CassUuid;
@@ -13,16 +15,20 @@ use openssl_sys::SSL_CTX_up_ref;
 use scylla::execution_profile::ExecutionProfileBuilder;
 use scylla::frame::Compression;
 use scylla::load_balancing::LatencyAwarenessBuilder;
-use scylla::load_balancing::{DefaultPolicyBuilder, LoadBalancingPolicy};
+use scylla::load_balancing::{
+    DefaultPolicyBuilder, FallbackPlan, LoadBalancingPolicy, RoutingInfo,
+};
 use scylla::retry_policy::RetryPolicy;
+use scylla::routing::Shard;
 use scylla::speculative_execution::SimpleSpeculativeExecutionPolicy;
 use scylla::statement::{Consistency, SerialConsistency};
-use scylla::transport::SelfIdentity;
+use scylla::transport::errors::QueryError;
+use scylla::transport::{ClusterData, NodeRef, SelfIdentity};
 use scylla::{SessionBuilder, SessionConfig};
 use std::collections::HashMap;
 use std::convert::TryInto;
 use std::future::Future;
-use std::os::raw::{c_char, c_int, c_uint};
+use std::os::raw::{c_char, c_int, c_uint, c_void};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -59,6 +65,7 @@ pub(crate) struct LoadBalancingConfig {
     pub(crate) load_balancing_kind: Option<LoadBalancingKind>,
     pub(crate) latency_awareness_enabled: bool,
     pub(crate) latency_awareness_builder: LatencyAwarenessBuilder,
+    pub(crate) lwt_routing_optimization_enabled: bool,
 }
 impl LoadBalancingConfig {
     // This is `async` to prevent running this function from beyond tokio context,
@@ -94,7 +101,16 @@ impl LoadBalancingConfig {
         if self.latency_awareness_enabled {
             builder = builder.latency_awareness(self.latency_awareness_builder);
         }
-        builder.build()
+
+        // The default policy routes statements confirmed as LWT at prepare time
+        // to the replicas in ring order (primary replica first), ignoring shuffling,
+        // so that concurrent Paxos rounds don't contend on different coordinators.
+        let policy = builder.build();
+        if self.lwt_routing_optimization_enabled {
+            policy
+        } else {
+            Arc::new(LwtUnawarePolicy(policy))
+        }
     }
 }
 impl Default for LoadBalancingConfig {
@@ -105,8 +121,93 @@ impl Default for LoadBalancingConfig {
             load_balancing_kind: None,
             latency_awareness_enabled: false,
             latency_awareness_builder: Default::default(),
+            lwt_routing_optimization_enabled: true,
+        }
+    }
+}
+
+/// Load balancing policy which routes LWT statements like any other ones,
+/// i.e. it hides the LWT flag from the wrapped policy.
+#[derive(Debug)]
+struct LwtUnawarePolicy(Arc<dyn LoadBalancingPolicy>);
+
+impl LwtUnawarePolicy {
+    fn lwt_unaware<'a>(request: &RoutingInfo<'a>) -> RoutingInfo<'a> {
+        RoutingInfo {
+            is_confirmed_lwt: false,
+            ..request.clone()
         }
     }
+
+    // Nodes picked by the wrapped policy for the modified routing info borrow it,
+    // so they are looked up again in the cluster data.
+    fn node_in_cluster<'a>(cluster: &'a ClusterData, node: NodeRef<'_>) -> Option<NodeRef<'a>> {
+        cluster
+            .get_nodes_info()
+            .iter()
+            .find(|cluster_node| cluster_node.host_id == node.host_id)
+    }
+}
+
+impl LoadBalancingPolicy for LwtUnawarePolicy {
+    fn pick<'a>(
+        &'a self,
+        request: &'a RoutingInfo,
+        cluster: &'a ClusterData,
+    ) -> Option<(NodeRef<'a>, Option<Shard>)> {
+        if !request.is_confirmed_lwt {
+            return self.0.pick(request, cluster);
+        }
+
+        let request = Self::lwt_unaware(request);
+        let (node, shard) = self.0.pick(&request, cluster)?;
+        Some((Self::node_in_cluster(cluster, node)?, shard))
+    }
+
+    fn fallback<'a>(
+        &'a self,
+        request: &'a RoutingInfo,
+        cluster: &'a ClusterData,
+    ) -> FallbackPlan<'a> {
+        if !request.is_confirmed_lwt {
+            return self.0.fallback(request, cluster);
+        }
+
+        // The plan of the wrapped policy borrows the modified routing info,
+        // which doesn't outlive this call, so the plan is computed eagerly.
+        let request = Self::lwt_unaware(request);
+        let nodes: HashMap<uuid::Uuid, NodeRef<'a>> = cluster
+            .get_nodes_info()
+            .iter()
+            .map(|node| (node.host_id, node))
+            .collect();
+        let plan: Vec<_> = self
+            .0
+            .fallback(&request, cluster)
+            .filter_map(|(node, shard)| Some((*nodes.get(&node.host_id)?, shard)))
+            .collect();
+        Box::new(plan.into_iter())
+    }
+
+    fn on_query_success(&self, request: &RoutingInfo, latency: Duration, node: NodeRef<'_>) {
+        self.0
+            .on_query_success(&Self::lwt_unaware(request), latency, node)
+    }
+
+    fn on_query_failure(
+        &self,
+        request: &RoutingInfo,
+        latency: Duration,
+        node: NodeRef<'_>,
+        error: &QueryError,
+    ) {
+        self.0
+            .on_query_failure(&Self::lwt_unaware(request), latency, node, error)
+    }
+
+    fn name(&self) -> String {
+        format!("LwtUnawarePolicy({})", self.0.name())
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -137,6 +238,10 @@ pub struct CassCluster {
     auth_password: Option<String>,
 
     client_id: Option<uuid::Uuid>,
+
+    schema_change_listener: Option<SchemaChangeListener>,
+
+    callback_executor: CallbackExecutor,
 }
 
 impl CassCluster {
@@ -159,10 +264,28 @@ impl CassCluster {
         &self.contact_points
     }
 
+    /// Contact points, along with the port to connect to.
+    pub(crate) fn get_known_nodes(&self) -> Vec<String> {
+        self.contact_points
+            .iter()
+            .map(|cp| format!("{}:{}", cp, self.port))
+            .collect()
+    }
+
     #[inline]
     pub(crate) fn get_client_id(&self) -> Option<uuid::Uuid> {
         self.client_id
     }
+
+    #[inline]
+    pub(crate) fn get_schema_change_listener(&self) -> Option<SchemaChangeListener> {
+        self.schema_change_listener
+    }
+
+    #[inline]
+    pub(crate) fn get_callback_executor(&self) -> CallbackExecutor {
+        self.callback_executor.clone()
+    }
 }
 
 impl BoxFFI for CassCluster {}
@@ -174,10 +297,7 @@ pub struct CassCustomPayload;
 pub fn build_session_builder(
     cluster: &CassCluster,
 ) -> impl Future<Output = SessionBuilder> + 'static {
-    let known_nodes = cluster
-        .contact_points
-        .iter()
-        .map(|cp| format!("{}:{}", cp, cluster.port));
+    let known_nodes = cluster.get_known_nodes();
     let mut execution_profile_builder = cluster.default_execution_profile_builder.clone();
     let load_balancing_config = cluster.load_balancing_config.clone();
     let mut session_builder = cluster.session_builder.clone().known_nodes(known_nodes);
@@ -211,6 +331,11 @@ pub unsafe extern "C" fn cass_cluster_new() -> *mut CassCluster {
             .fetch_schema_metadata(DEFAULT_DO_FETCH_SCHEMA_METADATA)
             .schema_agreement_timeout(DEFAULT_MAX_SCHEMA_WAIT_TIME)
             .schema_agreement_interval(DEFAULT_SCHEMA_AGREEMENT_INTERVAL)
+            // Schema agreement is awaited by `cass_session_execute` itself, once the
+            // schema altering request has completed, so that a timeout is reported via
+            // `cass_future_schema_in_agreement` instead of failing the request, and the
+            // wait doesn't count towards the request timeout.
+            .auto_await_schema_agreement(false)
             .tcp_nodelay(DEFAULT_SET_TCP_NO_DELAY)
             .connection_timeout(DEFAULT_CONNECT_TIMEOUT)
             .keepalive_interval(DEFAULT_KEEPALIVE_INTERVAL)
@@ -230,6 +355,8 @@ pub unsafe extern "C" fn cass_cluster_new() -> *mut CassCluster {
         execution_profile_map: Default::default(),
         load_balancing_config: Default::default(),
         client_id: None,
+        schema_change_listener: None,
+        callback_executor: CallbackExecutor::Inline,
     }))
 }
 
@@ -365,6 +492,41 @@ pub unsafe extern "C" fn cass_cluster_set_client_id(
         .set_client_id(client_uuid_str)
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_schema_change_listener_callback(
+    cluster_raw: *mut CassCluster,
+    callback: CassSchemaChangeCallback,
+    data: *mut c_void,
+) -> CassError {
+    let cluster = BoxFFI::as_mut_ref(cluster_raw);
+    cluster.schema_change_listener = SchemaChangeListener::new(callback, data);
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_callback_thread_pool_size(
+    cluster_raw: *mut CassCluster,
+    num_threads: c_uint,
+) -> CassError {
+    let cluster = BoxFFI::as_mut_ref(cluster_raw);
+    cluster.callback_executor = CallbackExecutor::thread_pool(num_threads as usize);
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_callback_executor(
+    cluster_raw: *mut CassCluster,
+    executor: CassCallbackExecutorFunc,
+    data: *mut c_void,
+) -> CassError {
+    let cluster = BoxFFI::as_mut_ref(cluster_raw);
+    cluster.callback_executor = CallbackExecutor::user(executor, data);
+
+    CassError::CASS_OK
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_cluster_set_use_schema(
     cluster_raw: *mut CassCluster,
@@ -797,6 +959,18 @@ pub unsafe extern "C" fn cass_cluster_set_token_aware_routing_shuffle_replicas(
         .token_aware_shuffling_replicas_enabled = enabled != 0;
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_cluster_set_lwt_routing_optimization(
+    cluster_raw: *mut CassCluster,
+    enabled: cass_bool_t,
+) {
+    let cluster = BoxFFI::as_mut_ref(cluster_raw);
+
+    cluster
+        .load_balancing_config
+        .lwt_routing_optimization_enabled = enabled != 0;
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_cluster_set_retry_policy(
     cluster_raw: *mut CassCluster,
@@ -950,11 +1124,14 @@ mod tests {
         argconv::make_c_str,
         cass_error::CassError,
         exec_profile::{cass_execution_profile_free, cass_execution_profile_new},
+        session::tests::{generic_drop_queries_rules, handshake_rules, test_with_one_proxy_one},
     };
     use assert_matches::assert_matches;
+    use scylla_proxy::RunningProxy;
     use std::{
         collections::HashSet,
         convert::{TryFrom, TryInto},
+        net::SocketAddr,
         os::raw::c_char,
     };
 
@@ -1214,4 +1391,95 @@ mod tests {
             cass_cluster_free(cluster_raw);
         }
     }
+
+    /// Records whether the routed requests were flagged as LWT
+    /// and plans all nodes of the cluster.
+    #[derive(Debug, Default)]
+    struct LwtRecordingPolicy {
+        seen_lwt_flags: std::sync::Mutex<Vec<bool>>,
+    }
+
+    impl LwtRecordingPolicy {
+        fn record(&self, request: &RoutingInfo) {
+            self.seen_lwt_flags
+                .lock()
+                .unwrap()
+                .push(request.is_confirmed_lwt);
+        }
+    }
+
+    impl LoadBalancingPolicy for LwtRecordingPolicy {
+        fn pick<'a>(
+            &'a self,
+            request: &'a RoutingInfo,
+            cluster: &'a ClusterData,
+        ) -> Option<(NodeRef<'a>, Option<Shard>)> {
+            self.record(request);
+            cluster.get_nodes_info().first().map(|node| (node, None))
+        }
+
+        fn fallback<'a>(
+            &'a self,
+            request: &'a RoutingInfo,
+            cluster: &'a ClusterData,
+        ) -> FallbackPlan<'a> {
+            self.record(request);
+            Box::new(cluster.get_nodes_info().iter().map(|node| (node, None)))
+        }
+
+        fn on_query_success(&self, request: &RoutingInfo, _latency: Duration, _node: NodeRef<'_>) {
+            self.record(request);
+        }
+
+        fn name(&self) -> String {
+            "LwtRecordingPolicy".to_owned()
+        }
+    }
+
+    #[tokio::test]
+    #[ntest::timeout(5000)]
+    async fn test_lwt_unaware_policy() {
+        test_with_one_proxy_one(
+            test_lwt_unaware_policy_do,
+            handshake_rules()
+                .into_iter()
+                .chain(generic_drop_queries_rules()),
+        )
+        .await;
+    }
+
+    fn test_lwt_unaware_policy_do(node_addr: SocketAddr, proxy: RunningProxy) -> RunningProxy {
+        let session = crate::RUNTIME
+            .block_on(SessionBuilder::new().known_node_addr(node_addr).build())
+            .unwrap();
+        let cluster_data = session.get_cluster_data();
+        let cluster_nodes = cluster_data.get_nodes_info();
+
+        let inner = Arc::new(LwtRecordingPolicy::default());
+        let policy = LwtUnawarePolicy(inner.clone());
+        for is_confirmed_lwt in [true, false] {
+            let request = RoutingInfo {
+                is_confirmed_lwt,
+                ..Default::default()
+            };
+
+            let (node, shard) = policy.pick(&request, &cluster_data).unwrap();
+            assert!(Arc::ptr_eq(node, &cluster_nodes[0]));
+            assert_eq!(shard, None);
+
+            let plan: Vec<_> = policy.fallback(&request, &cluster_data).collect();
+            assert_eq!(plan.len(), cluster_nodes.len());
+            for ((node, shard), cluster_node) in plan.into_iter().zip(cluster_nodes) {
+                assert!(Arc::ptr_eq(node, cluster_node));
+                assert_eq!(shard, None);
+            }
+
+            policy.on_query_success(&request, Duration::from_millis(1), &cluster_nodes[0]);
+        }
+
+        // The LWT flag is never seen by the wrapped policy.
+        assert_eq!(*inner.seen_lwt_flags.lock().unwrap(), vec![false; 6]);
+
+        proxy
+    }
 }
@@ -216,6 +216,8 @@ make_binders!(uuid, cass_collection_append_uuid);
 make_binders!(inet, cass_collection_append_inet);
 make_binders!(duration, cass_collection_append_duration);
 make_binders!(decimal, cass_collection_append_decimal);
+make_binders!(varint_string, cass_collection_append_varint_string);
+make_binders!(decimal_string, cass_collection_append_decimal_string);
 make_binders!(collection, cass_collection_append_collection);
 make_binders!(tuple, cass_collection_append_tuple);
 make_binders!(user_type, cass_collection_append_user_type);
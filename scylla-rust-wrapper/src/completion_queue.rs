@@ -0,0 +1,235 @@
+//! Completion queue, which lets event loops (epoll, io_uring, libuv etc.)
+//! learn about completed futures by polling a file descriptor, instead of
+//! handling the completion in callbacks invoked on the driver's threads.
+
+use crate::argconv::*;
+use crate::cass_error::CassError;
+use crate::future::CassFuture;
+use std::collections::VecDeque;
+use std::io;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::os::raw::{c_int, c_void};
+use std::sync::{Arc, Mutex};
+
+/// File descriptor, which is readable as long as the queue is not empty.
+struct ReadinessFd {
+    read_fd: OwnedFd,
+    // `None` for eventfd, which is both read from and written to.
+    write_fd: Option<OwnedFd>,
+}
+
+impl ReadinessFd {
+    #[cfg(target_os = "linux")]
+    fn new() -> io::Result<Self> {
+        // In semaphore mode, each read decrements the counter by one.
+        let fd = unsafe {
+            libc::eventfd(
+                0,
+                libc::EFD_SEMAPHORE | libc::EFD_NONBLOCK | libc::EFD_CLOEXEC,
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Self {
+            read_fd: unsafe { OwnedFd::from_raw_fd(fd) },
+            write_fd: None,
+        })
+    }
+
+    // There is no eventfd beyond Linux - fall back to a pipe, with a byte
+    // written per completed future.
+    #[cfg(not(target_os = "linux"))]
+    fn new() -> io::Result<Self> {
+        let mut fds: [c_int; 2] = [0; 2];
+        if unsafe { libc::pipe(fds.as_mut_ptr()) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let (read_fd, write_fd) =
+            unsafe { (OwnedFd::from_raw_fd(fds[0]), OwnedFd::from_raw_fd(fds[1])) };
+
+        for fd in fds {
+            unsafe {
+                libc::fcntl(fd, libc::F_SETFL, libc::O_NONBLOCK);
+                libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC);
+            }
+        }
+
+        Ok(Self {
+            read_fd,
+            write_fd: Some(write_fd),
+        })
+    }
+
+    #[cfg(target_os = "linux")]
+    const TOKEN: [u8; 8] = 1u64.to_ne_bytes();
+    #[cfg(not(target_os = "linux"))]
+    const TOKEN: [u8; 1] = [1];
+
+    fn signal(&self) {
+        let fd = self.write_fd.as_ref().unwrap_or(&self.read_fd);
+        let token = Self::TOKEN;
+        // Can't fail, unless the counter (or pipe buffer) overflows.
+        unsafe { libc::write(fd.as_raw_fd(), token.as_ptr() as *const c_void, token.len()) };
+    }
+
+    fn consume(&self) {
+        let mut token = Self::TOKEN;
+        unsafe {
+            libc::read(
+                self.read_fd.as_raw_fd(),
+                token.as_mut_ptr() as *mut c_void,
+                token.len(),
+            )
+        };
+    }
+}
+
+pub struct CassCompletionQueue {
+    completed: Mutex<VecDeque<Arc<CassFuture>>>,
+    fd: ReadinessFd,
+}
+
+impl ArcFFI for CassCompletionQueue {}
+
+impl CassCompletionQueue {
+    pub(crate) fn push(&self, future: Arc<CassFuture>) {
+        // The fd is signalled under the lock, to keep it in sync with the queue.
+        let mut completed = self.completed.lock().unwrap();
+        completed.push_back(future);
+        self.fd.signal();
+    }
+
+    fn pop(&self) -> Option<Arc<CassFuture>> {
+        let mut completed = self.completed.lock().unwrap();
+        let future = completed.pop_front()?;
+        self.fd.consume();
+        Some(future)
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_completion_queue_new() -> *mut CassCompletionQueue {
+    match ReadinessFd::new() {
+        Ok(fd) => ArcFFI::into_ptr(Arc::new(CassCompletionQueue {
+            completed: Mutex::new(VecDeque::new()),
+            fd,
+        })) as *mut CassCompletionQueue,
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_completion_queue_free(queue_raw: *mut CassCompletionQueue) {
+    ArcFFI::free(queue_raw);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_completion_queue_fd(queue_raw: *const CassCompletionQueue) -> c_int {
+    ArcFFI::as_ref(queue_raw).fd.read_fd.as_raw_fd()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_completion_queue_attach(
+    queue_raw: *const CassCompletionQueue,
+    future_raw: *const CassFuture,
+) -> CassError {
+    let queue = ArcFFI::cloned_from_ptr(queue_raw);
+    let future = ArcFFI::cloned_from_ptr(future_raw);
+
+    future.set_completion_queue(&queue)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_completion_queue_pop(
+    queue_raw: *const CassCompletionQueue,
+) -> *const CassFuture {
+    match ArcFFI::as_ref(queue_raw).pop() {
+        Some(future) => ArcFFI::into_ptr(future),
+        None => std::ptr::null(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::future::{cass_future_error_code, cass_future_free};
+    use crate::testing::assert_cass_error_eq;
+    use std::time::Duration;
+
+    fn is_readable(fd: c_int) -> bool {
+        let mut pollfd = libc::pollfd {
+            fd,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let ready = unsafe { libc::poll(&mut pollfd, 1, 0) };
+        ready == 1 && pollfd.revents & libc::POLLIN != 0
+    }
+
+    #[test]
+    #[ntest::timeout(500)]
+    fn completion_queue_test() {
+        unsafe {
+            let queue_raw = cass_completion_queue_new();
+            assert!(!queue_raw.is_null());
+            let fd = cass_completion_queue_fd(queue_raw);
+            assert!(!is_readable(fd));
+            assert!(cass_completion_queue_pop(queue_raw).is_null());
+
+            let future_raw = CassFuture::make_raw(async {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                Err((CassError::CASS_OK, "OK".into()))
+            });
+            let ready_future_raw = ArcFFI::into_ptr(CassFuture::new_ready(Err((
+                CassError::CASS_ERROR_LIB_BAD_PARAMS,
+                "Bad params".into(),
+            ))));
+
+            assert_cass_error_eq!(
+                cass_completion_queue_attach(queue_raw, future_raw),
+                CassError::CASS_OK
+            );
+            // A future can be attached to one queue only.
+            assert_cass_error_eq!(
+                cass_completion_queue_attach(queue_raw, future_raw),
+                CassError::CASS_ERROR_LIB_CALLBACK_ALREADY_SET
+            );
+            // The future, which is already set, is queued right away.
+            assert_cass_error_eq!(
+                cass_completion_queue_attach(queue_raw, ready_future_raw),
+                CassError::CASS_OK
+            );
+            assert!(is_readable(fd));
+
+            let popped_raw = cass_completion_queue_pop(queue_raw);
+            assert_eq!(ready_future_raw, popped_raw);
+            assert_cass_error_eq!(
+                cass_future_error_code(popped_raw),
+                CassError::CASS_ERROR_LIB_BAD_PARAMS
+            );
+            cass_future_free(popped_raw);
+            assert!(!is_readable(fd));
+
+            // Wait for the other future to complete.
+            let mut pollfd = libc::pollfd {
+                fd,
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            assert_eq!(1, libc::poll(&mut pollfd, 1, 400));
+
+            let popped_raw = cass_completion_queue_pop(queue_raw);
+            assert_eq!(future_raw as *const CassFuture, popped_raw);
+            assert_cass_error_eq!(cass_future_error_code(popped_raw), CassError::CASS_OK);
+            cass_future_free(popped_raw);
+            assert!(!is_readable(fd));
+            assert!(cass_completion_queue_pop(queue_raw).is_null());
+
+            cass_future_free(future_raw);
+            cass_future_free(ready_future_raw);
+            cass_completion_queue_free(queue_raw);
+        }
+    }
+}
@@ -1,10 +1,23 @@
-use crate::types::{cass_int64_t, cass_uint32_t};
+use crate::argconv::*;
+pub(crate) use crate::cass_date_time_types::{CassDateTime, CassDuration};
+use crate::cass_error::CassError;
+use crate::types::{cass_int32_t, cass_int64_t, cass_uint32_t, size_t};
+use std::fmt::Write;
+use std::os::raw::c_char;
 
 // Implementation directly ported from Cpp Driver implementation:
 
 const NUM_SECONDS_PER_DAY: i64 = 24 * 60 * 60;
 const CASS_DATE_EPOCH: u64 = 2147483648; // 2^31
 const CASS_TIME_NANOSECONDS_PER_SECOND: i64 = 1_000_000_000;
+const NANOS_PER_MILLI: i64 = 1_000_000;
+const MILLIS_PER_DAY: i64 = NUM_SECONDS_PER_DAY * 1_000;
+const NANOS_PER_DAY: i64 = NUM_SECONDS_PER_DAY * CASS_TIME_NANOSECONDS_PER_SECOND;
+// Bounds the year so that the calendar arithmetic can't overflow.
+const MAX_YEAR_DIGITS: usize = 11;
+const MAX_ABS_YEAR: i64 = 99_999_999_999;
+// Same bound as in Java's `ZoneOffset`.
+const MAX_OFFSET_MINUTES: i32 = 18 * 60;
 
 // All type conversions (between i32, u64, i64) based on original Cpp Driver implementation
 // and C++ implicit type promotion rules.
@@ -27,3 +40,1122 @@ pub unsafe extern "C" fn cass_date_time_to_epoch(
     (((date as u64) - CASS_DATE_EPOCH) * (NUM_SECONDS_PER_DAY as u64)
         + ((time / CASS_TIME_NANOSECONDS_PER_SECOND) as u64)) as i64
 }
+
+/// Converts a number of days since the Unix epoch to a (year, month, day) triple
+/// in the proleptic Gregorian calendar.
+///
+/// Based on the `civil_from_days` algorithm by Howard Hinnant.
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097); // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365; // [0, 399]
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11]
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32; // [1, 31]
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32; // [1, 12]
+    let year = yoe + era * 400 + (month <= 2) as i64;
+
+    (year, month, day)
+}
+
+/// Converts a `date` value (days since the Unix epoch, centered at 2^31) to a (year, month, day) triple.
+pub(crate) fn civil_from_cass_date(date: cass_uint32_t) -> (i64, u32, u32) {
+    civil_from_days(date as i64 - CASS_DATE_EPOCH as i64)
+}
+
+/// Converts a (year, month, day) triple in the proleptic Gregorian calendar
+/// to a number of days since the Unix epoch. Inverse of `civil_from_days`.
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400); // [0, 399]
+    let mp = (month as i64 + 9) % 12; // [0, 11], starting from March
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+
+    era * 146097 + doe - 719468
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+fn cass_date_from_days(days: i64) -> Option<cass_uint32_t> {
+    cass_uint32_t::try_from(days + CASS_DATE_EPOCH as i64).ok()
+}
+
+fn days_from_cass_date(date: cass_uint32_t) -> i64 {
+    date as i64 - CASS_DATE_EPOCH as i64
+}
+
+/// A cursor over a date/time literal.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(s: &'a str) -> Self {
+        Cursor {
+            bytes: s.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    /// Consumes the next byte if it's one of `expected`.
+    fn eat_any(&mut self, expected: &[u8]) -> Option<u8> {
+        let b = *self.bytes.get(self.pos)?;
+        if !expected.contains(&b) {
+            return None;
+        }
+        self.pos += 1;
+        Some(b)
+    }
+
+    fn eat(&mut self, expected: u8) -> bool {
+        self.eat_any(&[expected]).is_some()
+    }
+
+    /// Consumes between `min` and `max` digits, returning the number and the count of digits.
+    fn number(&mut self, min: usize, max: usize) -> Option<(i64, usize)> {
+        let start = self.pos;
+        let mut value: i64 = 0;
+        while self.pos - start < max {
+            match self.bytes.get(self.pos) {
+                Some(d) if d.is_ascii_digit() => {
+                    value = value * 10 + (d - b'0') as i64;
+                    self.pos += 1;
+                }
+                _ => break,
+            }
+        }
+        let count = self.pos - start;
+        (count >= min).then_some((value, count))
+    }
+}
+
+/// Parses `[+-]yyyy-mm-dd` into days since the Unix epoch.
+fn parse_date(cursor: &mut Cursor) -> Option<i64> {
+    let negative = cursor.eat_any(b"+-") == Some(b'-');
+    let (year, _) = cursor.number(1, MAX_YEAR_DIGITS)?;
+    let year = if negative { -year } else { year };
+    if !cursor.eat(b'-') {
+        return None;
+    }
+    let (month, _) = cursor.number(1, 2)?;
+    if !cursor.eat(b'-') {
+        return None;
+    }
+    let (day, _) = cursor.number(1, 2)?;
+
+    let (month, day) = (month as u32, day as u32);
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some(days_from_civil(year, month, day))
+}
+
+/// Parses `hh:mm[:ss[.fff]]` into nanoseconds since midnight.
+fn parse_time(
+    cursor: &mut Cursor,
+    seconds_required: bool,
+    max_fraction_digits: usize,
+) -> Option<i64> {
+    let (hour, _) = cursor.number(1, 2)?;
+    if !cursor.eat(b':') {
+        return None;
+    }
+    let (minute, _) = cursor.number(2, 2)?;
+
+    let (mut second, mut fraction) = (0, 0);
+    if cursor.eat(b':') {
+        second = cursor.number(2, 2)?.0;
+        if cursor.eat(b'.') {
+            let (value, digits) = cursor.number(1, max_fraction_digits)?;
+            fraction = value * 10i64.pow(9 - digits as u32);
+        }
+    } else if seconds_required {
+        return None;
+    }
+
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    Some(((hour * 60 + minute) * 60 + second) * CASS_TIME_NANOSECONDS_PER_SECOND + fraction)
+}
+
+/// Parses `Z`, `+hh`, `+hhmm` or `+hh:mm` into an offset from UTC in seconds.
+fn parse_offset(cursor: &mut Cursor) -> Option<i64> {
+    let sign = match cursor.eat_any(b"Zz+-")? {
+        b'Z' | b'z' => return Some(0),
+        b'+' => 1,
+        _ => -1,
+    };
+    let (hours, _) = cursor.number(2, 2)?;
+    let minutes = if cursor.eat(b':') {
+        cursor.number(2, 2)?.0
+    } else {
+        cursor.number(0, 2).filter(|(_, digits)| *digits != 1)?.0
+    };
+
+    let offset_minutes = hours * 60 + minutes;
+    if minutes > 59 || offset_minutes > MAX_OFFSET_MINUTES as i64 {
+        return None;
+    }
+    Some(sign * offset_minutes * 60)
+}
+
+/// Parses a timestamp literal in one of the formats accepted by CQL, i.e.
+/// `yyyy-mm-dd[(T| )hh:mm[:ss[.fff]]][Z|+hh|+hhmm|+hh:mm]`, into nanoseconds
+/// since the Unix epoch. Timestamps without an offset are in UTC.
+fn parse_timestamp(s: &str, max_fraction_digits: usize) -> Option<i128> {
+    let mut cursor = Cursor::new(s);
+    let days = parse_date(&mut cursor)?;
+    let mut nanos_of_day = 0;
+    if cursor.eat_any(b"Tt ").is_some() {
+        nanos_of_day = parse_time(&mut cursor, false, max_fraction_digits)?;
+    }
+    let offset_seconds = if cursor.is_at_end() {
+        0
+    } else {
+        parse_offset(&mut cursor)?
+    };
+    if !cursor.is_at_end() {
+        return None;
+    }
+
+    Some(
+        days as i128 * NANOS_PER_DAY as i128 + nanos_of_day as i128
+            - offset_seconds as i128 * CASS_TIME_NANOSECONDS_PER_SECOND as i128,
+    )
+}
+
+/// CQL also accepts the raw numeric representation of dates, times and timestamps.
+fn parse_integer<T: std::str::FromStr>(s: &str) -> Option<T> {
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// Parses a `date` literal: `yyyy-mm-dd` or the raw (unsigned) number of days
+/// since -5877641-06-23.
+pub(crate) fn date_from_string(s: &str) -> Option<cass_uint32_t> {
+    if let Some(date) = parse_integer(s) {
+        return Some(date);
+    }
+    let mut cursor = Cursor::new(s);
+    let days = parse_date(&mut cursor)?;
+    if !cursor.is_at_end() {
+        return None;
+    }
+    cass_date_from_days(days)
+}
+
+/// Parses a `time` literal: `hh:mm:ss[.fffffffff]` or the raw number of nanoseconds since midnight.
+pub(crate) fn time_from_string(s: &str) -> Option<cass_int64_t> {
+    let time = match parse_integer(s) {
+        Some(time) => time,
+        None => {
+            let mut cursor = Cursor::new(s);
+            let time = parse_time(&mut cursor, true, 9)?;
+            cursor.is_at_end().then_some(time)?
+        }
+    };
+    (0..NANOS_PER_DAY).contains(&time).then_some(time)
+}
+
+/// Parses a `timestamp` literal (with up to millisecond precision) or the raw
+/// number of milliseconds since the Unix epoch.
+pub(crate) fn timestamp_from_string(s: &str) -> Option<cass_int64_t> {
+    if let Some(millis) = parse_integer(s) {
+        return Some(millis);
+    }
+    let nanos = parse_timestamp(s, 3)?;
+    i64::try_from(nanos / NANOS_PER_MILLI as i128).ok()
+}
+
+/// Same as `timestamp_from_string`, but with up to nanosecond precision.
+/// The raw number is in nanoseconds since the Unix epoch.
+pub(crate) fn timestamp_ns_from_string(s: &str) -> Option<cass_int64_t> {
+    if let Some(nanos) = parse_integer(s) {
+        return Some(nanos);
+    }
+    i64::try_from(parse_timestamp(s, 9)?).ok()
+}
+
+fn write_date(out: &mut String, days: i64) {
+    let (year, month, day) = civil_from_days(days);
+    if year < 0 {
+        out.push('-');
+    }
+    write!(out, "{:04}-{:02}-{:02}", year.abs(), month, day).unwrap();
+}
+
+fn write_time(out: &mut String, nanos_of_day: i64, fraction_digits: u32) {
+    let seconds = nanos_of_day / CASS_TIME_NANOSECONDS_PER_SECOND;
+    let fraction = nanos_of_day % CASS_TIME_NANOSECONDS_PER_SECOND / 10i64.pow(9 - fraction_digits);
+    write!(
+        out,
+        "{:02}:{:02}:{:02}.{:0width$}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60,
+        fraction,
+        width = fraction_digits as usize
+    )
+    .unwrap();
+}
+
+pub(crate) fn date_to_string(date: cass_uint32_t) -> String {
+    let mut out = String::new();
+    write_date(&mut out, days_from_cass_date(date));
+    out
+}
+
+/// Formats a `time` as `hh:mm:ss.fffffffff`. Returns `None` if it's out of range.
+pub(crate) fn time_to_string(time: cass_int64_t) -> Option<String> {
+    if !(0..NANOS_PER_DAY).contains(&time) {
+        return None;
+    }
+    let mut out = String::new();
+    write_time(&mut out, time, 9);
+    Some(out)
+}
+
+/// Formats nanoseconds since the Unix epoch in ISO-8601, in the given offset from UTC,
+/// e.g. `2011-02-03T04:05:06.789Z` or `2011-02-03T06:05:06.789+02:00`.
+fn format_timestamp(
+    nanos: i128,
+    offset_minutes: cass_int32_t,
+    fraction_digits: u32,
+) -> Option<String> {
+    if offset_minutes.abs() > MAX_OFFSET_MINUTES {
+        return None;
+    }
+    let local = nanos + offset_minutes as i128 * 60 * CASS_TIME_NANOSECONDS_PER_SECOND as i128;
+    let days = local.div_euclid(NANOS_PER_DAY as i128) as i64;
+    let nanos_of_day = local.rem_euclid(NANOS_PER_DAY as i128) as i64;
+
+    let mut out = String::new();
+    write_date(&mut out, days);
+    out.push('T');
+    write_time(&mut out, nanos_of_day, fraction_digits);
+    if offset_minutes == 0 {
+        out.push('Z');
+    } else {
+        let sign = if offset_minutes < 0 { '-' } else { '+' };
+        let offset_minutes = offset_minutes.abs();
+        write!(
+            out,
+            "{}{:02}:{:02}",
+            sign,
+            offset_minutes / 60,
+            offset_minutes % 60
+        )
+        .unwrap();
+    }
+    Some(out)
+}
+
+pub(crate) fn timestamp_to_string(
+    millis: cass_int64_t,
+    offset_minutes: cass_int32_t,
+) -> Option<String> {
+    format_timestamp(millis as i128 * NANOS_PER_MILLI as i128, offset_minutes, 3)
+}
+
+pub(crate) fn timestamp_ns_to_string(
+    nanos: cass_int64_t,
+    offset_minutes: cass_int32_t,
+) -> Option<String> {
+    format_timestamp(nanos as i128, offset_minutes, 9)
+}
+
+fn date_time_from_parts(days: i64, nanos_of_day: i64) -> CassDateTime {
+    let (year, month, day) = civil_from_days(days);
+    let seconds = nanos_of_day / CASS_TIME_NANOSECONDS_PER_SECOND;
+    CassDateTime {
+        year,
+        month: month as u8,
+        day: day as u8,
+        hour: (seconds / 3600) as u8,
+        minute: (seconds / 60 % 60) as u8,
+        second: (seconds % 60) as u8,
+        nanosecond: (nanos_of_day % CASS_TIME_NANOSECONDS_PER_SECOND) as u32,
+    }
+}
+
+/// Returns the days since the Unix epoch and the nanoseconds since midnight,
+/// or `None` if any of the fields is out of range.
+fn date_time_to_parts(date_time: &CassDateTime) -> Option<(i64, i64)> {
+    let CassDateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        nanosecond,
+    } = *date_time;
+    let valid = (-MAX_ABS_YEAR..=MAX_ABS_YEAR).contains(&year)
+        && (1..=12).contains(&month)
+        && day >= 1
+        && day as u32 <= days_in_month(year, month as u32)
+        && hour < 24
+        && minute < 60
+        && second < 60
+        && (nanosecond as i64) < CASS_TIME_NANOSECONDS_PER_SECOND;
+    if !valid {
+        return None;
+    }
+
+    let days = days_from_civil(year, month as u32, day as u32);
+    let seconds = (hour as i64 * 60 + minute as i64) * 60 + second as i64;
+    Some((
+        days,
+        seconds * CASS_TIME_NANOSECONDS_PER_SECOND + nanosecond as i64,
+    ))
+}
+
+/// Moves a date by a number of months. If the day doesn't exist in the resulting month,
+/// the last day of that month is used, e.g. 2011-01-31 + 1 month is 2011-02-28.
+fn add_months(days: i64, months: i64) -> Option<i64> {
+    let (year, month, day) = civil_from_days(days);
+    let total_months = year
+        .checked_mul(12)?
+        .checked_add(month as i64 - 1)?
+        .checked_add(months)?;
+    let (year, month) = (
+        total_months.div_euclid(12),
+        total_months.rem_euclid(12) as u32 + 1,
+    );
+    Some(days_from_civil(
+        year,
+        month,
+        day.min(days_in_month(year, month)),
+    ))
+}
+
+/// Adds (or, with `sign` equal to -1, subtracts) a duration to a timestamp the same way
+/// as CQL does: the months first, then the days and the nanoseconds (truncated to milliseconds).
+fn timestamp_add_duration(millis: i64, duration: &CassDuration, sign: i64) -> Option<i64> {
+    let mut days = millis.div_euclid(MILLIS_PER_DAY);
+    let millis_of_day = millis.rem_euclid(MILLIS_PER_DAY);
+    if duration.months != 0 {
+        days = add_months(days, sign * duration.months as i64)?;
+    }
+    let total = (days as i128 + (sign * duration.days as i64) as i128) * MILLIS_PER_DAY as i128
+        + millis_of_day as i128
+        + (sign * (duration.nanos / NANOS_PER_MILLI)) as i128;
+    i64::try_from(total).ok()
+}
+
+/// Same as `timestamp_add_duration`, but for dates. Returns `Err` if the duration
+/// has a time part, as CQL only allows adding durations with a day precision to dates.
+fn date_add_duration(
+    date: cass_uint32_t,
+    duration: &CassDuration,
+    sign: i64,
+) -> Result<cass_uint32_t, CassError> {
+    if duration.nanos != 0 {
+        return Err(CassError::CASS_ERROR_LIB_BAD_PARAMS);
+    }
+    let days = add_months(days_from_cass_date(date), sign * duration.months as i64)
+        .map(|days| days + sign * duration.days as i64)
+        .and_then(cass_date_from_days);
+    days.ok_or(CassError::CASS_ERROR_LIB_INVALID_DATA)
+}
+
+unsafe fn write_parsed<T>(
+    value: *const c_char,
+    parse: impl FnOnce(&str) -> Option<T>,
+    output: *mut T,
+) -> CassError {
+    if value.is_null() || output.is_null() {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+    match ptr_to_cstr(value).and_then(parse) {
+        Some(v) => {
+            std::ptr::write(output, v);
+            CassError::CASS_OK
+        }
+        None => CassError::CASS_ERROR_LIB_BAD_PARAMS,
+    }
+}
+
+unsafe fn write_formatted(
+    formatted: Option<String>,
+    output: *mut c_char,
+    output_size: size_t,
+    length: *mut size_t,
+) -> CassError {
+    match formatted {
+        Some(s) => write_str_to_buffer(&s, output, output_size, length),
+        None => CassError::CASS_ERROR_LIB_BAD_PARAMS,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_date_from_string(
+    value: *const c_char,
+    output: *mut cass_uint32_t,
+) -> CassError {
+    write_parsed(value, date_from_string, output)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_date_to_string(
+    date: cass_uint32_t,
+    output: *mut c_char,
+    output_size: size_t,
+    length: *mut size_t,
+) -> CassError {
+    write_formatted(Some(date_to_string(date)), output, output_size, length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_time_from_string(
+    value: *const c_char,
+    output: *mut cass_int64_t,
+) -> CassError {
+    write_parsed(value, time_from_string, output)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_time_to_string(
+    time: cass_int64_t,
+    output: *mut c_char,
+    output_size: size_t,
+    length: *mut size_t,
+) -> CassError {
+    write_formatted(time_to_string(time), output, output_size, length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_timestamp_from_string(
+    value: *const c_char,
+    output: *mut cass_int64_t,
+) -> CassError {
+    write_parsed(value, timestamp_from_string, output)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_timestamp_to_string(
+    timestamp: cass_int64_t,
+    offset_minutes: cass_int32_t,
+    output: *mut c_char,
+    output_size: size_t,
+    length: *mut size_t,
+) -> CassError {
+    write_formatted(
+        timestamp_to_string(timestamp, offset_minutes),
+        output,
+        output_size,
+        length,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_timestamp_ns_from_string(
+    value: *const c_char,
+    output: *mut cass_int64_t,
+) -> CassError {
+    write_parsed(value, timestamp_ns_from_string, output)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_timestamp_ns_to_string(
+    timestamp_ns: cass_int64_t,
+    offset_minutes: cass_int32_t,
+    output: *mut c_char,
+    output_size: size_t,
+    length: *mut size_t,
+) -> CassError {
+    write_formatted(
+        timestamp_ns_to_string(timestamp_ns, offset_minutes),
+        output,
+        output_size,
+        length,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_date_time_from_timestamp(
+    timestamp: cass_int64_t,
+    output: *mut CassDateTime,
+) -> CassError {
+    if output.is_null() {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+    let days = timestamp.div_euclid(MILLIS_PER_DAY);
+    let nanos_of_day = timestamp.rem_euclid(MILLIS_PER_DAY) * NANOS_PER_MILLI;
+    std::ptr::write(output, date_time_from_parts(days, nanos_of_day));
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_date_time_from_date_and_time(
+    date: cass_uint32_t,
+    time: cass_int64_t,
+    output: *mut CassDateTime,
+) -> CassError {
+    if output.is_null() || !(0..NANOS_PER_DAY).contains(&time) {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+    std::ptr::write(
+        output,
+        date_time_from_parts(days_from_cass_date(date), time),
+    );
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_date_time_to_timestamp(
+    date_time: *const CassDateTime,
+    output: *mut cass_int64_t,
+) -> CassError {
+    if date_time.is_null() || output.is_null() {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+    let Some((days, nanos_of_day)) = date_time_to_parts(&std::ptr::read(date_time)) else {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    };
+    let millis = days as i128 * MILLIS_PER_DAY as i128 + (nanos_of_day / NANOS_PER_MILLI) as i128;
+    match i64::try_from(millis) {
+        Ok(millis) => {
+            std::ptr::write(output, millis);
+            CassError::CASS_OK
+        }
+        Err(_) => CassError::CASS_ERROR_LIB_INVALID_DATA,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_date_time_to_date_and_time(
+    date_time: *const CassDateTime,
+    date: *mut cass_uint32_t,
+    time: *mut cass_int64_t,
+) -> CassError {
+    if date_time.is_null() {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+    let Some((days, nanos_of_day)) = date_time_to_parts(&std::ptr::read(date_time)) else {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    };
+    let Some(cass_date) = cass_date_from_days(days) else {
+        return CassError::CASS_ERROR_LIB_INVALID_DATA;
+    };
+    if !date.is_null() {
+        std::ptr::write(date, cass_date);
+    }
+    if !time.is_null() {
+        std::ptr::write(time, nanos_of_day);
+    }
+    CassError::CASS_OK
+}
+
+unsafe fn write_timestamp_sum(
+    timestamp: cass_int64_t,
+    duration: &CassDuration,
+    sign: i64,
+    output: *mut cass_int64_t,
+) -> CassError {
+    if output.is_null() {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+    match timestamp_add_duration(timestamp, duration, sign) {
+        Some(v) => {
+            std::ptr::write(output, v);
+            CassError::CASS_OK
+        }
+        None => CassError::CASS_ERROR_LIB_INVALID_DATA,
+    }
+}
+
+unsafe fn write_date_sum(
+    date: cass_uint32_t,
+    duration: &CassDuration,
+    sign: i64,
+    output: *mut cass_uint32_t,
+) -> CassError {
+    if output.is_null() {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+    match date_add_duration(date, duration, sign) {
+        Ok(v) => {
+            std::ptr::write(output, v);
+            CassError::CASS_OK
+        }
+        Err(e) => e,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_timestamp_add_duration(
+    timestamp: cass_int64_t,
+    duration: CassDuration,
+    output: *mut cass_int64_t,
+) -> CassError {
+    write_timestamp_sum(timestamp, &duration, 1, output)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_timestamp_subtract_duration(
+    timestamp: cass_int64_t,
+    duration: CassDuration,
+    output: *mut cass_int64_t,
+) -> CassError {
+    write_timestamp_sum(timestamp, &duration, -1, output)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_date_add_duration(
+    date: cass_uint32_t,
+    duration: CassDuration,
+    output: *mut cass_uint32_t,
+) -> CassError {
+    write_date_sum(date, &duration, 1, output)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_date_subtract_duration(
+    date: cass_uint32_t,
+    duration: CassDuration,
+    output: *mut cass_uint32_t,
+) -> CassError {
+    write_date_sum(date, &duration, -1, output)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cass_error::CassError;
+    use crate::testing::assert_cass_error_eq;
+
+    use super::{
+        cass_date_add_duration, cass_date_time_from_date_and_time, cass_date_time_from_timestamp,
+        cass_date_time_to_date_and_time, cass_date_time_to_timestamp, cass_timestamp_add_duration,
+        cass_timestamp_subtract_duration, civil_from_days, date_from_string, date_to_string,
+        days_from_civil, time_from_string, time_to_string, timestamp_from_string,
+        timestamp_ns_from_string, timestamp_ns_to_string, timestamp_to_string, CassDateTime,
+        CassDuration, CASS_DATE_EPOCH,
+    };
+
+    // 2011-02-03T04:05:06.789Z
+    const TIMESTAMP: i64 = 1296705906789;
+    const DAYS: i64 = 15008;
+
+    fn duration(months: i32, days: i32, nanos: i64) -> CassDuration {
+        CassDuration {
+            months,
+            days,
+            nanos,
+        }
+    }
+
+    #[test]
+    fn civil_days_round_trip_test() {
+        assert_eq!(0, days_from_civil(1970, 1, 1));
+        assert_eq!(DAYS, days_from_civil(2011, 2, 3));
+        assert_eq!(-719162, days_from_civil(1, 1, 1));
+        assert_eq!(11016, days_from_civil(2000, 2, 29));
+
+        for days in (-800_000..800_000).step_by(7) {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days, days_from_civil(year, month, day));
+        }
+    }
+
+    #[test]
+    fn date_string_test() {
+        let date = |days: i64| (days + CASS_DATE_EPOCH as i64) as u32;
+        let cases = [
+            ("2011-02-03", date(DAYS)),
+            ("2011-2-3", date(DAYS)),
+            ("+2011-02-03", date(DAYS)),
+            ("1970-01-01", date(0)),
+            ("2000-02-29", date(11016)),
+            ("0001-01-01", date(-719162)),
+            ("-0001-12-31", date(-719529)),
+            ("-5877641-06-23", 0),
+            ("5881580-07-11", u32::MAX),
+            // Raw values.
+            ("2147483648", date(0)),
+            ("0", 0),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(Some(expected), date_from_string(s), "string: {}", s);
+        }
+
+        for s in [
+            "",
+            "2011-02-30",
+            "2001-02-29",
+            "2011-13-01",
+            "2011-00-01",
+            "2011/02/03",
+            "2011-02-03 ",
+            "2011-02-03T00:00",
+            "-5877641-06-22",
+            "5881580-07-12",
+            "4294967296",
+            "-1",
+        ] {
+            assert_eq!(None, date_from_string(s), "string: {:?}", s);
+        }
+
+        for s in [
+            "2011-02-03",
+            "1970-01-01",
+            "-0001-12-31",
+            "0001-01-01",
+            "10000-01-01",
+        ] {
+            assert_eq!(s, date_to_string(date_from_string(s).unwrap()));
+        }
+    }
+
+    #[test]
+    fn time_string_test() {
+        const H: i64 = 3_600_000_000_000;
+        const M: i64 = 60_000_000_000;
+        const S: i64 = 1_000_000_000;
+        let cases = [
+            ("08:12:54", 8 * H + 12 * M + 54 * S),
+            ("8:12:54", 8 * H + 12 * M + 54 * S),
+            ("08:12:54.1", 8 * H + 12 * M + 54 * S + 100_000_000),
+            ("08:12:54.123", 8 * H + 12 * M + 54 * S + 123_000_000),
+            ("08:12:54.123456", 8 * H + 12 * M + 54 * S + 123_456_000),
+            ("08:12:54.123456789", 8 * H + 12 * M + 54 * S + 123_456_789),
+            ("00:00:00", 0),
+            ("23:59:59.999999999", 24 * H - 1),
+            // Raw values.
+            ("1000", 1000),
+            ("86399999999999", 24 * H - 1),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(Some(expected), time_from_string(s), "string: {}", s);
+        }
+
+        for s in [
+            "",
+            "08:12",
+            "24:00:00",
+            "08:60:00",
+            "08:12:60",
+            "08:12:54.",
+            "08:12:54.1234567890",
+            "08:12:54Z",
+            "8:2:54",
+            "86400000000000",
+            "-1",
+        ] {
+            assert_eq!(None, time_from_string(s), "string: {:?}", s);
+        }
+
+        assert_eq!(
+            Some("08:12:54.123456789".to_string()),
+            time_to_string(8 * H + 12 * M + 54 * S + 123_456_789)
+        );
+        assert_eq!(Some("00:00:00.000000000".to_string()), time_to_string(0));
+        assert_eq!(None, time_to_string(24 * H));
+        assert_eq!(None, time_to_string(-1));
+    }
+
+    #[test]
+    fn timestamp_string_test() {
+        const MINUTE: i64 = 60_000;
+        const HOUR: i64 = 60 * MINUTE;
+        let date_only = DAYS * 24 * HOUR;
+        let no_seconds = TIMESTAMP - 6789;
+        let no_millis = TIMESTAMP - 789;
+
+        // All the formats accepted by CQL.
+        let mut cases: Vec<(String, i64)> = [
+            ("2011-02-03", date_only),
+            ("2011-02-03Z", date_only),
+            ("2011-02-03+01", date_only - HOUR),
+            ("2011-02-03+0100", date_only - HOUR),
+            ("2011-02-03+01:00", date_only - HOUR),
+            ("2011-02-03-0130", date_only + HOUR + 30 * MINUTE),
+            // Raw values.
+            ("1296705906789", TIMESTAMP),
+            ("-1", -1),
+        ]
+        .into_iter()
+        .map(|(s, expected)| (s.to_string(), expected))
+        .collect();
+        for separator in [" ", "T"] {
+            for (time, expected) in [
+                ("04:05", no_seconds),
+                ("04:05:06", no_millis),
+                ("04:05:06.789", TIMESTAMP),
+                ("04:05:06.7", TIMESTAMP - 89),
+            ] {
+                for (offset, shift) in [
+                    ("", 0),
+                    ("Z", 0),
+                    ("+00", 0),
+                    ("+0000", 0),
+                    ("+00:00", 0),
+                    ("+02", -2 * HOUR),
+                    ("+0230", -2 * HOUR - 30 * MINUTE),
+                    ("-02:30", 2 * HOUR + 30 * MINUTE),
+                ] {
+                    let s = format!("2011-02-03{}{}{}", separator, time, offset);
+                    cases.push((s, expected + shift));
+                }
+            }
+        }
+        for (s, expected) in cases {
+            assert_eq!(Some(expected), timestamp_from_string(&s), "string: {}", s);
+        }
+
+        for s in [
+            "",
+            "2011-02-03 ",
+            "2011-02-03T",
+            "2011-02-03 04",
+            "2011-02-03 24:00",
+            "2011-02-03 04:05:06.7890",
+            "2011-02-03 04:05:06.",
+            "2011-02-03 04:05+2",
+            "2011-02-03 04:05+020",
+            "2011-02-03 04:05+19:00",
+            "2011-02-03 04:05+02:60",
+            "2011-02-03 04:05 +02:00",
+            "2011-02-03 04:05PST",
+            "2011-02-30 04:05",
+            "1e3",
+        ] {
+            assert_eq!(None, timestamp_from_string(s), "string: {:?}", s);
+        }
+
+        assert_eq!(
+            Some(TIMESTAMP * 1_000_000 + 123_456),
+            timestamp_ns_from_string("2011-02-03T04:05:06.789123456Z")
+        );
+        assert_eq!(
+            Some(TIMESTAMP * 1_000_000 - 2 * HOUR * 1_000_000),
+            timestamp_ns_from_string("2011-02-03T04:05:06.789+02:00")
+        );
+        assert_eq!(Some(42), timestamp_ns_from_string("42"));
+        assert_eq!(None, timestamp_ns_from_string("2400-01-01"));
+        assert_eq!(None, timestamp_ns_from_string("04:05:06.7891234567"));
+
+        let formatted = [
+            (TIMESTAMP, 0, "2011-02-03T04:05:06.789Z"),
+            (TIMESTAMP, 120, "2011-02-03T06:05:06.789+02:00"),
+            (TIMESTAMP, -330, "2011-02-02T22:35:06.789-05:30"),
+            (0, 0, "1970-01-01T00:00:00.000Z"),
+            (-1, 0, "1969-12-31T23:59:59.999Z"),
+        ];
+        for (timestamp, offset, expected) in formatted {
+            let s = timestamp_to_string(timestamp, offset).unwrap();
+            assert_eq!(expected, s);
+            assert_eq!(Some(timestamp), timestamp_from_string(&s));
+        }
+        assert_eq!(None, timestamp_to_string(0, 18 * 60 + 1));
+        assert_eq!(
+            Some("2011-02-03T04:05:06.789123456Z".to_string()),
+            timestamp_ns_to_string(TIMESTAMP * 1_000_000 + 123_456, 0)
+        );
+
+        // Extreme values can be formatted and parsed back.
+        for timestamp in [i64::MIN, i64::MAX] {
+            let s = timestamp_to_string(timestamp, 0).unwrap();
+            assert_eq!(Some(timestamp), timestamp_from_string(&s), "string: {}", s);
+        }
+    }
+
+    #[test]
+    fn date_time_test() {
+        unsafe {
+            let mut date_time = std::mem::zeroed::<CassDateTime>();
+            assert_cass_error_eq!(
+                cass_date_time_from_timestamp(TIMESTAMP, &mut date_time),
+                CassError::CASS_OK
+            );
+            let expected = CassDateTime {
+                year: 2011,
+                month: 2,
+                day: 3,
+                hour: 4,
+                minute: 5,
+                second: 6,
+                nanosecond: 789_000_000,
+            };
+            assert_eq!(expected, date_time);
+
+            let mut timestamp = 0;
+            assert_cass_error_eq!(
+                cass_date_time_to_timestamp(&date_time, &mut timestamp),
+                CassError::CASS_OK
+            );
+            assert_eq!(TIMESTAMP, timestamp);
+
+            // Before the Epoch.
+            assert_cass_error_eq!(
+                cass_date_time_from_timestamp(-1, &mut date_time),
+                CassError::CASS_OK
+            );
+            assert_eq!(
+                CassDateTime {
+                    year: 1969,
+                    month: 12,
+                    day: 31,
+                    hour: 23,
+                    minute: 59,
+                    second: 59,
+                    nanosecond: 999_000_000,
+                },
+                date_time
+            );
+
+            let (mut date, mut time) = (0, 0);
+            let with_nanos = CassDateTime {
+                nanosecond: 789_123_456,
+                ..expected
+            };
+            assert_cass_error_eq!(
+                cass_date_time_to_date_and_time(&with_nanos, &mut date, &mut time),
+                CassError::CASS_OK
+            );
+            assert_eq!((DAYS + CASS_DATE_EPOCH as i64) as u32, date);
+            assert_eq!(14_706_789_123_456, time);
+            assert_cass_error_eq!(
+                cass_date_time_from_date_and_time(date, time, &mut date_time),
+                CassError::CASS_OK
+            );
+            assert_eq!(with_nanos, date_time);
+            assert_cass_error_eq!(
+                cass_date_time_from_date_and_time(date, -1, &mut date_time),
+                CassError::CASS_ERROR_LIB_BAD_PARAMS
+            );
+
+            for invalid in [
+                CassDateTime {
+                    month: 13,
+                    ..expected
+                },
+                CassDateTime {
+                    day: 29,
+                    ..expected
+                },
+                CassDateTime {
+                    hour: 24,
+                    ..expected
+                },
+                CassDateTime {
+                    minute: 60,
+                    ..expected
+                },
+                CassDateTime {
+                    second: 60,
+                    ..expected
+                },
+                CassDateTime {
+                    nanosecond: 1_000_000_000,
+                    ..expected
+                },
+                CassDateTime {
+                    year: i64::MAX,
+                    ..expected
+                },
+            ] {
+                assert_cass_error_eq!(
+                    cass_date_time_to_timestamp(&invalid, &mut timestamp),
+                    CassError::CASS_ERROR_LIB_BAD_PARAMS
+                );
+            }
+            assert_cass_error_eq!(
+                cass_date_time_to_timestamp(
+                    &CassDateTime {
+                        year: 300_000_000,
+                        ..expected
+                    },
+                    &mut timestamp
+                ),
+                CassError::CASS_ERROR_LIB_INVALID_DATA
+            );
+        }
+    }
+
+    #[test]
+    fn duration_arithmetic_test() {
+        let timestamp = |s: &str| timestamp_from_string(s).unwrap();
+        let cases = [
+            ("2011-01-31 10:00", duration(1, 0, 0), "2011-02-28 10:00"),
+            ("2012-01-31 10:00", duration(1, 0, 0), "2012-02-29 10:00"),
+            ("2011-01-31 10:00", duration(13, 1, 0), "2012-03-01 10:00"),
+            ("2011-12-15 10:00", duration(1, 0, 0), "2012-01-15 10:00"),
+            ("2011-02-03 10:00", duration(0, 30, 0), "2011-03-05 10:00"),
+            (
+                "2011-02-03 23:30",
+                duration(0, 0, 3_600_000_000_000),
+                "2011-02-04 00:30",
+            ),
+            // Sub-millisecond parts are truncated.
+            (
+                "2011-02-03 10:00",
+                duration(0, 0, 1_999_999),
+                "2011-02-03 10:00:00.001",
+            ),
+            ("1970-01-01", duration(-1, -1, 0), "1969-11-30"),
+        ];
+        unsafe {
+            for (start, d, expected) in cases {
+                let mut output = 0;
+                assert_cass_error_eq!(
+                    cass_timestamp_add_duration(timestamp(start), d, &mut output),
+                    CassError::CASS_OK
+                );
+                assert_eq!(timestamp(expected), output, "{} + {:?}", start, d);
+            }
+
+            let mut output = 0;
+            assert_cass_error_eq!(
+                cass_timestamp_subtract_duration(
+                    timestamp("2011-03-31 10:00"),
+                    duration(1, 1, 60_000_000_000),
+                    &mut output
+                ),
+                CassError::CASS_OK
+            );
+            assert_eq!(timestamp("2011-02-27 09:59"), output);
+
+            assert_cass_error_eq!(
+                cass_timestamp_add_duration(i64::MAX, duration(0, 1, 0), &mut output),
+                CassError::CASS_ERROR_LIB_INVALID_DATA
+            );
+
+            let date = |s: &str| date_from_string(s).unwrap();
+            let mut output = 0;
+            assert_cass_error_eq!(
+                cass_date_add_duration(date("2011-01-31"), duration(1, 1, 0), &mut output),
+                CassError::CASS_OK
+            );
+            assert_eq!(date("2011-03-01"), output);
+            assert_cass_error_eq!(
+                cass_date_add_duration(date("2011-01-31"), duration(0, 0, 1), &mut output),
+                CassError::CASS_ERROR_LIB_BAD_PARAMS
+            );
+            assert_cass_error_eq!(
+                cass_date_add_duration(u32::MAX, duration(0, 1, 0), &mut output),
+                CassError::CASS_ERROR_LIB_INVALID_DATA
+            );
+        }
+    }
+}
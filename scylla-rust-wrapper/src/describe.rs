@@ -0,0 +1,1191 @@
+//! Rendering of schema metadata as CQL DDL statements.
+//!
+//! The output follows the format of cqlsh's `DESCRIBE`:
+//! - a keyspace is rendered with its user types (dependencies first), functions,
+//!   aggregates and tables,
+//! - a table is rendered with its indexes and materialized views,
+//! - identifiers are quoted only when needed,
+//! - table options are taken from the raw `system_schema` rows, so all options
+//!   known to the server are included,
+//! - virtual keyspaces and tables can't be recreated, so they are rendered
+//!   inside a comment, for reference only.
+
+use crate::argconv::*;
+use crate::cass_column_types::{CassClusteringOrder, CassColumnType};
+use crate::cass_error::CassError;
+use crate::cass_types::{CassDataType, CassDataTypeInner, CassValueType, MapDataType, UDTDataType};
+use crate::metadata::{
+    CassAggregateMeta, CassFunctionMeta, CassIndexMeta, CassKeyspaceMeta, CassMaterializedViewMeta,
+    CassMetaFields, CassTableMeta,
+};
+use crate::query_result::{CassValue, Collection, Value};
+use crate::types::*;
+use scylla::frame::response::result::CqlValue;
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::os::raw::c_char;
+
+/// Keywords which can't be used as unquoted identifiers.
+const RESERVED_KEYWORDS: &[&str] = &[
+    "add",
+    "allow",
+    "alter",
+    "and",
+    "apply",
+    "asc",
+    "authorize",
+    "batch",
+    "begin",
+    "by",
+    "columnfamily",
+    "create",
+    "delete",
+    "desc",
+    "describe",
+    "drop",
+    "entries",
+    "execute",
+    "from",
+    "full",
+    "grant",
+    "if",
+    "in",
+    "index",
+    "infinity",
+    "insert",
+    "into",
+    "keyspace",
+    "limit",
+    "modify",
+    "nan",
+    "norecursive",
+    "not",
+    "null",
+    "of",
+    "on",
+    "or",
+    "order",
+    "primary",
+    "rename",
+    "replace",
+    "revoke",
+    "schema",
+    "select",
+    "set",
+    "table",
+    "to",
+    "token",
+    "truncate",
+    "unlogged",
+    "update",
+    "use",
+    "using",
+    "view",
+    "where",
+    "with",
+];
+
+/// Columns of the `system_schema` rows which aren't table options.
+const NON_OPTION_FIELDS: &[&str] = &[
+    "keyspace_name",
+    "table_name",
+    "view_name",
+    "id",
+    "flags",
+    "extensions",
+    "base_table_id",
+    "base_table_name",
+    "include_all_columns",
+    "where_clause",
+];
+
+fn quote_identifier(name: &str) -> Cow<'_, str> {
+    let is_unquoted_identifier = name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && !RESERVED_KEYWORDS.contains(&name);
+
+    if is_unquoted_identifier {
+        Cow::Borrowed(name)
+    } else {
+        Cow::Owned(format!("\"{}\"", name.replace('"', "\"\"")))
+    }
+}
+
+fn quote_string(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+fn qualified_name(keyspace_name: &str, name: &str) -> String {
+    format!(
+        "{}.{}",
+        quote_identifier(keyspace_name),
+        quote_identifier(name)
+    )
+}
+
+fn value_type_name(value_type: CassValueType) -> &'static str {
+    match value_type {
+        CassValueType::CASS_VALUE_TYPE_ASCII => "ascii",
+        CassValueType::CASS_VALUE_TYPE_BIGINT => "bigint",
+        CassValueType::CASS_VALUE_TYPE_BLOB => "blob",
+        CassValueType::CASS_VALUE_TYPE_BOOLEAN => "boolean",
+        CassValueType::CASS_VALUE_TYPE_COUNTER => "counter",
+        CassValueType::CASS_VALUE_TYPE_DATE => "date",
+        CassValueType::CASS_VALUE_TYPE_DECIMAL => "decimal",
+        CassValueType::CASS_VALUE_TYPE_DOUBLE => "double",
+        CassValueType::CASS_VALUE_TYPE_DURATION => "duration",
+        CassValueType::CASS_VALUE_TYPE_FLOAT => "float",
+        CassValueType::CASS_VALUE_TYPE_INET => "inet",
+        CassValueType::CASS_VALUE_TYPE_INT => "int",
+        CassValueType::CASS_VALUE_TYPE_SMALL_INT => "smallint",
+        CassValueType::CASS_VALUE_TYPE_TEXT | CassValueType::CASS_VALUE_TYPE_VARCHAR => "text",
+        CassValueType::CASS_VALUE_TYPE_TIME => "time",
+        CassValueType::CASS_VALUE_TYPE_TIMESTAMP => "timestamp",
+        CassValueType::CASS_VALUE_TYPE_TIMEUUID => "timeuuid",
+        CassValueType::CASS_VALUE_TYPE_TINY_INT => "tinyint",
+        CassValueType::CASS_VALUE_TYPE_UUID => "uuid",
+        CassValueType::CASS_VALUE_TYPE_VARINT => "varint",
+        CassValueType::CASS_VALUE_TYPE_LIST => "list",
+        CassValueType::CASS_VALUE_TYPE_SET => "set",
+        CassValueType::CASS_VALUE_TYPE_MAP => "map",
+        CassValueType::CASS_VALUE_TYPE_TUPLE => "tuple",
+        // Values of unknown types are raw bytes.
+        _ => "blob",
+    }
+}
+
+/// Returns the CQL name of the type, e.g. `frozen<map<text, int>>`.
+fn cql_type(data_type: &CassDataType) -> String {
+    let mut out = String::new();
+    write_cql_type(&mut out, unsafe { data_type.get_unchecked() }, false);
+    out
+}
+
+/// Writes the CQL name of the type. Types nested in a frozen type are frozen
+/// implicitly, so `frozen<>` is written only for the outermost frozen type.
+fn write_cql_type(out: &mut String, data_type: &CassDataTypeInner, in_frozen: bool) {
+    let (frozen, name, parameters): (bool, Cow<str>, Vec<&CassDataType>) = match data_type {
+        CassDataTypeInner::Value(value_type) => {
+            out.push_str(value_type_name(*value_type));
+            return;
+        }
+        CassDataTypeInner::Custom(class_name) => {
+            out.push_str(&quote_string(class_name));
+            return;
+        }
+        CassDataTypeInner::UDT(udt) => (udt.frozen, quote_identifier(&udt.name), Vec::new()),
+        CassDataTypeInner::List { typ, frozen } => (
+            *frozen,
+            Cow::Borrowed("list"),
+            typ.iter().map(|t| &**t).collect(),
+        ),
+        CassDataTypeInner::Set { typ, frozen } => (
+            *frozen,
+            Cow::Borrowed("set"),
+            typ.iter().map(|t| &**t).collect(),
+        ),
+        CassDataTypeInner::Map { typ, frozen } => {
+            let parameters = match typ {
+                MapDataType::Untyped => Vec::new(),
+                MapDataType::Key(key) => vec![&**key],
+                MapDataType::KeyAndValue(key, value) => vec![&**key, &**value],
+            };
+            (*frozen, Cow::Borrowed("map"), parameters)
+        }
+        // Tuples are always frozen.
+        CassDataTypeInner::Tuple(types) => (
+            true,
+            Cow::Borrowed("tuple"),
+            types.iter().map(|t| &**t).collect(),
+        ),
+    };
+
+    let write_frozen = frozen && !in_frozen;
+    if write_frozen {
+        out.push_str("frozen<");
+    }
+    out.push_str(&name);
+    if !parameters.is_empty() {
+        out.push('<');
+        for (i, parameter) in parameters.into_iter().enumerate() {
+            if i > 0 {
+                out.push_str(", ");
+            }
+            write_cql_type(
+                out,
+                unsafe { parameter.get_unchecked() },
+                frozen || in_frozen,
+            );
+        }
+        out.push('>');
+    }
+    if write_frozen {
+        out.push('>');
+    }
+}
+
+/// Returns the CQL literal of an option value, or None for values which
+/// can't be used as options.
+fn cql_literal(value: &CassValue) -> Option<String> {
+    let literal = match value.value.as_ref()? {
+        Value::RegularValue(value) => match value {
+            CqlValue::Ascii(s) | CqlValue::Text(s) => quote_string(s),
+            CqlValue::Boolean(b) => b.to_string(),
+            CqlValue::TinyInt(i) => i.to_string(),
+            CqlValue::SmallInt(i) => i.to_string(),
+            CqlValue::Int(i) => i.to_string(),
+            CqlValue::BigInt(i) => i.to_string(),
+            // Debug formatting keeps the decimal point, e.g. `1.0`.
+            CqlValue::Float(f) => format!("{:?}", f),
+            CqlValue::Double(d) => format!("{:?}", d),
+            CqlValue::Blob(blob) => blob.iter().fold("0x".to_owned(), |mut out, byte| {
+                let _ = write!(out, "{:02x}", byte);
+                out
+            }),
+            _ => return None,
+        },
+        Value::CollectionValue(collection) => match collection {
+            Collection::List(elements) => format!("[{}]", cql_literals(elements)?.join(", ")),
+            Collection::Set(elements) => format!("{{{}}}", cql_literals(elements)?.join(", ")),
+            Collection::Map(entries) => {
+                let entries = entries
+                    .iter()
+                    .map(|(key, value)| Some((cql_literal(key)?, cql_literal(value)?)))
+                    .collect::<Option<Vec<_>>>()?;
+                map_literal(entries)
+            }
+            _ => return None,
+        },
+    };
+
+    Some(literal)
+}
+
+fn cql_literals(values: &[CassValue]) -> Option<Vec<String>> {
+    values.iter().map(cql_literal).collect()
+}
+
+/// Returns the CQL literals of the keys and values of a map.
+fn map_literal_entries(value: &CassValue) -> Vec<(String, String)> {
+    match value.value.as_ref() {
+        Some(Value::CollectionValue(Collection::Map(entries))) => entries
+            .iter()
+            .filter_map(|(key, value)| Some((cql_literal(key)?, cql_literal(value)?)))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn map_literal(entries: impl IntoIterator<Item = (String, String)>) -> String {
+    let entries = entries
+        .into_iter()
+        .map(|(key, value)| format!("{}: {}", key, value))
+        .collect::<Vec<_>>();
+    format!("{{{}}}", entries.join(", "))
+}
+
+/// Returns the options of a table or view, in the order of the `system_schema` columns.
+fn table_options(fields: &CassMetaFields) -> Vec<String> {
+    (0..fields.len())
+        .filter_map(|i| fields.nth(i))
+        .filter(|(name, _)| !NON_OPTION_FIELDS.contains(name))
+        .filter_map(|(name, value)| Some(format!("{} = {}", name, cql_literal(value)?)))
+        .collect()
+}
+
+/// Returns a comment with the structure of a schema object which can't be recreated
+/// with CQL, e.g. a virtual table.
+fn virtual_comment(kind: &str, name: &str, structure: &str) -> String {
+    format!(
+        "/*\nWarning: {} is a virtual {} and cannot be recreated with CQL.\n\
+         Structure, for reference:\n{}\n*/",
+        name, kind, structure
+    )
+}
+
+pub fn keyspace_cql(keyspace_meta: &CassKeyspaceMeta) -> String {
+    let name = quote_identifier(&keyspace_meta.name);
+    let mut statements = Vec::new();
+
+    if keyspace_meta.is_virtual {
+        statements.push(virtual_comment(
+            "keyspace",
+            &name,
+            &format!("VIRTUAL KEYSPACE {};", name),
+        ));
+    } else {
+        // The class goes first, followed by the replication factors.
+        let mut replication = replication_options(&keyspace_meta.fields);
+        replication.sort_by_key(|(key, _)| key.as_str() != "'class'");
+        let mut statement = format!(
+            "CREATE KEYSPACE {} WITH replication = {}",
+            name,
+            map_literal(replication)
+        );
+        if let Some(durable_writes) = keyspace_meta.fields.boolean("durable_writes") {
+            let _ = write!(statement, " AND durable_writes = {}", durable_writes);
+        }
+        match keyspace_meta.initial_tablets {
+            Some(0) => statement.push_str(" AND tablets = {'enabled': true}"),
+            Some(initial_tablets) => {
+                let _ = write!(
+                    statement,
+                    " AND tablets = {{'initial': {}}}",
+                    initial_tablets
+                );
+            }
+            None => (),
+        }
+        statement.push(';');
+        statements.push(statement);
+    }
+
+    for user_type in user_types_in_dependency_order(keyspace_meta) {
+        statements.push(user_type_cql(user_type));
+    }
+
+    let mut functions: Vec<&CassFunctionMeta> =
+        keyspace_meta.functions.values().map(|f| &**f).collect();
+    functions.sort_by(|a, b| a.full_name.cmp(&b.full_name));
+    for function_meta in functions {
+        statements.push(function_cql(&keyspace_meta.name, function_meta));
+    }
+
+    let mut aggregates: Vec<&CassAggregateMeta> =
+        keyspace_meta.aggregates.values().map(|a| &**a).collect();
+    aggregates.sort_by(|a, b| a.full_name.cmp(&b.full_name));
+    for aggregate_meta in aggregates {
+        statements.push(aggregate_cql(&keyspace_meta.name, aggregate_meta));
+    }
+
+    let mut tables: Vec<&CassTableMeta> = keyspace_meta.tables.values().map(|t| &**t).collect();
+    tables.sort_by(|a, b| a.name.cmp(&b.name));
+    for table_meta in tables {
+        statements.push(table_cql(table_meta));
+    }
+
+    statements.join("\n\n")
+}
+
+fn replication_options(fields: &CassMetaFields) -> Vec<(String, String)> {
+    fields
+        .get("replication")
+        .map(map_literal_entries)
+        .unwrap_or_default()
+}
+
+/// Returns the user types of the keyspace, ordered so that each type is preceded
+/// by the types it uses. Otherwise, the types are ordered by name.
+fn user_types_in_dependency_order(keyspace_meta: &CassKeyspaceMeta) -> Vec<&UDTDataType> {
+    fn visit<'a>(
+        udt: &'a UDTDataType,
+        keyspace_meta: &'a CassKeyspaceMeta,
+        visited: &mut HashSet<&'a str>,
+        ordered: &mut Vec<&'a UDTDataType>,
+    ) {
+        if !visited.insert(udt.name.as_str()) {
+            return;
+        }
+        let mut dependencies = Vec::new();
+        for (_, field_type) in &udt.field_types {
+            collect_user_type_names(unsafe { field_type.get_unchecked() }, &mut dependencies);
+        }
+        for dependency in dependencies {
+            if let Some(dependency) = user_type(keyspace_meta, dependency) {
+                visit(dependency, keyspace_meta, visited, ordered);
+            }
+        }
+        ordered.push(udt);
+    }
+
+    let mut names: Vec<&String> = keyspace_meta.user_defined_type_data_type.keys().collect();
+    names.sort();
+
+    let mut visited = HashSet::new();
+    let mut ordered = Vec::new();
+    for name in names {
+        if let Some(udt) = user_type(keyspace_meta, name) {
+            visit(udt, keyspace_meta, &mut visited, &mut ordered);
+        }
+    }
+    ordered
+}
+
+fn user_type<'a>(keyspace_meta: &'a CassKeyspaceMeta, name: &str) -> Option<&'a UDTDataType> {
+    let data_type = keyspace_meta.user_defined_type_data_type.get(name)?;
+    match unsafe { data_type.get_unchecked() } {
+        CassDataTypeInner::UDT(udt) => Some(udt),
+        _ => None,
+    }
+}
+
+fn collect_user_type_names<'a>(data_type: &'a CassDataTypeInner, names: &mut Vec<&'a str>) {
+    let sub_types: Vec<&CassDataType> = match data_type {
+        CassDataTypeInner::UDT(udt) => {
+            names.push(udt.name.as_str());
+            return;
+        }
+        CassDataTypeInner::List { typ, .. } | CassDataTypeInner::Set { typ, .. } => {
+            typ.iter().map(|t| &**t).collect()
+        }
+        CassDataTypeInner::Map { typ, .. } => match typ {
+            MapDataType::Untyped => Vec::new(),
+            MapDataType::Key(key) => vec![&**key],
+            MapDataType::KeyAndValue(key, value) => vec![&**key, &**value],
+        },
+        CassDataTypeInner::Tuple(types) => types.iter().map(|t| &**t).collect(),
+        CassDataTypeInner::Value(_) | CassDataTypeInner::Custom(_) => Vec::new(),
+    };
+    for sub_type in sub_types {
+        collect_user_type_names(unsafe { sub_type.get_unchecked() }, names);
+    }
+}
+
+pub fn user_type_cql(udt: &UDTDataType) -> String {
+    let fields = udt
+        .field_types
+        .iter()
+        .map(|(name, typ)| format!("    {} {}", quote_identifier(name), cql_type(typ)))
+        .collect::<Vec<_>>();
+
+    format!(
+        "CREATE TYPE {} (\n{}\n);",
+        qualified_name(&udt.keyspace, &udt.name),
+        fields.join(",\n")
+    )
+}
+
+fn function_cql(keyspace_name: &str, function_meta: &CassFunctionMeta) -> String {
+    let arguments = function_meta
+        .arguments
+        .iter()
+        .map(|(name, typ)| format!("{} {}", quote_identifier(name), cql_type(typ)))
+        .collect::<Vec<_>>();
+
+    format!(
+        "CREATE FUNCTION {}({})\n    {} ON NULL INPUT\n    RETURNS {}\n    \
+         LANGUAGE {}\n    AS $${}$$;",
+        qualified_name(keyspace_name, &function_meta.name),
+        arguments.join(", "),
+        if function_meta.called_on_null_input {
+            "CALLED"
+        } else {
+            "RETURNS NULL"
+        },
+        cql_type(&function_meta.return_type),
+        function_meta.language,
+        function_meta.body
+    )
+}
+
+fn aggregate_cql(keyspace_name: &str, aggregate_meta: &CassAggregateMeta) -> String {
+    let argument_types = aggregate_meta
+        .argument_types
+        .iter()
+        .map(|typ| cql_type(typ))
+        .collect::<Vec<_>>();
+    let fields = &aggregate_meta.fields;
+
+    let mut statement = format!(
+        "CREATE AGGREGATE {}({})",
+        qualified_name(keyspace_name, &aggregate_meta.name),
+        argument_types.join(", ")
+    );
+    let state_func = fields
+        .text("state_func")
+        .or(aggregate_meta.state_func.as_ref().map(|f| f.name.as_str()));
+    if let Some(state_func) = state_func {
+        let _ = write!(statement, "\n    SFUNC {}", quote_identifier(state_func));
+    }
+    let _ = write!(
+        statement,
+        "\n    STYPE {}",
+        cql_type(&aggregate_meta.state_type)
+    );
+    let final_func = fields
+        .text("final_func")
+        .or(aggregate_meta.final_func.as_ref().map(|f| f.name.as_str()));
+    if let Some(final_func) = final_func {
+        let _ = write!(
+            statement,
+            "\n    FINALFUNC {}",
+            quote_identifier(final_func)
+        );
+    }
+    // The initial condition is stored as a CQL literal.
+    if let Some(init_cond) = fields.text("initcond") {
+        let _ = write!(statement, "\n    INITCOND {}", init_cond);
+    }
+    statement.push(';');
+    statement
+}
+
+/// Writes the column definitions and the primary key of a table or view.
+fn write_columns(out: &mut String, table_meta: &CassTableMeta) {
+    let mut other_columns = table_meta
+        .columns_metadata
+        .values()
+        .filter(|column| {
+            !table_meta.partition_keys.contains(&column.name)
+                && !table_meta.clustering_keys.contains(&column.name)
+        })
+        .collect::<Vec<_>>();
+    other_columns.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let key_columns = table_meta
+        .partition_keys
+        .iter()
+        .chain(table_meta.clustering_keys.iter())
+        .filter_map(|name| table_meta.columns_metadata.get(name));
+    for column in key_columns.chain(other_columns) {
+        let _ = write!(
+            out,
+            "    {} {}",
+            quote_identifier(&column.name),
+            cql_type(&column.column_type)
+        );
+        if column.column_kind == CassColumnType::CASS_COLUMN_TYPE_STATIC {
+            out.push_str(" static");
+        }
+        out.push_str(",\n");
+    }
+
+    let _ = write!(out, "    PRIMARY KEY ({})\n)", primary_key(table_meta));
+}
+
+fn primary_key(table_meta: &CassTableMeta) -> String {
+    let quote_all = |names: &[String]| {
+        names
+            .iter()
+            .map(|name| quote_identifier(name).into_owned())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let partition_key = if table_meta.partition_keys.len() == 1 {
+        quote_all(&table_meta.partition_keys)
+    } else {
+        format!("({})", quote_all(&table_meta.partition_keys))
+    };
+    if table_meta.clustering_keys.is_empty() {
+        partition_key
+    } else {
+        format!(
+            "{}, {}",
+            partition_key,
+            quote_all(&table_meta.clustering_keys)
+        )
+    }
+}
+
+/// Returns the properties of the `WITH` clause of a table or view: the clustering order
+/// and the options.
+fn table_properties(table_meta: &CassTableMeta) -> Vec<String> {
+    let mut options = Vec::new();
+    if !table_meta.clustering_keys.is_empty() {
+        let clustering_order = table_meta
+            .clustering_keys
+            .iter()
+            .map(|name| {
+                let order = match table_meta.columns_metadata.get(name) {
+                    Some(column)
+                        if column.clustering_order
+                            == CassClusteringOrder::CASS_CLUSTERING_ORDER_DESC =>
+                    {
+                        "DESC"
+                    }
+                    _ => "ASC",
+                };
+                format!("{} {}", quote_identifier(name), order)
+            })
+            .collect::<Vec<_>>();
+        options.push(format!(
+            "CLUSTERING ORDER BY ({})",
+            clustering_order.join(", ")
+        ));
+    }
+    options.extend(table_options(&table_meta.fields));
+    options
+}
+
+/// Renders a table together with its indexes and materialized views.
+pub fn table_cql(table_meta: &CassTableMeta) -> String {
+    let name = qualified_name(&table_meta.keyspace_name, &table_meta.name);
+    let mut out = String::new();
+
+    let keyword = if table_meta.is_virtual {
+        "VIRTUAL TABLE"
+    } else {
+        "CREATE TABLE"
+    };
+    let _ = writeln!(out, "{} {} (", keyword, name);
+    write_columns(&mut out, table_meta);
+    let properties = table_properties(table_meta);
+    if !properties.is_empty() {
+        let _ = write!(out, " WITH {}", properties.join("\n    AND "));
+    }
+    out.push(';');
+
+    if table_meta.is_virtual {
+        return virtual_comment("table", &name, &out);
+    }
+
+    let mut indexes: Vec<&CassIndexMeta> = table_meta.indexes.values().collect();
+    indexes.sort_by(|a, b| a.name.cmp(&b.name));
+    for index_meta in indexes {
+        let _ = write!(out, "\n\n{}", index_cql(table_meta, index_meta));
+    }
+
+    let mut views: Vec<&CassMaterializedViewMeta> =
+        table_meta.views.values().map(|v| &**v).collect();
+    views.sort_by(|a, b| a.name.cmp(&b.name));
+    for view_meta in views {
+        let _ = write!(out, "\n\n{}", view_cql(view_meta));
+    }
+
+    out
+}
+
+fn index_cql(table_meta: &CassTableMeta, index_meta: &CassIndexMeta) -> String {
+    let table_name = qualified_name(&table_meta.keyspace_name, &table_meta.name);
+    let index_name = quote_identifier(&index_meta.name);
+    let target = index_target(&index_meta.target);
+
+    let options = map_literal_entries(&index_meta.options);
+    let class_name = options
+        .iter()
+        .find(|(key, _)| key == "'class_name'")
+        .map(|(_, value)| value.clone());
+
+    match class_name {
+        Some(class_name) => {
+            let mut statement = format!(
+                "CREATE CUSTOM INDEX {} ON {} ({}) USING {}",
+                index_name, table_name, target, class_name
+            );
+            let other_options = options
+                .into_iter()
+                .filter(|(key, _)| key != "'class_name'" && key != "'target'")
+                .collect::<Vec<_>>();
+            if !other_options.is_empty() {
+                let _ = write!(statement, " WITH OPTIONS = {}", map_literal(other_options));
+            }
+            statement.push(';');
+            statement
+        }
+        None => format!(
+            "CREATE INDEX {} ON {} ({});",
+            index_name, table_name, target
+        ),
+    }
+}
+
+/// Converts the target of an index to its CQL form. Targets of Scylla's local
+/// secondary indexes are JSON objects, e.g. `{"pk":["p"],"ck":["v"]}`, which
+/// correspond to `(p), v`.
+fn index_target(target: &str) -> String {
+    if !target.starts_with('{') {
+        return target.to_owned();
+    }
+
+    let json_list = |key: &str| -> Vec<String> {
+        let Some(start) = target.find(&format!("\"{}\"", key)) else {
+            return Vec::new();
+        };
+        let rest = &target[start..];
+        let (Some(open), Some(close)) = (rest.find('['), rest.find(']')) else {
+            return Vec::new();
+        };
+        rest[open + 1..close]
+            .split(',')
+            .map(|element| element.trim().trim_matches('"').replace("\\\"", "\""))
+            .filter(|element| !element.is_empty())
+            .collect()
+    };
+
+    let partition_key = json_list("pk");
+    let clustering_key = json_list("ck");
+    let mut target = format!("({})", partition_key.join(", "));
+    for column in clustering_key {
+        let _ = write!(target, ", {}", column);
+    }
+    target
+}
+
+pub fn view_cql(view_meta: &CassMaterializedViewMeta) -> String {
+    let table_meta = &view_meta.view_metadata;
+    let fields = &table_meta.fields;
+    let base_table_name = view_meta
+        .base_table
+        .upgrade()
+        .map(|base_table| base_table.name.clone())
+        .or_else(|| fields.text("base_table_name").map(str::to_owned))
+        .unwrap_or_default();
+
+    let columns = if fields.boolean("include_all_columns") == Some(true) {
+        "*".to_owned()
+    } else {
+        let mut other_columns = table_meta
+            .columns_metadata
+            .keys()
+            .filter(|name| {
+                !table_meta.partition_keys.contains(name)
+                    && !table_meta.clustering_keys.contains(name)
+            })
+            .collect::<Vec<_>>();
+        other_columns.sort();
+        table_meta
+            .partition_keys
+            .iter()
+            .chain(table_meta.clustering_keys.iter())
+            .chain(other_columns)
+            .map(|name| quote_identifier(name).into_owned())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let mut out = format!(
+        "CREATE MATERIALIZED VIEW {} AS\n    SELECT {}\n    FROM {}",
+        qualified_name(&table_meta.keyspace_name, &view_meta.name),
+        columns,
+        qualified_name(&table_meta.keyspace_name, &base_table_name)
+    );
+    if let Some(where_clause) = fields.text("where_clause") {
+        let _ = write!(out, "\n    WHERE {}", where_clause);
+    }
+    let _ = write!(out, "\n    PRIMARY KEY ({})", primary_key(table_meta));
+    let properties = table_properties(table_meta);
+    if !properties.is_empty() {
+        let _ = write!(out, "\n    WITH {}", properties.join("\n    AND "));
+    }
+    out.push(';');
+    out
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_as_cql(
+    keyspace_meta: *const CassKeyspaceMeta,
+    output: *mut c_char,
+    output_size: size_t,
+    cql_length: *mut size_t,
+) -> CassError {
+    let keyspace_meta = RefFFI::as_ref(keyspace_meta);
+
+    write_str_to_buffer(
+        &keyspace_cql(keyspace_meta),
+        output,
+        output_size,
+        cql_length,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_table_meta_as_cql(
+    table_meta: *const CassTableMeta,
+    output: *mut c_char,
+    output_size: size_t,
+    cql_length: *mut size_t,
+) -> CassError {
+    let table_meta = RefFFI::as_ref(table_meta);
+
+    write_str_to_buffer(&table_cql(table_meta), output, output_size, cql_length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_materialized_view_meta_as_cql(
+    view_meta: *const CassMaterializedViewMeta,
+    output: *mut c_char,
+    output_size: size_t,
+    cql_length: *mut size_t,
+) -> CassError {
+    let view_meta = RefFFI::as_ref(view_meta);
+
+    write_str_to_buffer(&view_cql(view_meta), output, output_size, cql_length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_user_type_as_cql(
+    data_type: *const CassDataType,
+    output: *mut c_char,
+    output_size: size_t,
+    cql_length: *mut size_t,
+) -> CassError {
+    let data_type = ArcFFI::as_ref(data_type);
+
+    match data_type.get_unchecked() {
+        CassDataTypeInner::UDT(udt) => {
+            write_str_to_buffer(&user_type_cql(udt), output, output_size, cql_length)
+        }
+        _ => CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::{
+        create_index_metadata, create_virtual_table_metadata, IndexRow, KeyspaceReplication,
+    };
+    use crate::testing::make_fields;
+    use scylla::transport::topology::Strategy;
+    use std::collections::{BTreeMap, HashMap};
+    use std::sync::{Arc, Weak};
+
+    fn value_type(value_type: CassValueType) -> Arc<CassDataType> {
+        CassDataType::new_arced(CassDataTypeInner::Value(value_type))
+    }
+
+    fn text_type() -> Arc<CassDataType> {
+        value_type(CassValueType::CASS_VALUE_TYPE_VARCHAR)
+    }
+
+    fn int_type() -> Arc<CassDataType> {
+        value_type(CassValueType::CASS_VALUE_TYPE_INT)
+    }
+
+    fn text(text: &str) -> Option<CqlValue> {
+        Some(CqlValue::Text(text.to_owned()))
+    }
+
+    fn text_map_type() -> Arc<CassDataType> {
+        CassDataType::new_arced(CassDataTypeInner::Map {
+            typ: MapDataType::KeyAndValue(text_type(), text_type()),
+            frozen: true,
+        })
+    }
+
+    fn text_map(entries: &[(&str, &str)]) -> Option<CqlValue> {
+        Some(CqlValue::Map(
+            entries
+                .iter()
+                .map(|(key, value)| (text(key).unwrap(), text(value).unwrap()))
+                .collect(),
+        ))
+    }
+
+    fn udt(name: &str, field_types: Vec<(&str, Arc<CassDataType>)>, frozen: bool) -> UDTDataType {
+        UDTDataType {
+            field_types: field_types
+                .into_iter()
+                .map(|(name, typ)| (name.to_owned(), typ))
+                .collect(),
+            keyspace: "ks".to_owned(),
+            name: name.to_owned(),
+            frozen,
+        }
+    }
+
+    /// Creates a table from the raw rows of its columns, given as (name, kind, position,
+    /// clustering order, type).
+    fn table(
+        name: &str,
+        fields: CassMetaFields,
+        columns: &[(&str, &str, i32, &str, &str)],
+    ) -> CassTableMeta {
+        let column_fields = columns
+            .iter()
+            .map(|(name, kind, position, order, typ)| {
+                make_fields(vec![
+                    ("column_name", text_type(), text(name)),
+                    ("kind", text_type(), text(kind)),
+                    ("position", int_type(), Some(CqlValue::Int(*position))),
+                    ("clustering_order", text_type(), text(order)),
+                    ("type", text_type(), text(typ)),
+                ])
+            })
+            .collect();
+
+        let mut table_meta = create_virtual_table_metadata("ks", name, fields, column_fields);
+        table_meta.is_virtual = false;
+        table_meta
+    }
+
+    fn index(name: &str, options: &[(&str, &str)]) -> CassIndexMeta {
+        let options: BTreeMap<String, String> = options
+            .iter()
+            .map(|(key, value)| ((*key).to_owned(), (*value).to_owned()))
+            .collect();
+        let kind = if options.contains_key("class_name") {
+            "CUSTOM"
+        } else {
+            "COMPOSITES"
+        };
+
+        create_index_metadata(
+            IndexRow {
+                keyspace_name: "ks".to_owned(),
+                table_name: "t".to_owned(),
+                index_name: name.to_owned(),
+                kind: Some(kind.to_owned()),
+                options: Some(options),
+            },
+            CassMetaFields::default(),
+        )
+    }
+
+    #[test]
+    fn cql_type_test() {
+        let list_type = |typ, frozen| {
+            CassDataType::new_arced(CassDataTypeInner::List {
+                typ: Some(typ),
+                frozen,
+            })
+        };
+        let address_type = |frozen| {
+            CassDataType::new_arced(CassDataTypeInner::UDT(udt("Address", vec![], frozen)))
+        };
+
+        assert_eq!(cql_type(&text_type()), "text");
+        assert_eq!(
+            cql_type(&list_type(address_type(true), false)),
+            "list<frozen<\"Address\">>"
+        );
+        assert_eq!(
+            cql_type(&list_type(list_type(int_type(), true), true)),
+            "frozen<list<list<int>>>"
+        );
+        assert_eq!(
+            cql_type(&CassDataType::new_arced(CassDataTypeInner::Tuple(vec![
+                int_type(),
+                text_map_type()
+            ]))),
+            "frozen<tuple<int, map<text, text>>>"
+        );
+        assert_eq!(
+            cql_type(&CassDataType::new_arced(CassDataTypeInner::Custom(
+                "org.Custom".to_owned()
+            ))),
+            "'org.Custom'"
+        );
+    }
+
+    #[test]
+    fn index_target_test() {
+        assert_eq!(index_target("v"), "v");
+        assert_eq!(index_target("keys(m)"), "keys(m)");
+        assert_eq!(
+            index_target(r#"{"pk":["p1","p2"],"ck":["v"]}"#),
+            "(p1, p2), v"
+        );
+    }
+
+    #[test]
+    fn keyspace_cql_test() {
+        let keyspace_fields = make_fields(vec![
+            ("keyspace_name", text_type(), text("ks")),
+            (
+                "durable_writes",
+                value_type(CassValueType::CASS_VALUE_TYPE_BOOLEAN),
+                Some(CqlValue::Boolean(true)),
+            ),
+            (
+                "replication",
+                text_map_type(),
+                text_map(&[
+                    ("DC1", "3"),
+                    (
+                        "class",
+                        "org.apache.cassandra.locator.NetworkTopologyStrategy",
+                    ),
+                ]),
+            ),
+        ]);
+
+        let address_type = udt(
+            "z_address",
+            vec![("street", text_type()), ("Zip", int_type())],
+            true,
+        );
+        let user_type = udt(
+            "a_user",
+            vec![(
+                "addresses",
+                CassDataType::new_arced(CassDataTypeInner::List {
+                    typ: Some(CassDataType::new_arced(CassDataTypeInner::UDT(
+                        address_type.clone(),
+                    ))),
+                    frozen: false,
+                }),
+            )],
+            false,
+        );
+
+        let function_meta = Arc::new(CassFunctionMeta {
+            name: "f".to_owned(),
+            full_name: "f(int)".to_owned(),
+            arguments: vec![("x".to_owned(), int_type())],
+            return_type: int_type(),
+            body: "return x;".to_owned(),
+            language: "lua".to_owned(),
+            called_on_null_input: false,
+            fields: CassMetaFields::default(),
+        });
+        let aggregate_meta = Arc::new(CassAggregateMeta {
+            name: "agg".to_owned(),
+            full_name: "agg(int)".to_owned(),
+            argument_types: vec![int_type()],
+            return_type: int_type(),
+            state_type: int_type(),
+            state_func: Some(function_meta.clone()),
+            final_func: None,
+            init_cond: CassValue {
+                value: None,
+                value_type: text_type(),
+            },
+            fields: make_fields(vec![("initcond", text_type(), text("0"))]),
+        });
+
+        let mut table_meta = table(
+            "t",
+            make_fields(vec![
+                ("keyspace_name", text_type(), text("ks")),
+                ("table_name", text_type(), text("t")),
+                ("comment", text_type(), text("it's")),
+                (
+                    "compaction",
+                    text_map_type(),
+                    text_map(&[("class", "SizeTieredCompactionStrategy")]),
+                ),
+                (
+                    "crc_check_chance",
+                    value_type(CassValueType::CASS_VALUE_TYPE_DOUBLE),
+                    Some(CqlValue::Double(1.0)),
+                ),
+                ("gc_grace_seconds", int_type(), Some(CqlValue::Int(864000))),
+                ("speculative_retry", text_type(), None),
+            ]),
+            &[
+                ("value", "regular", -1, "none", "text"),
+                ("ck2", "clustering", 1, "asc", "int"),
+                ("pk", "partition_key", 0, "none", "text"),
+                ("s", "static", -1, "none", "int"),
+                ("ck1", "clustering", 0, "desc", "timestamp"),
+            ],
+        );
+        table_meta.indexes = HashMap::from([
+            (
+                "t_value_idx".to_owned(),
+                index("t_value_idx", &[("target", "value")]),
+            ),
+            (
+                "t_local_idx".to_owned(),
+                index("t_local_idx", &[("target", r#"{"pk":["pk"],"ck":["s"]}"#)]),
+            ),
+        ]);
+
+        let view_meta = Arc::new(CassMaterializedViewMeta {
+            name: "mv".to_owned(),
+            view_metadata: table(
+                "mv",
+                make_fields(vec![
+                    ("base_table_name", text_type(), text("t")),
+                    (
+                        "include_all_columns",
+                        value_type(CassValueType::CASS_VALUE_TYPE_BOOLEAN),
+                        Some(CqlValue::Boolean(false)),
+                    ),
+                    (
+                        "where_clause",
+                        text_type(),
+                        text("value IS NOT NULL AND pk IS NOT NULL"),
+                    ),
+                ]),
+                &[
+                    ("value", "partition_key", 0, "none", "text"),
+                    ("pk", "clustering", 0, "asc", "text"),
+                    ("ck1", "clustering", 1, "desc", "timestamp"),
+                    ("ck2", "clustering", 2, "asc", "int"),
+                ],
+            ),
+            base_table: Weak::new(),
+        });
+        table_meta.views = HashMap::from([("mv".to_owned(), view_meta.clone())]);
+
+        let keyspace_meta = CassKeyspaceMeta {
+            name: "ks".to_owned(),
+            user_defined_type_data_type: HashMap::from([
+                (
+                    "a_user".to_owned(),
+                    CassDataType::new_arced(CassDataTypeInner::UDT(user_type)),
+                ),
+                (
+                    "z_address".to_owned(),
+                    CassDataType::new_arced(CassDataTypeInner::UDT(address_type)),
+                ),
+            ]),
+            tables: HashMap::from([("t".to_owned(), Arc::new(table_meta))]),
+            views: HashMap::from([("mv".to_owned(), view_meta)]),
+            functions: HashMap::from([("f(int)".to_owned(), function_meta)]),
+            aggregates: HashMap::from([("agg(int)".to_owned(), aggregate_meta)]),
+            replication: KeyspaceReplication::new(&Strategy::NetworkTopologyStrategy {
+                datacenter_repfactors: HashMap::from([("DC1".to_owned(), 3)]),
+            }),
+            durable_writes: true,
+            initial_tablets: Some(8),
+            fields: keyspace_fields,
+            is_virtual: false,
+        };
+
+        let expected = [
+            "CREATE KEYSPACE ks WITH replication = {'class': \
+             'org.apache.cassandra.locator.NetworkTopologyStrategy', 'DC1': '3'} \
+             AND durable_writes = true AND tablets = {'initial': 8};",
+            "CREATE TYPE ks.z_address (\n    street text,\n    \"Zip\" int\n);",
+            "CREATE TYPE ks.a_user (\n    addresses list<frozen<z_address>>\n);",
+            "CREATE FUNCTION ks.f(x int)\n    RETURNS NULL ON NULL INPUT\n    RETURNS int\n    \
+             LANGUAGE lua\n    AS $$return x;$$;",
+            "CREATE AGGREGATE ks.agg(int)\n    SFUNC f\n    STYPE int\n    INITCOND 0;",
+            "CREATE TABLE ks.t (\n    pk text,\n    ck1 timestamp,\n    ck2 int,\n    \
+             s int static,\n    value text,\n    PRIMARY KEY (pk, ck1, ck2)\n\
+             ) WITH CLUSTERING ORDER BY (ck1 DESC, ck2 ASC)\n    AND comment = 'it''s'\n    \
+             AND compaction = {'class': 'SizeTieredCompactionStrategy'}\n    \
+             AND crc_check_chance = 1.0\n    AND gc_grace_seconds = 864000;",
+            "CREATE INDEX t_local_idx ON ks.t ((pk), s);",
+            "CREATE INDEX t_value_idx ON ks.t (value);",
+            "CREATE MATERIALIZED VIEW ks.mv AS\n    SELECT value, pk, ck1, ck2\n    FROM ks.t\n    \
+             WHERE value IS NOT NULL AND pk IS NOT NULL\n    PRIMARY KEY (value, pk, ck1, ck2)\n    \
+             WITH CLUSTERING ORDER BY (pk ASC, ck1 DESC, ck2 ASC);",
+        ]
+        .join("\n\n");
+        assert_eq!(keyspace_cql(&keyspace_meta), expected);
+
+        let custom_index = index(
+            "t_custom_idx",
+            &[
+                ("class_name", "org.Custom"),
+                ("mode", "CONTAINS"),
+                ("target", "value"),
+            ],
+        );
+        assert_eq!(
+            index_cql(&keyspace_meta.tables["t"], &custom_index),
+            "CREATE CUSTOM INDEX t_custom_idx ON ks.t (value) USING 'org.Custom' \
+             WITH OPTIONS = {'mode': 'CONTAINS'};"
+        );
+    }
+
+    #[test]
+    fn virtual_table_cql_test() {
+        let mut table_meta = table(
+            "clients",
+            make_fields(vec![("comment", text_type(), text("connected clients"))]),
+            &[
+                ("address", "partition_key", 0, "none", "inet"),
+                ("port", "clustering", 0, "asc", "int"),
+            ],
+        );
+        table_meta.is_virtual = true;
+
+        assert_eq!(
+            table_cql(&table_meta),
+            "/*\nWarning: ks.clients is a virtual table and cannot be recreated with CQL.\n\
+             Structure, for reference:\n\
+             VIRTUAL TABLE ks.clients (\n    address inet,\n    port int,\n    \
+             PRIMARY KEY (address, port)\n\
+             ) WITH CLUSTERING ORDER BY (port ASC)\n    AND comment = 'connected clients';\n*/"
+        );
+    }
+}
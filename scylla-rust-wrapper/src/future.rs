@@ -1,10 +1,13 @@
 use crate::argconv::*;
+use crate::callback_executor::CallbackExecutor;
 use crate::cass_error::CassError;
 use crate::cass_error::CassErrorMessage;
 use crate::cass_error::ToCassError;
+use crate::completion_queue::CassCompletionQueue;
 use crate::prepared::CassPrepared;
 use crate::query_error::CassErrorResult;
 use crate::query_result::CassResult;
+use crate::request_history::{CassErrorDetail, CassExecutionAttempt, RequestHistory};
 use crate::types::*;
 use crate::uuid::CassUuid;
 use crate::RUNTIME;
@@ -12,15 +15,18 @@ use futures::future;
 use std::future::Future;
 use std::mem;
 use std::os::raw::c_void;
-use std::sync::{Arc, Condvar, Mutex};
+use std::sync::{Arc, Condvar, Mutex, Weak};
+use tokio::sync::{oneshot, Notify};
 use tokio::task::JoinHandle;
 use tokio::time::Duration;
+use uuid::Uuid;
 
 pub enum CassResultValue {
     Empty,
     QueryResult(Arc<CassResult>),
     QueryError(Arc<CassErrorResult>),
     Prepared(Arc<CassPrepared>),
+    SchemaVersion(Uuid),
 }
 
 type CassFutureError = (CassError, String);
@@ -53,6 +59,59 @@ struct CassFutureState {
     err_string: Option<String>,
     callback: Option<BoundCallback>,
     join_handle: Option<JoinHandle<()>>,
+    // Used to stop the task, which then completes the future with cancellation error.
+    cancel_sender: Option<oneshot::Sender<()>>,
+    // Whether the future was cancelled. Once set, the task completes the future
+    // with cancellation error, even if the request has completed in the meantime.
+    cancelled: bool,
+    // Notifiers of the callers waiting on multiple futures, including this one.
+    set_notifiers: Vec<Weak<FutureSetNotifier>>,
+    // Queue, which the future is pushed to once it's set.
+    completion_queue: Option<Weak<CassCompletionQueue>>,
+    // Attempts made on behalf of the request, exposed as per-host error details.
+    request_history: Option<Arc<RequestHistory>>,
+}
+
+/// Wakes up the threads and tasks waiting on multiple futures,
+/// whenever any of them is set.
+#[derive(Default)]
+struct FutureSetNotifier {
+    set_count: Mutex<usize>,
+    condvar: Condvar,
+    notify: Notify,
+}
+
+impl FutureSetNotifier {
+    fn notify(&self) {
+        *self.set_count.lock().unwrap() += 1;
+        self.condvar.notify_all();
+        // Stores a permit if the task is not waiting yet, so the wakeup is not lost.
+        self.notify.notify_one();
+    }
+
+    /// Registers the notifier in the futures, unless it's registered already,
+    /// and returns the index of any future which is already set.
+    fn register(self: &Arc<Self>, futures: &[&CassFuture]) -> Option<usize> {
+        let this = Arc::downgrade(self);
+        let mut ready_index = None;
+        for (index, future) in futures.iter().enumerate() {
+            let mut lock = future.state.lock().unwrap();
+            if lock.value.is_some() {
+                ready_index.get_or_insert(index);
+                continue;
+            }
+            lock.set_notifiers
+                .retain(|notifier| notifier.strong_count() > 0);
+            if !lock
+                .set_notifiers
+                .iter()
+                .any(|notifier| notifier.ptr_eq(&this))
+            {
+                lock.set_notifiers.push(this.clone());
+            }
+        }
+        ready_index
+    }
 }
 
 pub struct CassFuture {
@@ -68,6 +127,13 @@ enum FutureError {
     InvalidDuration,
 }
 
+fn cancelled_result() -> CassFutureResult {
+    Err((
+        CassError::CASS_ERROR_LIB_REQUEST_CANCELLED,
+        "Request was cancelled".msg(),
+    ))
+}
+
 /// The timeout appeared when we tried to await `JoinHandle`.
 /// This errors contains the original handle, so it can be awaited later again.
 struct JoinHandleTimeout(JoinHandle<()>);
@@ -81,29 +147,86 @@ impl CassFuture {
 
     pub fn new_from_future(
         fut: impl Future<Output = CassFutureResult> + Send + 'static,
+    ) -> Arc<CassFuture> {
+        Self::new_from_future_with_callback_executor(fut, async { CallbackExecutor::Inline })
+    }
+
+    /// Same as [CassFuture::make_raw], but the callback is dispatched to the executor
+    /// returned by `callback_executor`, which is awaited once `fut` completes.
+    pub fn make_raw_with_callback_executor(
+        fut: impl Future<Output = CassFutureResult> + Send + 'static,
+        callback_executor: impl Future<Output = CallbackExecutor> + Send + 'static,
+    ) -> *mut CassFuture {
+        Self::new_from_future_with_callback_executor(fut, callback_executor).into_raw() as *mut _
+    }
+
+    /// Same as [CassFuture::make_raw_with_callback_executor], but the future
+    /// also exposes the attempts recorded in `request_history`.
+    pub fn make_raw_with_request_history(
+        fut: impl Future<Output = CassFutureResult> + Send + 'static,
+        callback_executor: impl Future<Output = CallbackExecutor> + Send + 'static,
+        request_history: Arc<RequestHistory>,
+    ) -> *mut CassFuture {
+        let cass_fut = Self::new_from_future_with_callback_executor(fut, callback_executor);
+        cass_fut.state.lock().unwrap().request_history = Some(request_history);
+        cass_fut.into_raw() as *mut _
+    }
+
+    pub fn new_from_future_with_callback_executor(
+        fut: impl Future<Output = CassFutureResult> + Send + 'static,
+        callback_executor: impl Future<Output = CallbackExecutor> + Send + 'static,
     ) -> Arc<CassFuture> {
         let cass_fut = Arc::new(CassFuture {
             state: Mutex::new(Default::default()),
             wait_for_value: Condvar::new(),
         });
         let cass_fut_clone = cass_fut.clone();
+        let (cancel_sender, cancel_receiver) = oneshot::channel();
         let join_handle = RUNTIME.spawn(async move {
-            let r = fut.await;
-            let maybe_cb = {
+            // The request is cancelled by dropping `fut`. The task itself sets the
+            // value, so that it's set (and the callback is invoked) exactly once.
+            // The cancellation is polled first, so that it takes precedence.
+            let r = match future::select(cancel_receiver, Box::pin(fut)).await {
+                future::Either::Left((Ok(()), _)) => cancelled_result(),
+                // The sender is never dropped without sending, but let's be safe.
+                future::Either::Left((Err(_), fut)) => fut.await,
+                future::Either::Right((r, _)) => r,
+            };
+            let callback_executor = callback_executor.await;
+            let (maybe_cb, set_notifiers, maybe_queue) = {
                 let mut guard = cass_fut_clone.state.lock().unwrap();
-                guard.value = Some(r);
+                // The outcome of `cancel` is decided under the lock, so the result
+                // of a request completed concurrently with the cancellation is dropped.
+                guard.value = Some(if guard.cancelled {
+                    cancelled_result()
+                } else {
+                    r
+                });
                 // Take the callback and call it after releasing the lock
-                guard.callback.take()
+                (
+                    guard.callback.take(),
+                    mem::take(&mut guard.set_notifiers),
+                    guard.completion_queue.as_ref().and_then(Weak::upgrade),
+                )
             };
             if let Some(bound_cb) = maybe_cb {
-                bound_cb.invoke(cass_fut_clone.as_ref());
+                let cass_fut = cass_fut_clone.clone();
+                callback_executor.execute(move || bound_cb.invoke(cass_fut.as_ref()));
             }
 
             cass_fut_clone.wait_for_value.notify_all();
+            set_notifiers
+                .iter()
+                .filter_map(Weak::upgrade)
+                .for_each(|notifier| notifier.notify());
+            if let Some(queue) = maybe_queue {
+                queue.push(cass_fut_clone.clone());
+            }
         });
         {
             let mut lock = cass_fut.state.lock().unwrap();
             lock.join_handle = Some(join_handle);
+            lock.cancel_sender = Some(cancel_sender);
         }
         cass_fut
     }
@@ -118,6 +241,30 @@ impl CassFuture {
         })
     }
 
+    /// Awaits the future, and returns the outcomes of the attempts made
+    /// on behalf of the request.
+    pub(crate) fn error_details(&self) -> Vec<CassErrorDetail> {
+        self.with_waited_state(|state| {
+            state
+                .request_history
+                .as_ref()
+                .map(|history| history.error_details())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Awaits the future, and returns the execution history of the request,
+    /// if it was enabled.
+    pub(crate) fn execution_history(&self) -> Vec<CassExecutionAttempt> {
+        self.with_waited_state(|state| {
+            state
+                .request_history
+                .as_ref()
+                .map(|history| history.execution_history())
+                .unwrap_or_default()
+        })
+    }
+
     pub fn with_waited_result<T>(&self, f: impl FnOnce(&mut CassFutureResult) -> T) -> T {
         self.with_waited_state(|s| f(s.value.as_mut().unwrap()))
     }
@@ -276,6 +423,139 @@ impl CassFuture {
         CassError::CASS_OK
     }
 
+    /// Cancels the underlying task, unless the future is already set.
+    /// Returns whether the future was cancelled, i.e. whether it is
+    /// (or is going to be) set with cancellation error.
+    pub fn cancel(&self) -> bool {
+        let mut lock = self.state.lock().unwrap();
+        if lock.value.is_some() {
+            return false;
+        }
+        // The sender is missing if the future was already cancelled.
+        let Some(cancel_sender) = lock.cancel_sender.take() else {
+            return false;
+        };
+        lock.cancelled = true;
+
+        // Sending fails if the task has already completed the request.
+        // It is going to set the cancellation error anyway, once it takes the lock.
+        let _ = cancel_sender.send(());
+        true
+    }
+
+    pub(crate) fn set_completion_queue(
+        self: &Arc<Self>,
+        queue: &Arc<CassCompletionQueue>,
+    ) -> CassError {
+        let mut lock = self.state.lock().unwrap();
+        if lock.completion_queue.is_some() {
+            // The future has been already attached to a queue.
+            return CassError::CASS_ERROR_LIB_CALLBACK_ALREADY_SET;
+        }
+        lock.completion_queue = Some(Arc::downgrade(queue));
+        if lock.value.is_some() {
+            // The value is already available, we need to queue the future ourselves
+            mem::drop(lock);
+            queue.push(self.clone());
+        }
+        CassError::CASS_OK
+    }
+
+    fn is_set(&self) -> bool {
+        self.state.lock().unwrap().value.is_some()
+    }
+
+    /// Waits until any of the futures is set, and returns its index.
+    /// Returns `None` if timed out.
+    fn wait_any(futures: &[&CassFuture], timeout_duration: Duration) -> Option<usize> {
+        if futures.is_empty() {
+            return None;
+        }
+        let deadline = std::time::Instant::now().checked_add(timeout_duration);
+        let notifier = Arc::new(FutureSetNotifier::default());
+        CassFuture::wait_any_notified(&notifier, futures, deadline)
+    }
+
+    /// Like [CassFuture::wait_any], but waits until the `deadline`
+    /// (or indefinitely, if there is none) using the given notifier,
+    /// so that the notifier may be reused in the next wait.
+    fn wait_any_notified(
+        notifier: &Arc<FutureSetNotifier>,
+        futures: &[&CassFuture],
+        deadline: Option<std::time::Instant>,
+    ) -> Option<usize> {
+        loop {
+            // Snapshot the counter before checking the futures, so that
+            // a future set in between is not missed.
+            let set_count = *notifier.set_count.lock().unwrap();
+            if let Some(index) = notifier.register(futures) {
+                return Some(index);
+            }
+
+            let guard = notifier.set_count.lock().unwrap();
+            let guard = match deadline {
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+                    let (guard, timeout_result) = notifier
+                        .condvar
+                        .wait_timeout_while(guard, remaining, |count| *count == set_count)
+                        .unwrap();
+                    if timeout_result.timed_out() {
+                        return None;
+                    }
+                    guard
+                }
+                // The deadline overflowed - wait indefinitely.
+                None => notifier
+                    .condvar
+                    .wait_while(guard, |count| *count == set_count)
+                    .unwrap(),
+            };
+            mem::drop(guard);
+        }
+    }
+
+    /// Waits until all the futures are set. Returns `false` if timed out.
+    fn wait_all(futures: &[&CassFuture], timeout_duration: Duration) -> bool {
+        let deadline = std::time::Instant::now().checked_add(timeout_duration);
+        // A single notifier is registered in each future once,
+        // rather than once per wait.
+        let notifier = Arc::new(FutureSetNotifier::default());
+        let mut pending: Vec<&CassFuture> = futures.to_vec();
+
+        while !pending.is_empty() {
+            match CassFuture::wait_any_notified(&notifier, &pending, deadline) {
+                Some(_) => pending.retain(|future| !future.is_set()),
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// Creates a future, which is set when all the given futures are set.
+    fn new_all(futures: Vec<Arc<CassFuture>>) -> Arc<CassFuture> {
+        CassFuture::new_from_future(async move {
+            let notifier = Arc::new(FutureSetNotifier::default());
+            loop {
+                let pending: Vec<&CassFuture> = futures
+                    .iter()
+                    .map(Arc::as_ref)
+                    .filter(|future| !future.is_set())
+                    .collect();
+                if pending.is_empty() {
+                    return Ok(CassResultValue::Empty);
+                }
+
+                // A future set after filtering is not going to notify,
+                // as it wasn't registered in, so filter again.
+                if notifier.register(&pending).is_some() {
+                    continue;
+                }
+                notifier.notify.notified().await;
+            }
+        })
+    }
+
     fn into_raw(self: Arc<Self>) -> *const Self {
         ArcFFI::into_ptr(self)
     }
@@ -311,6 +591,68 @@ pub unsafe extern "C" fn cass_future_wait_timed(
         .is_ok() as cass_bool_t
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_future_cancel(future_raw: *const CassFuture) -> cass_bool_t {
+    ArcFFI::as_ref(future_raw).cancel() as cass_bool_t
+}
+
+unsafe fn futures_from_raw<'a>(
+    futures_raw: *const *const CassFuture,
+    futures_count: size_t,
+) -> &'a [*const CassFuture] {
+    if futures_raw.is_null() {
+        return &[];
+    }
+    std::slice::from_raw_parts(futures_raw, futures_count as usize)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_future_wait_any(
+    futures_raw: *const *const CassFuture,
+    futures_count: size_t,
+    timeout_us: cass_duration_t,
+    index: *mut size_t,
+) -> cass_bool_t {
+    let futures: Vec<&CassFuture> = futures_from_raw(futures_raw, futures_count)
+        .iter()
+        .map(|future_raw| ArcFFI::as_ref(*future_raw))
+        .collect();
+    match CassFuture::wait_any(&futures, Duration::from_micros(timeout_us)) {
+        Some(ready_index) => {
+            *index = ready_index as size_t;
+            cass_true
+        }
+        None => cass_false,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_future_wait_all(
+    futures_raw: *const *const CassFuture,
+    futures_count: size_t,
+    timeout_us: cass_duration_t,
+) -> cass_bool_t {
+    let futures: Vec<&CassFuture> = futures_from_raw(futures_raw, futures_count)
+        .iter()
+        .map(|future_raw| ArcFFI::as_ref(*future_raw))
+        .collect();
+    CassFuture::wait_all(&futures, Duration::from_micros(timeout_us)) as cass_bool_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_future_all(
+    futures_raw: *const *const CassFuture,
+    futures_count: size_t,
+) -> *const CassFuture {
+    // Keep the futures alive, even if they are freed by the caller.
+    let futures = futures_from_raw(futures_raw, futures_count)
+        .iter()
+        .map(|future_raw| ArcFFI::cloned_from_ptr(*future_raw))
+        .collect();
+
+    CassFuture::new_all(futures).into_raw()
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_future_ready(future_raw: *const CassFuture) -> cass_bool_t {
     let state_guard = ArcFFI::as_ref(future_raw).state.lock().unwrap();
@@ -412,6 +754,34 @@ pub unsafe extern "C" fn cass_future_tracing_id(
     })
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_future_schema_version(
+    future: *const CassFuture,
+    schema_version: *mut CassUuid,
+) -> CassError {
+    ArcFFI::as_ref(future).with_waited_result(|r: &mut CassFutureResult| match r {
+        Ok(CassResultValue::SchemaVersion(version)) => {
+            *schema_version = CassUuid::from(*version);
+            CassError::CASS_OK
+        }
+        Ok(CassResultValue::QueryError(err)) => err.to_cass_error(),
+        Err((err, _)) => *err,
+        _ => CassError::CASS_ERROR_LIB_INVALID_FUTURE_TYPE,
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_future_schema_in_agreement(future: *const CassFuture) -> cass_bool_t {
+    ArcFFI::as_ref(future).with_waited_result(|r: &mut CassFutureResult| {
+        let in_agreement = match r {
+            Ok(CassResultValue::QueryResult(result)) => result.schema_in_agreement,
+            Ok(CassResultValue::SchemaVersion(_)) => true,
+            _ => false,
+        };
+        in_agreement as cass_bool_t
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::testing::{assert_cass_error_eq, assert_cass_future_error_message_eq};
@@ -419,6 +789,7 @@ mod tests {
     use super::*;
     use std::{
         os::raw::c_char,
+        ptr::addr_of_mut,
         thread::{self},
         time::Duration,
     };
@@ -575,4 +946,229 @@ mod tests {
             }
         }
     }
+
+    // This test checks that cancelled future is set with cancellation error,
+    // and its callback is executed exactly once.
+    #[test]
+    #[ntest::timeout(200)]
+    fn cass_future_cancel_test() {
+        unsafe extern "C" fn count_cb(_fut: *const CassFuture, data: *mut c_void) {
+            let counter = data as *mut u32;
+            *counter += 1;
+        }
+
+        unsafe {
+            const ERROR_MSG: &str = "NOBODY EXPECTED SPANISH INQUISITION";
+            const TEN_SECONDS_IN_MICROS: u64 = 10 * 1000 * 1000;
+
+            // Cancel the future while some thread waits for it.
+            {
+                let fut = async move {
+                    tokio::time::sleep(Duration::from_micros(TEN_SECONDS_IN_MICROS)).await;
+                    Err((CassError::CASS_OK, ERROR_MSG.into()))
+                };
+                let cass_fut = CassFuture::make_raw(fut);
+                let counter_ptr = Box::into_raw(Box::new(0u32));
+                assert_cass_error_eq!(
+                    cass_future_set_callback(cass_fut, Some(count_cb), counter_ptr as *mut c_void),
+                    CassError::CASS_OK
+                );
+
+                // Take the join handle and return it after timeout.
+                assert_eq!(0, cass_future_wait_timed(cass_fut, 10 * 1000));
+
+                struct PtrWrapper(*mut CassFuture);
+                unsafe impl Send for PtrWrapper {}
+                let wrapped_cass_fut = PtrWrapper(cass_fut);
+                let handle = thread::spawn(move || {
+                    let wrapper = wrapped_cass_fut;
+                    let PtrWrapper(cass_fut) = wrapper;
+                    cass_future_wait(cass_fut);
+                });
+
+                assert_ne!(0, cass_future_cancel(cass_fut));
+                handle.join().unwrap();
+                cass_future_wait(cass_fut);
+
+                assert_cass_error_eq!(
+                    cass_future_error_code(cass_fut),
+                    CassError::CASS_ERROR_LIB_REQUEST_CANCELLED
+                );
+                assert_eq!(1, *counter_ptr);
+
+                // Cancelling the future, which is already set, has no effect.
+                assert_eq!(0, cass_future_cancel(cass_fut));
+                assert_eq!(1, *counter_ptr);
+
+                cass_future_free(cass_fut);
+                let _ = Box::from_raw(counter_ptr);
+            }
+
+            // Cancel the future after completion.
+            {
+                let fut = async { Err((CassError::CASS_OK, ERROR_MSG.into())) };
+                let cass_fut = CassFuture::make_raw(fut);
+                cass_future_wait(cass_fut);
+
+                assert_eq!(0, cass_future_cancel(cass_fut));
+                assert_cass_future_error_message_eq!(cass_fut, Some(ERROR_MSG));
+
+                cass_future_free(cass_fut);
+            }
+        }
+    }
+
+    // This test checks that cancelling the future, whose request has completed
+    // but which is not set yet, sets it with cancellation error.
+    #[test]
+    #[ntest::timeout(200)]
+    fn cass_future_cancel_completed_request_test() {
+        let (completed_sender, completed_receiver) = std::sync::mpsc::channel();
+        let (release_sender, release_receiver) = oneshot::channel::<()>();
+        let fut = async move {
+            completed_sender.send(()).unwrap();
+            Ok(CassResultValue::Empty)
+        };
+        // The task awaits the callback executor after the request completes,
+        // so it doesn't set the future until released.
+        let callback_executor = async move {
+            let _ = release_receiver.await;
+            CallbackExecutor::Inline
+        };
+
+        unsafe {
+            let cass_fut = CassFuture::make_raw_with_callback_executor(fut, callback_executor);
+            completed_receiver.recv().unwrap();
+
+            assert_ne!(0, cass_future_cancel(cass_fut));
+            // Cancelling again has no effect.
+            assert_eq!(0, cass_future_cancel(cass_fut));
+
+            release_sender.send(()).unwrap();
+            cass_future_wait(cass_fut);
+            assert_cass_error_eq!(
+                cass_future_error_code(cass_fut),
+                CassError::CASS_ERROR_LIB_REQUEST_CANCELLED
+            );
+
+            cass_future_free(cass_fut);
+        }
+    }
+
+    #[test]
+    #[ntest::timeout(500)]
+    fn cass_future_wait_many_test() {
+        const ERROR_MSG: &str = "NOBODY EXPECTED SPANISH INQUISITION";
+        let sleeping_future = |millis: u64| {
+            CassFuture::make_raw(async move {
+                tokio::time::sleep(Duration::from_millis(millis)).await;
+                Err((CassError::CASS_OK, ERROR_MSG.into()))
+            }) as *const CassFuture
+        };
+
+        unsafe {
+            let futures = [
+                sleeping_future(200),
+                sleeping_future(20),
+                sleeping_future(100),
+            ];
+            let mut index: size_t = 42;
+
+            // None of the futures is set yet.
+            assert_eq!(
+                0,
+                cass_future_wait_any(futures.as_ptr(), 3, 5 * 1000, addr_of_mut!(index))
+            );
+            assert_eq!(0, cass_future_wait_all(futures.as_ptr(), 3, 5 * 1000));
+
+            let all_fut = cass_future_all(futures.as_ptr(), 3);
+            assert_eq!(0, cass_future_ready(all_fut));
+
+            assert_ne!(
+                0,
+                cass_future_wait_any(futures.as_ptr(), 3, 1000 * 1000, addr_of_mut!(index))
+            );
+            assert_eq!(1, index);
+            assert_ne!(0, cass_future_ready(futures[1]));
+
+            assert_ne!(0, cass_future_wait_all(futures.as_ptr(), 3, 1000 * 1000));
+            for future in futures {
+                assert_ne!(0, cass_future_ready(future));
+            }
+
+            // The combined future completes once all the futures are set.
+            assert_ne!(0, cass_future_wait_timed(all_fut, 100 * 1000));
+            assert_cass_error_eq!(cass_future_error_code(all_fut), CassError::CASS_OK);
+
+            // Waiting on no futures.
+            assert_eq!(
+                0,
+                cass_future_wait_any(std::ptr::null(), 0, 1000, addr_of_mut!(index))
+            );
+            assert_ne!(0, cass_future_wait_all(std::ptr::null(), 0, 1000));
+
+            cass_future_free(all_fut);
+            for future in futures {
+                cass_future_free(future);
+            }
+        }
+    }
+
+    // This test checks that the combined future completes, even if the last
+    // pending future is set while the combined future is registering in it.
+    #[test]
+    #[ntest::timeout(500)]
+    fn cass_future_all_set_while_registering_test() {
+        let (release_sender, release_receiver) = oneshot::channel::<()>();
+        let released_future = CassFuture::new_from_future(async move {
+            let _ = release_receiver.await;
+            Ok(CassResultValue::Empty)
+        });
+        let locked_future = CassFuture::new_from_future(future::pending());
+
+        unsafe {
+            // Checking whether `locked_future` is set blocks the combined future,
+            // after it finds `released_future` pending.
+            let mut locked_state = locked_future.state.lock().unwrap();
+            let futures = [Arc::as_ptr(&released_future), Arc::as_ptr(&locked_future)];
+            let all_fut = cass_future_all(futures.as_ptr(), futures.len() as size_t);
+            thread::sleep(Duration::from_millis(50));
+
+            // Both futures are set before the combined future registers in them,
+            // so none of them is going to notify it.
+            release_sender.send(()).unwrap();
+            cass_future_wait(futures[0]);
+            locked_state.value = Some(Ok(CassResultValue::Empty));
+            mem::drop(locked_state);
+
+            assert_ne!(0, cass_future_wait_timed(all_fut, 200 * 1000));
+            assert_cass_error_eq!(cass_future_error_code(all_fut), CassError::CASS_OK);
+
+            cass_future_free(all_fut);
+        }
+    }
+
+    // Waiting repeatedly with the same notifier, e.g. in `cass_future_wait_all`,
+    // must not pile up registrations in the pending futures.
+    #[test]
+    fn cass_future_notifier_registered_once_test() {
+        let pending_future = CassFuture::new_from_future(future::pending());
+        let notifier = Arc::new(FutureSetNotifier::default());
+
+        for _ in 0..3 {
+            assert_eq!(None, notifier.register(&[pending_future.as_ref()]));
+        }
+        assert_eq!(1, pending_future.state.lock().unwrap().set_notifiers.len());
+
+        // A timed out wait keeps the registration.
+        assert_eq!(
+            None,
+            CassFuture::wait_any_notified(
+                &notifier,
+                &[pending_future.as_ref()],
+                Some(std::time::Instant::now())
+            )
+        );
+        assert_eq!(1, pending_future.state.lock().unwrap().set_notifiers.len());
+    }
 }
@@ -0,0 +1,609 @@
+//! Rendering of values, rows and results as JSON.
+//!
+//! The output follows the format of CQL's `toJson()` function (and `SELECT JSON`):
+//! - numbers (including varints and decimals) are rendered as JSON numbers,
+//!   NaN and infinities as `null`,
+//! - UUIDs, inet addresses, dates, times and durations are rendered as strings,
+//! - blobs are rendered as `"0x"`-prefixed hex strings,
+//! - timestamps are rendered as `"yyyy-mm-dd hh:mm:ss.fffZ"` strings (in UTC),
+//! - lists, sets and tuples are rendered as arrays,
+//! - maps and UDTs are rendered as objects. Non-string map keys are quoted.
+
+use crate::argconv::*;
+use crate::cass_error::CassError;
+use crate::date_time::{civil_from_cass_date, civil_from_days};
+use crate::numeric::{decimal_to_string, varint_to_string};
+use crate::query_result::{
+    CassResult, CassResultKind, CassRow, CassRowsResult, CassValue, Collection, Value,
+};
+use crate::types::*;
+use scylla::frame::response::result::CqlValue;
+use scylla::frame::value::CqlDuration;
+use std::ffi::CString;
+use std::fmt::Write;
+use std::os::raw::c_char;
+use uuid::Uuid;
+
+const NANOS_PER_MICRO: i64 = 1_000;
+const NANOS_PER_MILLI: i64 = 1_000 * NANOS_PER_MICRO;
+const NANOS_PER_SECOND: i64 = 1_000 * NANOS_PER_MILLI;
+const NANOS_PER_MINUTE: i64 = 60 * NANOS_PER_SECOND;
+const NANOS_PER_HOUR: i64 = 60 * NANOS_PER_MINUTE;
+const MILLIS_PER_DAY: i64 = 24 * 60 * 60 * 1_000;
+const MONTHS_PER_YEAR: i64 = 12;
+
+fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0c}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Formats a duration the same way as Cassandra's `Duration::toString()` does, e.g. `1y2mo3d4h5m`.
+pub(crate) fn duration_to_string(duration: &CqlDuration) -> String {
+    // Appends `dividend / divisor` followed by the `unit` and returns the remainder.
+    fn append(out: &mut String, dividend: i64, divisor: i64, unit: &str) -> i64 {
+        if dividend == 0 || dividend < divisor {
+            return dividend;
+        }
+        write!(out, "{}{}", dividend / divisor, unit).unwrap();
+        dividend % divisor
+    }
+
+    let mut result = String::new();
+    if duration.months < 0 || duration.days < 0 || duration.nanoseconds < 0 {
+        result.push('-');
+    }
+
+    let months = (duration.months as i64).abs();
+    let remainder = append(&mut result, months, MONTHS_PER_YEAR, "y");
+    append(&mut result, remainder, 1, "mo");
+
+    append(&mut result, (duration.days as i64).abs(), 1, "d");
+
+    if duration.nanoseconds != 0 {
+        let nanos = duration.nanoseconds.unsigned_abs() as i64;
+        let remainder = append(&mut result, nanos, NANOS_PER_HOUR, "h");
+        let remainder = append(&mut result, remainder, NANOS_PER_MINUTE, "m");
+        let remainder = append(&mut result, remainder, NANOS_PER_SECOND, "s");
+        let remainder = append(&mut result, remainder, NANOS_PER_MILLI, "ms");
+        let remainder = append(&mut result, remainder, NANOS_PER_MICRO, "us");
+        append(&mut result, remainder, 1, "ns");
+    }
+
+    result
+}
+
+fn write_float_json(out: &mut String, value: f64, as_string: impl FnOnce() -> String) {
+    // JSON does not support NaN and infinities.
+    if value.is_finite() {
+        out.push_str(&as_string());
+    } else {
+        out.push_str("null");
+    }
+}
+
+fn write_timestamp_json(out: &mut String, millis: i64) {
+    let (year, month, day) = civil_from_days(millis.div_euclid(MILLIS_PER_DAY));
+    let millis_of_day = millis.rem_euclid(MILLIS_PER_DAY);
+    write!(
+        out,
+        "\"{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:03}Z\"",
+        year,
+        month,
+        day,
+        millis_of_day / 3_600_000,
+        millis_of_day / 60_000 % 60,
+        millis_of_day / 1_000 % 60,
+        millis_of_day % 1_000
+    )
+    .unwrap();
+}
+
+fn write_time_json(out: &mut String, nanos: i64) {
+    write!(
+        out,
+        "\"{:02}:{:02}:{:02}.{:09}\"",
+        nanos / NANOS_PER_HOUR,
+        nanos / NANOS_PER_MINUTE % 60,
+        nanos / NANOS_PER_SECOND % 60,
+        nanos % NANOS_PER_SECOND
+    )
+    .unwrap();
+}
+
+/// Writes a map key. JSON object keys must be strings, so keys which
+/// are not rendered as JSON strings are quoted.
+fn write_json_key(out: &mut String, key: String) {
+    if key.starts_with('"') {
+        out.push_str(&key);
+    } else {
+        write_json_string(out, &key);
+    }
+}
+
+fn write_json_array<T>(out: &mut String, items: &[T], mut write_item: impl FnMut(&mut String, &T)) {
+    out.push('[');
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        write_item(out, item);
+    }
+    out.push(']');
+}
+
+fn write_json_object<T>(
+    out: &mut String,
+    entries: &[T],
+    mut write_entry: impl FnMut(&mut String, &T),
+) {
+    out.push('{');
+    for (i, entry) in entries.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        write_entry(out, entry);
+    }
+    out.push('}');
+}
+
+fn write_cql_value_json(out: &mut String, value: &CqlValue) {
+    match value {
+        CqlValue::Ascii(s) | CqlValue::Text(s) => write_json_string(out, s),
+        CqlValue::Boolean(b) => write!(out, "{}", b).unwrap(),
+        CqlValue::Blob(bytes) => {
+            out.push_str("\"0x");
+            bytes.iter().for_each(|b| write!(out, "{:02x}", b).unwrap());
+            out.push('"');
+        }
+        CqlValue::Counter(c) => write!(out, "{}", c.0).unwrap(),
+        CqlValue::Decimal(d) => out.push_str(&decimal_to_string(d)),
+        CqlValue::Date(d) => {
+            let (year, month, day) = civil_from_cass_date(d.0);
+            write!(out, "\"{:04}-{:02}-{:02}\"", year, month, day).unwrap();
+        }
+        CqlValue::Double(d) => write_float_json(out, *d, || format!("{:?}", d)),
+        CqlValue::Duration(d) => write_json_string(out, &duration_to_string(d)),
+        CqlValue::Empty => out.push_str("null"),
+        CqlValue::Float(f) => write_float_json(out, *f as f64, || format!("{:?}", f)),
+        CqlValue::Int(i) => write!(out, "{}", i).unwrap(),
+        CqlValue::BigInt(i) => write!(out, "{}", i).unwrap(),
+        CqlValue::Timestamp(t) => write_timestamp_json(out, t.0),
+        CqlValue::Inet(inet) => write!(out, "\"{}\"", inet).unwrap(),
+        CqlValue::List(list) | CqlValue::Set(list) => {
+            write_json_array(out, list, |out, v| write_cql_value_json(out, v))
+        }
+        CqlValue::Map(map) => write_json_object(out, map, |out, (k, v)| {
+            let mut key = String::new();
+            write_cql_value_json(&mut key, k);
+            write_json_key(out, key);
+            out.push_str(": ");
+            write_cql_value_json(out, v);
+        }),
+        CqlValue::UserDefinedType { fields, .. } => {
+            write_json_object(out, fields, |out, (name, v)| {
+                write_json_string(out, name);
+                out.push_str(": ");
+                match v {
+                    Some(v) => write_cql_value_json(out, v),
+                    None => out.push_str("null"),
+                }
+            })
+        }
+        CqlValue::SmallInt(i) => write!(out, "{}", i).unwrap(),
+        CqlValue::TinyInt(i) => write!(out, "{}", i).unwrap(),
+        CqlValue::Time(t) => write_time_json(out, t.0),
+        CqlValue::Timeuuid(uuid) => write!(out, "\"{}\"", Into::<Uuid>::into(*uuid)).unwrap(),
+        CqlValue::Tuple(tuple) => write_json_array(out, tuple, |out, v| match v {
+            Some(v) => write_cql_value_json(out, v),
+            None => out.push_str("null"),
+        }),
+        CqlValue::Uuid(uuid) => write!(out, "\"{}\"", uuid).unwrap(),
+        CqlValue::Varint(v) => out.push_str(&varint_to_string(v.as_signed_bytes_be_slice())),
+    }
+}
+
+fn write_cass_value_json(out: &mut String, value: &CassValue) {
+    match &value.value {
+        None => out.push_str("null"),
+        Some(Value::RegularValue(v)) => write_cql_value_json(out, v),
+        Some(Value::CollectionValue(Collection::List(list) | Collection::Set(list))) => {
+            write_json_array(out, list, write_cass_value_json)
+        }
+        Some(Value::CollectionValue(Collection::Map(map))) => {
+            write_json_object(out, map, |out, (k, v)| {
+                let mut key = String::new();
+                write_cass_value_json(&mut key, k);
+                write_json_key(out, key);
+                out.push_str(": ");
+                write_cass_value_json(out, v);
+            })
+        }
+        Some(Value::CollectionValue(Collection::UserDefinedType { fields, .. })) => {
+            write_json_object(out, fields, |out, (name, v)| {
+                write_json_string(out, name);
+                out.push_str(": ");
+                match v {
+                    Some(v) => write_cass_value_json(out, v),
+                    None => out.push_str("null"),
+                }
+            })
+        }
+        Some(Value::CollectionValue(Collection::Tuple(tuple))) => {
+            write_json_array(out, tuple, |out, v| match v {
+                Some(v) => write_cass_value_json(out, v),
+                None => out.push_str("null"),
+            })
+        }
+    }
+}
+
+fn write_cass_row_json(out: &mut String, row: &CassRow) {
+    let columns: Vec<(&str, &CassValue)> = row
+        .result_metadata
+        .col_specs
+        .iter()
+        .map(|col_spec| col_spec.name.as_str())
+        .zip(row.columns.iter())
+        .collect();
+
+    write_json_object(out, &columns, |out, (name, v)| {
+        write_json_string(out, name);
+        out.push_str(": ");
+        write_cass_value_json(out, v);
+    });
+}
+
+fn write_cass_result_json(out: &mut String, result: &CassResult) {
+    match &result.kind {
+        CassResultKind::Rows(CassRowsResult { rows, .. }) => {
+            write_json_array(out, rows, write_cass_row_json)
+        }
+        CassResultKind::NonRows => out.push_str("[]"),
+    }
+}
+
+pub(crate) fn cass_value_json(value: &CassValue) -> String {
+    let mut out = String::new();
+    write_cass_value_json(&mut out, value);
+    out
+}
+
+pub(crate) fn cass_row_json(row: &CassRow) -> String {
+    let mut out = String::new();
+    write_cass_row_json(&mut out, row);
+    out
+}
+
+pub(crate) fn cass_result_json(result: &CassResult) -> String {
+    let mut out = String::new();
+    write_cass_result_json(&mut out, result);
+    out
+}
+
+/// Passes the ownership of `json` to the caller. It must be freed with `cass_json_free`.
+unsafe fn write_json_to_owned_string(
+    json: String,
+    output: *mut *mut c_char,
+    json_length: *mut size_t,
+) -> CassError {
+    if output.is_null() {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+
+    if !json_length.is_null() {
+        std::ptr::write(json_length, json.len() as size_t);
+    }
+
+    // Control characters (including NUL) are always escaped in the output,
+    // so the rendered JSON never contains interior NUL bytes.
+    let json = CString::new(json).unwrap();
+    std::ptr::write(output, json.into_raw());
+
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_value_to_json(
+    value: *const CassValue,
+    output: *mut c_char,
+    output_size: size_t,
+    json_length: *mut size_t,
+) -> CassError {
+    let value_from_raw = RefFFI::as_ref(value);
+
+    write_str_to_buffer(
+        &cass_value_json(value_from_raw),
+        output,
+        output_size,
+        json_length,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_value_to_json_alloc(
+    value: *const CassValue,
+    output: *mut *mut c_char,
+    json_length: *mut size_t,
+) -> CassError {
+    let value_from_raw = RefFFI::as_ref(value);
+
+    write_json_to_owned_string(cass_value_json(value_from_raw), output, json_length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_row_to_json(
+    row: *const CassRow,
+    output: *mut c_char,
+    output_size: size_t,
+    json_length: *mut size_t,
+) -> CassError {
+    let row_from_raw = RefFFI::as_ref(row);
+
+    write_str_to_buffer(
+        &cass_row_json(row_from_raw),
+        output,
+        output_size,
+        json_length,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_row_to_json_alloc(
+    row: *const CassRow,
+    output: *mut *mut c_char,
+    json_length: *mut size_t,
+) -> CassError {
+    let row_from_raw = RefFFI::as_ref(row);
+
+    write_json_to_owned_string(cass_row_json(row_from_raw), output, json_length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_result_to_json(
+    result: *const CassResult,
+    output: *mut c_char,
+    output_size: size_t,
+    json_length: *mut size_t,
+) -> CassError {
+    let result_from_raw = ArcFFI::as_ref(result);
+
+    write_str_to_buffer(
+        &cass_result_json(result_from_raw),
+        output,
+        output_size,
+        json_length,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_result_to_json_alloc(
+    result: *const CassResult,
+    output: *mut *mut c_char,
+    json_length: *mut size_t,
+) -> CassError {
+    let result_from_raw = ArcFFI::as_ref(result);
+
+    write_json_to_owned_string(cass_result_json(result_from_raw), output, json_length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_json_free(json: *mut c_char) {
+    if json.is_null() {
+        return;
+    }
+
+    drop(CString::from_raw(json));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+    use std::os::raw::c_char;
+    use std::ptr::addr_of_mut;
+    use std::sync::Arc;
+
+    use scylla::frame::response::result::{ColumnType, CqlValue};
+    use scylla::frame::value::{Counter, CqlDate, CqlDuration, CqlTime, CqlTimestamp, CqlVarint};
+
+    use crate::argconv::{ptr_to_cstr, ptr_to_cstr_n};
+    use crate::cass_error::CassError;
+    use crate::query_result::CassResult;
+    use crate::testing::{assert_cass_error_eq, make_rows_result};
+    use crate::types::size_t;
+
+    use super::{
+        cass_json_free, cass_result_json, cass_result_to_json, cass_result_to_json_alloc,
+        duration_to_string, write_cql_value_json,
+    };
+
+    fn cql_value_json(value: &CqlValue) -> String {
+        let mut out = String::new();
+        write_cql_value_json(&mut out, value);
+        out
+    }
+
+    #[test]
+    fn duration_to_string_test() {
+        let cases = [
+            ((14, 3, 0), "1y2mo3d"),
+            ((0, 0, 3_723_000_000_000), "1h2m3s"),
+            ((0, 0, 1_001_001), "1ms1us1ns"),
+            ((-1, -2, -3), "-1mo2d3ns"),
+        ];
+
+        for ((months, days, nanoseconds), expected) in cases {
+            let duration = CqlDuration {
+                months,
+                days,
+                nanoseconds,
+            };
+            assert_eq!(expected, duration_to_string(&duration));
+        }
+    }
+
+    #[test]
+    fn cql_value_json_test() {
+        let cases = [
+            (CqlValue::Int(-42), "-42"),
+            (CqlValue::BigInt(1 << 40), "1099511627776"),
+            (CqlValue::Counter(Counter(5)), "5"),
+            (CqlValue::Boolean(true), "true"),
+            (CqlValue::Double(1.5), "1.5"),
+            (CqlValue::Float(2.0), "2.0"),
+            (CqlValue::Double(f64::NAN), "null"),
+            (CqlValue::Text("a\"b\\c\n".to_owned()), r#""a\"b\\c\n""#),
+            (CqlValue::Blob(vec![0xCA, 0xFE, 0x01]), r#""0xcafe01""#),
+            (
+                CqlValue::Varint(CqlVarint::from_signed_bytes_be_slice(&[0x00, 0x80])),
+                "128",
+            ),
+            (
+                CqlValue::Uuid(uuid::Uuid::from_u64_pair(
+                    0x123e4567e89b12d3,
+                    0xa456426614174000,
+                )),
+                r#""123e4567-e89b-12d3-a456-426614174000""#,
+            ),
+            (
+                CqlValue::Inet(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))),
+                r#""127.0.0.1""#,
+            ),
+            (
+                CqlValue::Timestamp(CqlTimestamp(1_700_000_000_123)),
+                r#""2023-11-14 22:13:20.123Z""#,
+            ),
+            (
+                CqlValue::Timestamp(CqlTimestamp(-1)),
+                r#""1969-12-31 23:59:59.999Z""#,
+            ),
+            (
+                CqlValue::Date(CqlDate((1 << 31) + 19675)),
+                r#""2023-11-14""#,
+            ),
+            (
+                CqlValue::Time(CqlTime(3_723_000_000_004)),
+                r#""01:02:03.000000004""#,
+            ),
+            (
+                CqlValue::List(vec![CqlValue::Int(1), CqlValue::Int(2)]),
+                "[1, 2]",
+            ),
+            (
+                CqlValue::Map(vec![
+                    (CqlValue::Int(1), CqlValue::Text("a".to_owned())),
+                    (CqlValue::Int(2), CqlValue::Text("b".to_owned())),
+                ]),
+                r#"{"1": "a", "2": "b"}"#,
+            ),
+            (
+                CqlValue::Tuple(vec![Some(CqlValue::Int(1)), None]),
+                "[1, null]",
+            ),
+            (
+                CqlValue::UserDefinedType {
+                    keyspace: "ks".to_owned(),
+                    type_name: "udt".to_owned(),
+                    fields: vec![
+                        ("x".to_owned(), Some(CqlValue::Int(1))),
+                        ("y".to_owned(), None),
+                    ],
+                },
+                r#"{"x": 1, "y": null}"#,
+            ),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(expected, cql_value_json(&value), "value: {:?}", value);
+        }
+    }
+
+    fn create_cass_rows_result() -> CassResult {
+        make_rows_result(
+            &[
+                ("id", ColumnType::Int),
+                (
+                    "tags",
+                    ColumnType::Map(Box::new(ColumnType::Text), Box::new(ColumnType::Int)),
+                ),
+            ],
+            vec![
+                vec![
+                    Some(CqlValue::Int(1)),
+                    Some(CqlValue::Map(vec![(
+                        CqlValue::Text("a".to_owned()),
+                        CqlValue::Int(10),
+                    )])),
+                ],
+                vec![Some(CqlValue::Int(2)), None],
+            ],
+        )
+    }
+
+    #[test]
+    fn cass_result_json_test() {
+        const EXPECTED: &str = r#"[{"id": 1, "tags": {"a": 10}}, {"id": 2, "tags": null}]"#;
+
+        let result = create_cass_rows_result();
+        assert_eq!(EXPECTED, cass_result_json(&result));
+
+        unsafe {
+            let result_ptr = std::ptr::addr_of!(result);
+
+            // Caller-supplied buffer.
+            {
+                let mut json_length: size_t = 0;
+                assert_cass_error_eq!(
+                    cass_result_to_json(
+                        result_ptr,
+                        std::ptr::null_mut(),
+                        0,
+                        addr_of_mut!(json_length)
+                    ),
+                    CassError::CASS_ERROR_LIB_BAD_PARAMS
+                );
+                assert_eq!(EXPECTED.len() as size_t, json_length);
+
+                let mut buffer = vec![0 as c_char; json_length as usize + 1];
+                assert_cass_error_eq!(
+                    cass_result_to_json(
+                        result_ptr,
+                        buffer.as_mut_ptr(),
+                        buffer.len() as size_t,
+                        addr_of_mut!(json_length)
+                    ),
+                    CassError::CASS_OK
+                );
+                assert_eq!(Some(EXPECTED), ptr_to_cstr(buffer.as_ptr()));
+            }
+
+            // Driver-owned string.
+            {
+                let mut json: *mut c_char = std::ptr::null_mut();
+                let mut json_length: size_t = 0;
+                assert_cass_error_eq!(
+                    cass_result_to_json_alloc(
+                        result_ptr,
+                        addr_of_mut!(json),
+                        addr_of_mut!(json_length)
+                    ),
+                    CassError::CASS_OK
+                );
+                assert_eq!(Some(EXPECTED), ptr_to_cstr_n(json, json_length));
+                cass_json_free(json);
+            }
+        }
+    }
+}
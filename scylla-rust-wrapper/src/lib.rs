@@ -10,23 +10,30 @@ use tokio::runtime::Runtime;
 mod binding;
 mod argconv;
 pub mod batch;
+pub mod callback_executor;
 pub mod cass_error;
 pub mod cass_types;
 pub mod cluster;
 pub mod collection;
+pub mod completion_queue;
 pub mod date_time;
+pub mod describe;
 pub mod exec_profile;
 mod external;
 pub mod future;
 pub mod inet;
 pub mod integration_testing;
+pub mod json;
 mod logging;
 pub mod metadata;
 pub mod misc;
+pub mod numeric;
 pub mod prepared;
 pub mod query_error;
 pub mod query_result;
+pub mod request_history;
 pub mod retry_policy;
+pub mod schema_change;
 pub mod session;
 pub mod ssl;
 pub mod statement;
@@ -54,7 +61,7 @@ pub mod types {
     include_bindgen_generated!("basic_types.rs");
 }
 
-/// CassError, CassErrorSource, CassWriteType
+/// CassError, CassErrorSource, CassWriteType, CassRetryDecision
 pub mod cass_error_types {
     include_bindgen_generated!("cppdriver_error_types.rs");
 }
@@ -98,11 +105,31 @@ pub mod cass_log_types {
     include_bindgen_generated!("cppdriver_log_types.rs");
 }
 
-/// CassColumnType
+/// CassColumnType, CassClusteringOrder
 pub mod cass_column_types {
     include_bindgen_generated!("cppdriver_column_type.rs");
 }
 
+/// CassIndexType
+pub mod cass_index_types {
+    include_bindgen_generated!("cppdriver_index_type.rs");
+}
+
+/// CassReplicationStrategy
+pub mod cass_replication_strategy_types {
+    include_bindgen_generated!("cppdriver_replication_strategy.rs");
+}
+
+/// CassSchemaChangeType, CassSchemaChangeTarget
+pub mod cass_schema_change_types {
+    include_bindgen_generated!("cppdriver_schema_change_types.rs");
+}
+
+/// CassDateTime, CassDuration
+pub mod cass_date_time_types {
+    include_bindgen_generated!("cppdriver_date_time_types.rs");
+}
+
 /// CassUuid
 pub mod cass_uuid_types {
     #![allow(non_camel_case_types, non_snake_case)]
@@ -110,6 +137,13 @@ pub mod cass_uuid_types {
     include_bindgen_generated!("cppdriver_uuid_types.rs");
 }
 
+/// CassVersion
+pub mod cass_version_types {
+    #![allow(non_camel_case_types, non_snake_case)]
+
+    include_bindgen_generated!("cppdriver_version_types.rs");
+}
+
 pub static RUNTIME: LazyLock<Runtime> = LazyLock::new(|| Runtime::new().unwrap());
 pub static LOGGER: LazyLock<RwLock<Logger>> = LazyLock::new(|| {
     RwLock::new(Logger {
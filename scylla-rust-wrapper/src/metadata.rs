@@ -1,19 +1,142 @@
 use crate::argconv::*;
-use crate::cass_column_types::CassColumnType;
-use crate::cass_types::get_column_type_from_cql_type;
-use crate::cass_types::CassDataType;
+use crate::cass_column_types::{CassClusteringOrder, CassColumnType};
+use crate::cass_error::CassError;
+use crate::cass_index_types::CassIndexType;
+use crate::cass_replication_strategy_types::CassReplicationStrategy;
+use crate::cass_types::{
+    get_column_type_from_cql_type, get_column_type_from_cql_type_string, CassDataType,
+    CassDataTypeInner, CassValueType, MapDataType,
+};
+use crate::cass_version_types::CassVersion;
+use crate::query_result::{get_column_value, CassRow, CassValue, Collection, Value};
 use crate::types::*;
-use scylla::transport::topology::{ColumnKind, Table, UserDefinedType};
-use std::collections::HashMap;
+use crate::uuid::CassUuid;
+use scylla::frame::response::result::CqlValue;
+use scylla::transport::topology::{ColumnKind, Strategy, Table, UserDefinedType};
+use std::collections::{BTreeMap, HashMap};
 use std::os::raw::c_char;
 use std::sync::Arc;
 use std::sync::Weak;
+use uuid::Uuid;
+
+/// Raw row of the `system_schema` table describing a schema object. It gives access
+/// to the columns that aren't modeled explicitly, e.g. replication or compaction.
+#[derive(Default)]
+pub struct CassMetaFields {
+    row: Option<CassRow>,
+}
+
+impl CassMetaFields {
+    pub fn new(row: CassRow) -> Self {
+        CassMetaFields { row: Some(row) }
+    }
+
+    pub fn len(&self) -> usize {
+        self.row.as_ref().map_or(0, |row| row.columns.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, name: &str) -> Option<&CassValue> {
+        let row = self.row.as_ref()?;
+        let index = row
+            .result_metadata
+            .col_specs
+            .iter()
+            .position(|col_spec| col_spec.name == name)?;
+
+        row.columns.get(index)
+    }
+
+    pub fn nth(&self, index: usize) -> Option<(&str, &CassValue)> {
+        let row = self.row.as_ref()?;
+        let col_spec = row.result_metadata.col_specs.get(index)?;
+
+        Some((col_spec.name.as_str(), row.columns.get(index)?))
+    }
+
+    /// Returns the value of a text column, if it's set.
+    pub fn text(&self, name: &str) -> Option<&str> {
+        match &self.get(name)?.value {
+            Some(Value::RegularValue(CqlValue::Text(text) | CqlValue::Ascii(text))) => {
+                Some(text.as_str())
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the value of an int column, if it's set.
+    pub fn int(&self, name: &str) -> Option<i32> {
+        match &self.get(name)?.value {
+            Some(Value::RegularValue(CqlValue::Int(int))) => Some(*int),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a boolean column, if it's set.
+    pub fn boolean(&self, name: &str) -> Option<bool> {
+        match &self.get(name)?.value {
+            Some(Value::RegularValue(CqlValue::Boolean(boolean))) => Some(*boolean),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of a list of texts. Null (e.g. empty) lists yield no elements.
+    pub fn text_list(&self, name: &str) -> Vec<&str> {
+        match self.get(name).and_then(|value| value.value.as_ref()) {
+            Some(Value::CollectionValue(Collection::List(list))) => list
+                .iter()
+                .filter_map(|element| match &element.value {
+                    Some(Value::RegularValue(CqlValue::Text(text) | CqlValue::Ascii(text))) => {
+                        Some(text.as_str())
+                    }
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns the entries of a map of texts, if it's set.
+    pub fn text_map(&self, name: &str) -> Option<BTreeMap<String, String>> {
+        let text = |value: &CassValue| match &value.value {
+            Some(Value::RegularValue(CqlValue::Text(text) | CqlValue::Ascii(text))) => {
+                Some(text.clone())
+            }
+            _ => None,
+        };
+
+        match self.get(name)?.value.as_ref()? {
+            Value::CollectionValue(Collection::Map(map)) => Some(
+                map.iter()
+                    .filter_map(|(key, value)| Some((text(key)?, text(value)?)))
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    fn owned_text_list(&self, name: &str) -> Vec<String> {
+        self.text_list(name)
+            .into_iter()
+            .map(str::to_owned)
+            .collect()
+    }
+}
 
 pub struct CassSchemaMeta {
     pub keyspaces: HashMap<String, CassKeyspaceMeta>,
+    // Incremented each time the snapshot is rebuilt after a metadata refresh
+    pub snapshot_version: u32,
+    // Release version of the node the metadata was fetched from
+    pub version: CassVersion,
+    pub schema_version: Option<Uuid>,
 }
 
-impl BoxFFI for CassSchemaMeta {}
+// Shared by the session's schema cache and the users holding the snapshot
+impl ArcFFI for CassSchemaMeta {}
 
 pub struct CassKeyspaceMeta {
     pub name: String,
@@ -22,17 +145,88 @@ pub struct CassKeyspaceMeta {
     pub user_defined_type_data_type: HashMap<String, Arc<CassDataType>>,
     pub tables: HashMap<String, Arc<CassTableMeta>>,
     pub views: HashMap<String, Arc<CassMaterializedViewMeta>>,
+
+    // Function/aggregate full name (e.g. "avg(int,int)") to metadata
+    pub functions: HashMap<String, Arc<CassFunctionMeta>>,
+    pub aggregates: HashMap<String, Arc<CassAggregateMeta>>,
+
+    pub replication: KeyspaceReplication,
+    pub durable_writes: bool,
+    // Initial tablet count (0 if chosen by the cluster), None if the keyspace uses vnodes
+    pub initial_tablets: Option<u32>,
+
+    pub fields: CassMetaFields,
+    pub is_virtual: bool,
 }
 
 // Owned by CassSchemaMeta
 impl RefFFI for CassKeyspaceMeta {}
 
+pub struct KeyspaceReplication {
+    pub strategy: CassReplicationStrategy,
+    pub class_name: String,
+    // Total number of replicas, 0 if unknown
+    pub replication_factor: u32,
+    // Only set for NetworkTopologyStrategy
+    pub datacenter_replication_factors: BTreeMap<String, u32>,
+}
+
+impl KeyspaceReplication {
+    pub fn new(strategy: &Strategy) -> Self {
+        let class_name = |name: &str| format!("org.apache.cassandra.locator.{}", name);
+
+        match strategy {
+            Strategy::SimpleStrategy { replication_factor } => KeyspaceReplication {
+                strategy: CassReplicationStrategy::CASS_REPLICATION_STRATEGY_SIMPLE,
+                class_name: class_name("SimpleStrategy"),
+                replication_factor: *replication_factor as u32,
+                datacenter_replication_factors: BTreeMap::new(),
+            },
+            Strategy::NetworkTopologyStrategy {
+                datacenter_repfactors,
+            } => {
+                let datacenter_replication_factors: BTreeMap<String, u32> = datacenter_repfactors
+                    .iter()
+                    .map(|(datacenter, replication_factor)| {
+                        (datacenter.clone(), *replication_factor as u32)
+                    })
+                    .collect();
+                KeyspaceReplication {
+                    strategy: CassReplicationStrategy::CASS_REPLICATION_STRATEGY_NETWORK_TOPOLOGY,
+                    class_name: class_name("NetworkTopologyStrategy"),
+                    replication_factor: datacenter_replication_factors.values().sum(),
+                    datacenter_replication_factors,
+                }
+            }
+            Strategy::LocalStrategy => KeyspaceReplication {
+                strategy: CassReplicationStrategy::CASS_REPLICATION_STRATEGY_LOCAL,
+                class_name: class_name("LocalStrategy"),
+                replication_factor: 1,
+                datacenter_replication_factors: BTreeMap::new(),
+            },
+            Strategy::Other { name, data } => KeyspaceReplication {
+                strategy: CassReplicationStrategy::CASS_REPLICATION_STRATEGY_OTHER,
+                class_name: name.clone(),
+                replication_factor: data
+                    .get("replication_factor")
+                    .and_then(|replication_factor| replication_factor.parse().ok())
+                    .unwrap_or(0),
+                datacenter_replication_factors: BTreeMap::new(),
+            },
+        }
+    }
+}
+
 pub struct CassTableMeta {
     pub name: String,
+    pub keyspace_name: String,
     pub columns_metadata: HashMap<String, CassColumnMeta>,
     pub partition_keys: Vec<String>,
     pub clustering_keys: Vec<String>,
     pub views: HashMap<String, Arc<CassMaterializedViewMeta>>,
+    pub indexes: HashMap<String, CassIndexMeta>,
+    pub fields: CassMetaFields,
+    pub is_virtual: bool,
 }
 
 // Either:
@@ -53,11 +247,266 @@ pub struct CassColumnMeta {
     pub name: String,
     pub column_type: CassDataType,
     pub column_kind: CassColumnType,
+    pub clustering_order: CassClusteringOrder,
+    pub fields: CassMetaFields,
 }
 
 // Owned by CassTableMeta
 impl RefFFI for CassColumnMeta {}
 
+pub struct CassIndexMeta {
+    pub name: String,
+    pub index_type: CassIndexType,
+    pub target: String,
+    pub options: CassValue,
+    pub fields: CassMetaFields,
+}
+
+// Owned by CassTableMeta
+impl RefFFI for CassIndexMeta {}
+
+pub struct CassFunctionMeta {
+    pub name: String,
+    pub full_name: String,
+    pub arguments: Vec<(String, Arc<CassDataType>)>,
+    pub return_type: Arc<CassDataType>,
+    pub body: String,
+    pub language: String,
+    pub called_on_null_input: bool,
+    pub fields: CassMetaFields,
+}
+
+// Owned by CassKeyspaceMeta (in Arc), shared with CassAggregateMeta
+impl RefFFI for CassFunctionMeta {}
+
+pub struct CassAggregateMeta {
+    pub name: String,
+    pub full_name: String,
+    pub argument_types: Vec<Arc<CassDataType>>,
+    pub return_type: Arc<CassDataType>,
+    pub state_type: Arc<CassDataType>,
+    pub state_func: Option<Arc<CassFunctionMeta>>,
+    pub final_func: Option<Arc<CassFunctionMeta>>,
+    pub init_cond: CassValue,
+    pub fields: CassMetaFields,
+}
+
+// Owned by CassKeyspaceMeta (in Arc)
+impl RefFFI for CassAggregateMeta {}
+
+pub const SELECT_LOCAL_QUERY: &str = "SELECT release_version, schema_version FROM system.local";
+
+/// Row of `system_schema.indexes`.
+pub struct IndexRow {
+    pub keyspace_name: String,
+    pub table_name: String,
+    pub index_name: String,
+    pub kind: Option<String>,
+    pub options: Option<BTreeMap<String, String>>,
+}
+
+impl IndexRow {
+    /// Returns `None` if the row lacks the name of the index.
+    pub fn from_fields(fields: &CassMetaFields) -> Option<Self> {
+        Some(IndexRow {
+            keyspace_name: fields.text("keyspace_name")?.to_owned(),
+            table_name: fields.text("table_name")?.to_owned(),
+            index_name: fields.text("index_name")?.to_owned(),
+            kind: fields.text("kind").map(str::to_owned),
+            options: fields.text_map("options"),
+        })
+    }
+}
+
+/// Row of `system_schema.functions`.
+#[derive(PartialEq)]
+pub struct FunctionRow {
+    pub keyspace_name: String,
+    pub function_name: String,
+    pub argument_names: Vec<String>,
+    pub argument_types: Vec<String>,
+    pub body: Option<String>,
+    pub called_on_null_input: Option<bool>,
+    pub language: Option<String>,
+    pub return_type: Option<String>,
+}
+
+impl FunctionRow {
+    /// Returns `None` if the row lacks the name of the function.
+    pub fn from_fields(fields: &CassMetaFields) -> Option<Self> {
+        Some(FunctionRow {
+            keyspace_name: fields.text("keyspace_name")?.to_owned(),
+            function_name: fields.text("function_name")?.to_owned(),
+            argument_names: fields.owned_text_list("argument_names"),
+            argument_types: fields.owned_text_list("argument_types"),
+            body: fields.text("body").map(str::to_owned),
+            called_on_null_input: fields.boolean("called_on_null_input"),
+            language: fields.text("language").map(str::to_owned),
+            return_type: fields.text("return_type").map(str::to_owned),
+        })
+    }
+}
+
+/// Row of `system_schema.aggregates`.
+#[derive(PartialEq)]
+pub struct AggregateRow {
+    pub keyspace_name: String,
+    pub aggregate_name: String,
+    pub argument_types: Vec<String>,
+    pub final_func: Option<String>,
+    pub initcond: Option<String>,
+    pub return_type: Option<String>,
+    pub state_func: Option<String>,
+    pub state_type: Option<String>,
+}
+
+impl AggregateRow {
+    /// Returns `None` if the row lacks the name of the aggregate.
+    pub fn from_fields(fields: &CassMetaFields) -> Option<Self> {
+        Some(AggregateRow {
+            keyspace_name: fields.text("keyspace_name")?.to_owned(),
+            aggregate_name: fields.text("aggregate_name")?.to_owned(),
+            argument_types: fields.owned_text_list("argument_types"),
+            final_func: fields.text("final_func").map(str::to_owned),
+            initcond: fields.text("initcond").map(str::to_owned),
+            return_type: fields.text("return_type").map(str::to_owned),
+            state_func: fields.text("state_func").map(str::to_owned),
+            state_type: fields.text("state_type").map(str::to_owned),
+        })
+    }
+}
+
+/// Parses the leading "major.minor.patch" part of a release version, e.g. "3.0.8"
+/// or "4.1-SNAPSHOT". Missing or malformed components are zeroed.
+pub fn parse_release_version(release_version: &str) -> CassVersion {
+    let numeric_len = release_version
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(release_version.len());
+    let mut components = release_version[..numeric_len]
+        .split('.')
+        .map(|component| component.parse().unwrap_or(0));
+
+    CassVersion {
+        major_version: components.next().unwrap_or(0),
+        minor_version: components.next().unwrap_or(0),
+        patch_version: components.next().unwrap_or(0),
+    }
+}
+
+/// Builds the full name of a function or an aggregate, e.g. "avg(int,frozen<list<int>>)".
+/// Whitespace is removed from the argument types, so that the name doesn't depend on
+/// how the types were spelled.
+pub fn full_function_name<'a>(
+    name: &str,
+    argument_types: impl IntoIterator<Item = &'a str>,
+) -> String {
+    let argument_types: Vec<String> = argument_types
+        .into_iter()
+        .map(|typ| typ.chars().filter(|c| !c.is_whitespace()).collect())
+        .collect();
+
+    format!("{}({})", name, argument_types.join(","))
+}
+
+pub fn create_function_metadata(
+    row: FunctionRow,
+    fields: CassMetaFields,
+    user_defined_types: &HashMap<String, Arc<UserDefinedType>>,
+) -> CassFunctionMeta {
+    let keyspace_name = row.keyspace_name.as_str();
+    let argument_types = row.argument_types;
+    let arguments = row
+        .argument_names
+        .into_iter()
+        .zip(argument_types.iter())
+        .map(|(name, typ)| {
+            let data_type =
+                get_column_type_from_cql_type_string(typ, user_defined_types, keyspace_name);
+            (name, Arc::new(data_type))
+        })
+        .collect();
+    let return_type = get_column_type_from_cql_type_string(
+        row.return_type.as_deref().unwrap_or_default(),
+        user_defined_types,
+        keyspace_name,
+    );
+
+    CassFunctionMeta {
+        full_name: full_function_name(
+            &row.function_name,
+            argument_types.iter().map(String::as_str),
+        ),
+        name: row.function_name,
+        arguments,
+        return_type: Arc::new(return_type),
+        body: row.body.unwrap_or_default(),
+        language: row.language.unwrap_or_default(),
+        called_on_null_input: row.called_on_null_input.unwrap_or(false),
+        fields,
+    }
+}
+
+/// Creates aggregate metadata. State and final functions are looked up in `functions`,
+/// which should contain the functions of the aggregate's keyspace.
+pub fn create_aggregate_metadata(
+    row: AggregateRow,
+    fields: CassMetaFields,
+    functions: &HashMap<String, Arc<CassFunctionMeta>>,
+    user_defined_types: &HashMap<String, Arc<UserDefinedType>>,
+) -> CassAggregateMeta {
+    let keyspace_name = row.keyspace_name.as_str();
+    let data_type = |typ: &str| {
+        Arc::new(get_column_type_from_cql_type_string(
+            typ,
+            user_defined_types,
+            keyspace_name,
+        ))
+    };
+
+    let argument_types = row.argument_types;
+    let state_type = row.state_type.unwrap_or_default();
+
+    // State function takes the state followed by the aggregate's arguments,
+    // final function takes only the state.
+    let state_func = row.state_func.and_then(|state_func| {
+        let state_func_arguments =
+            std::iter::once(state_type.as_str()).chain(argument_types.iter().map(String::as_str));
+        functions
+            .get(&full_function_name(&state_func, state_func_arguments))
+            .cloned()
+    });
+    let final_func = row.final_func.and_then(|final_func| {
+        functions
+            .get(&full_function_name(&final_func, [state_type.as_str()]))
+            .cloned()
+    });
+
+    // Since Cassandra 3.0 the initial condition is stored as its CQL literal.
+    let init_cond = CassValue {
+        value: row
+            .initcond
+            .map(|init_cond| Value::RegularValue(CqlValue::Text(init_cond))),
+        value_type: CassDataType::new_arced(CassDataTypeInner::Value(
+            CassValueType::CASS_VALUE_TYPE_VARCHAR,
+        )),
+    };
+
+    CassAggregateMeta {
+        full_name: full_function_name(
+            &row.aggregate_name,
+            argument_types.iter().map(String::as_str),
+        ),
+        name: row.aggregate_name,
+        argument_types: argument_types.iter().map(|typ| data_type(typ)).collect(),
+        return_type: data_type(row.return_type.as_deref().unwrap_or_default()),
+        state_type: data_type(&state_type),
+        state_func,
+        final_func,
+        init_cond,
+        fields,
+    }
+}
+
 pub unsafe fn create_table_metadata(
     keyspace_name: &str,
     table_name: &str,
@@ -82,6 +531,10 @@ pub unsafe fn create_table_metadata(
                     ColumnKind::Clustering => CassColumnType::CASS_COLUMN_TYPE_CLUSTERING_KEY,
                     ColumnKind::PartitionKey => CassColumnType::CASS_COLUMN_TYPE_PARTITION_KEY,
                 },
+                // Clustering order isn't a part of the driver's metadata,
+                // it's filled in from the raw column fields.
+                clustering_order: CassClusteringOrder::CASS_CLUSTERING_ORDER_NONE,
+                fields: CassMetaFields::default(),
             };
 
             columns_metadata.insert(column_name.clone(), cass_column_meta);
@@ -89,16 +542,195 @@ pub unsafe fn create_table_metadata(
 
     CassTableMeta {
         name: table_name.to_owned(),
+        keyspace_name: keyspace_name.to_owned(),
         columns_metadata,
         partition_keys: table_metadata.partition_key.clone(),
         clustering_keys: table_metadata.clustering_key.clone(),
         views: HashMap::new(),
+        indexes: HashMap::new(),
+        fields: CassMetaFields::default(),
+        is_virtual: false,
+    }
+}
+
+/// Sets the raw fields of the table's columns, along with the clustering order
+/// that is only available there. `column_fields` returns the fields of a column
+/// given the table and column name.
+pub fn fill_column_fields(
+    table_meta: &mut CassTableMeta,
+    mut column_fields: impl FnMut(&str, &str) -> CassMetaFields,
+) {
+    for (column_name, column_meta) in table_meta.columns_metadata.iter_mut() {
+        column_meta.fields = column_fields(&table_meta.name, column_name);
+        column_meta.clustering_order = clustering_order_from_fields(&column_meta.fields);
+    }
+}
+
+fn clustering_key_order(table_meta: &CassTableMeta, index: size_t) -> CassClusteringOrder {
+    table_meta
+        .clustering_keys
+        .get(index as usize)
+        .and_then(|column_name| table_meta.columns_metadata.get(column_name))
+        .map_or(
+            CassClusteringOrder::CASS_CLUSTERING_ORDER_NONE,
+            |column_meta| column_meta.clustering_order,
+        )
+}
+
+fn clustering_order_from_fields(fields: &CassMetaFields) -> CassClusteringOrder {
+    match fields.text("clustering_order") {
+        Some(order) if order.eq_ignore_ascii_case("asc") => {
+            CassClusteringOrder::CASS_CLUSTERING_ORDER_ASC
+        }
+        Some(order) if order.eq_ignore_ascii_case("desc") => {
+            CassClusteringOrder::CASS_CLUSTERING_ORDER_DESC
+        }
+        _ => CassClusteringOrder::CASS_CLUSTERING_ORDER_NONE,
+    }
+}
+
+/// Creates metadata of a virtual table. Virtual tables aren't a part of the driver's
+/// metadata, so the table is built from the rows of `system_virtual_schema.columns`.
+pub fn create_virtual_table_metadata(
+    keyspace_name: &str,
+    table_name: &str,
+    fields: CassMetaFields,
+    column_fields: Vec<CassMetaFields>,
+) -> CassTableMeta {
+    let mut columns_metadata = HashMap::new();
+    let mut partition_keys = Vec::new();
+    let mut clustering_keys = Vec::new();
+
+    for fields in column_fields {
+        let Some(column_name) = fields.text("column_name").map(str::to_owned) else {
+            continue;
+        };
+        let column_type = get_column_type_from_cql_type_string(
+            fields.text("type").unwrap_or_default(),
+            &HashMap::new(),
+            keyspace_name,
+        );
+        let position = fields.int("position").unwrap_or(0);
+        let column_kind = match fields.text("kind") {
+            Some("partition_key") => {
+                partition_keys.push((position, column_name.clone()));
+                CassColumnType::CASS_COLUMN_TYPE_PARTITION_KEY
+            }
+            Some("clustering") => {
+                clustering_keys.push((position, column_name.clone()));
+                CassColumnType::CASS_COLUMN_TYPE_CLUSTERING_KEY
+            }
+            Some("static") => CassColumnType::CASS_COLUMN_TYPE_STATIC,
+            _ => CassColumnType::CASS_COLUMN_TYPE_REGULAR,
+        };
+
+        let cass_column_meta = CassColumnMeta {
+            name: column_name.clone(),
+            column_type,
+            column_kind,
+            clustering_order: clustering_order_from_fields(&fields),
+            fields,
+        };
+        columns_metadata.insert(column_name, cass_column_meta);
+    }
+
+    partition_keys.sort();
+    clustering_keys.sort();
+
+    CassTableMeta {
+        name: table_name.to_owned(),
+        keyspace_name: keyspace_name.to_owned(),
+        columns_metadata,
+        partition_keys: partition_keys.into_iter().map(|(_, name)| name).collect(),
+        clustering_keys: clustering_keys.into_iter().map(|(_, name)| name).collect(),
+        views: HashMap::new(),
+        indexes: HashMap::new(),
+        fields,
+        is_virtual: true,
+    }
+}
+
+/// Creates index metadata. The target is taken from the index options as is, so for
+/// Scylla's local secondary indexes it's the JSON description of the index's partition
+/// key and indexed column, e.g. `{"pk":["p"],"ck":["v"]}`.
+pub fn create_index_metadata(row: IndexRow, fields: CassMetaFields) -> CassIndexMeta {
+    let index_type = match row.kind.as_deref() {
+        Some("KEYS") => CassIndexType::CASS_INDEX_TYPE_KEYS,
+        Some("CUSTOM") => CassIndexType::CASS_INDEX_TYPE_CUSTOM,
+        Some("COMPOSITES") => CassIndexType::CASS_INDEX_TYPE_COMPOSITES,
+        _ => CassIndexType::CASS_INDEX_TYPE_UNKNOWN,
+    };
+    let target = row
+        .options
+        .as_ref()
+        .and_then(|options| options.get("target"))
+        .cloned()
+        .unwrap_or_default();
+
+    let text_type = || {
+        CassDataType::new_arced(CassDataTypeInner::Value(
+            CassValueType::CASS_VALUE_TYPE_VARCHAR,
+        ))
+    };
+    let options_type = CassDataType::new_arced(CassDataTypeInner::Map {
+        typ: MapDataType::KeyAndValue(text_type(), text_type()),
+        frozen: true,
+    });
+    let options = CassValue {
+        value: row.options.map(|options| {
+            let entries = options
+                .into_iter()
+                .map(|(key, value)| (CqlValue::Text(key), CqlValue::Text(value)))
+                .collect();
+            get_column_value(CqlValue::Map(entries), &options_type)
+        }),
+        value_type: options_type,
+    };
+
+    CassIndexMeta {
+        name: row.index_name,
+        index_type,
+        target,
+        options,
+        fields,
     }
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn cass_schema_meta_free(schema_meta: *mut CassSchemaMeta) {
-    BoxFFI::free(schema_meta);
+pub unsafe extern "C" fn cass_schema_meta_free(schema_meta: *const CassSchemaMeta) {
+    ArcFFI::free(schema_meta);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_schema_meta_snapshot_version(
+    schema_meta: *const CassSchemaMeta,
+) -> cass_uint32_t {
+    let schema_meta = ArcFFI::as_ref(schema_meta);
+    schema_meta.snapshot_version
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_schema_meta_version(
+    schema_meta: *const CassSchemaMeta,
+) -> CassVersion {
+    let schema_meta = ArcFFI::as_ref(schema_meta);
+    schema_meta.version
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_schema_meta_schema_version(
+    schema_meta: *const CassSchemaMeta,
+    schema_version: *mut CassUuid,
+) -> CassError {
+    let schema_meta = ArcFFI::as_ref(schema_meta);
+
+    match schema_meta.schema_version {
+        Some(version) => {
+            *schema_version = version.into();
+            CassError::CASS_OK
+        }
+        None => CassError::CASS_ERROR_LIB_NULL_VALUE,
+    }
 }
 
 #[no_mangle]
@@ -119,7 +751,7 @@ pub unsafe extern "C" fn cass_schema_meta_keyspace_by_name_n(
         return std::ptr::null();
     }
 
-    let metadata = BoxFFI::as_ref(schema_meta);
+    let metadata = ArcFFI::as_ref(schema_meta);
     let keyspace = ptr_to_cstr_n(keyspace_name, keyspace_name_length).unwrap();
 
     let keyspace_meta = metadata.keyspaces.get(keyspace);
@@ -140,6 +772,137 @@ pub unsafe extern "C" fn cass_keyspace_meta_name(
     write_str_to_c(keyspace_meta.name.as_str(), name, name_length)
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_is_virtual(
+    keyspace_meta: *const CassKeyspaceMeta,
+) -> cass_bool_t {
+    let keyspace_meta = RefFFI::as_ref(keyspace_meta);
+    keyspace_meta.is_virtual as cass_bool_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_replication_strategy(
+    keyspace_meta: *const CassKeyspaceMeta,
+) -> CassReplicationStrategy {
+    let keyspace_meta = RefFFI::as_ref(keyspace_meta);
+    keyspace_meta.replication.strategy
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_replication_strategy_class(
+    keyspace_meta: *const CassKeyspaceMeta,
+    class_name: *mut *const c_char,
+    class_name_length: *mut size_t,
+) {
+    let keyspace_meta = RefFFI::as_ref(keyspace_meta);
+    write_str_to_c(
+        keyspace_meta.replication.class_name.as_str(),
+        class_name,
+        class_name_length,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_replication_factor(
+    keyspace_meta: *const CassKeyspaceMeta,
+) -> cass_uint32_t {
+    let keyspace_meta = RefFFI::as_ref(keyspace_meta);
+    keyspace_meta.replication.replication_factor
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_datacenter_count(
+    keyspace_meta: *const CassKeyspaceMeta,
+) -> size_t {
+    let keyspace_meta = RefFFI::as_ref(keyspace_meta);
+    keyspace_meta
+        .replication
+        .datacenter_replication_factors
+        .len() as size_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_datacenter(
+    keyspace_meta: *const CassKeyspaceMeta,
+    index: size_t,
+    name: *mut *const c_char,
+    name_length: *mut size_t,
+    replication_factor: *mut cass_uint32_t,
+) -> CassError {
+    let keyspace_meta = RefFFI::as_ref(keyspace_meta);
+
+    match keyspace_meta
+        .replication
+        .datacenter_replication_factors
+        .iter()
+        .nth(index as usize)
+    {
+        Some((datacenter, datacenter_replication_factor)) => {
+            write_str_to_c(datacenter.as_str(), name, name_length);
+            *replication_factor = *datacenter_replication_factor;
+            CassError::CASS_OK
+        }
+        None => CassError::CASS_ERROR_LIB_INDEX_OUT_OF_BOUNDS,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_datacenter_replication_factor(
+    keyspace_meta: *const CassKeyspaceMeta,
+    datacenter: *const c_char,
+) -> cass_uint32_t {
+    cass_keyspace_meta_datacenter_replication_factor_n(
+        keyspace_meta,
+        datacenter,
+        strlen(datacenter),
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_datacenter_replication_factor_n(
+    keyspace_meta: *const CassKeyspaceMeta,
+    datacenter: *const c_char,
+    datacenter_length: size_t,
+) -> cass_uint32_t {
+    if datacenter.is_null() {
+        return 0;
+    }
+
+    let keyspace_meta = RefFFI::as_ref(keyspace_meta);
+    let datacenter = ptr_to_cstr_n(datacenter, datacenter_length).unwrap();
+
+    keyspace_meta
+        .replication
+        .datacenter_replication_factors
+        .get(datacenter)
+        .copied()
+        .unwrap_or(0)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_durable_writes(
+    keyspace_meta: *const CassKeyspaceMeta,
+) -> cass_bool_t {
+    let keyspace_meta = RefFFI::as_ref(keyspace_meta);
+    keyspace_meta.durable_writes as cass_bool_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_tablets_enabled(
+    keyspace_meta: *const CassKeyspaceMeta,
+) -> cass_bool_t {
+    let keyspace_meta = RefFFI::as_ref(keyspace_meta);
+    keyspace_meta.initial_tablets.is_some() as cass_bool_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_initial_tablets(
+    keyspace_meta: *const CassKeyspaceMeta,
+) -> cass_uint32_t {
+    let keyspace_meta = RefFFI::as_ref(keyspace_meta);
+    keyspace_meta.initial_tablets.unwrap_or(0)
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_keyspace_meta_user_type_by_name(
     keyspace_meta: *const CassKeyspaceMeta,
@@ -209,6 +972,14 @@ pub unsafe extern "C" fn cass_table_meta_name(
     write_str_to_c(table_meta.name.as_str(), name, name_length)
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_table_meta_is_virtual(
+    table_meta: *const CassTableMeta,
+) -> cass_bool_t {
+    let table_meta = RefFFI::as_ref(table_meta);
+    table_meta.is_virtual as cass_bool_t
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_table_meta_column_count(table_meta: *const CassTableMeta) -> size_t {
     let table_meta = RefFFI::as_ref(table_meta);
@@ -255,6 +1026,15 @@ pub unsafe extern "C" fn cass_table_meta_clustering_key(
     }
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_table_meta_clustering_key_order(
+    table_meta: *const CassTableMeta,
+    index: size_t,
+) -> CassClusteringOrder {
+    let table_meta = RefFFI::as_ref(table_meta);
+    clustering_key_order(table_meta, index)
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_table_meta_clustering_key_count(
     table_meta: *const CassTableMeta,
@@ -343,6 +1123,86 @@ pub unsafe extern "C" fn cass_keyspace_meta_materialized_view_by_name_n(
     }
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_table_meta_index_by_name(
+    table_meta: *const CassTableMeta,
+    index: *const c_char,
+) -> *const CassIndexMeta {
+    cass_table_meta_index_by_name_n(table_meta, index, strlen(index))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_table_meta_index_by_name_n(
+    table_meta: *const CassTableMeta,
+    index: *const c_char,
+    index_length: size_t,
+) -> *const CassIndexMeta {
+    if index.is_null() {
+        return std::ptr::null();
+    }
+
+    let table_meta = RefFFI::as_ref(table_meta);
+    let index_name = ptr_to_cstr_n(index, index_length).unwrap();
+
+    match table_meta.indexes.get(index_name) {
+        Some(index_meta) => RefFFI::as_ptr(index_meta),
+        None => std::ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_table_meta_index_count(table_meta: *const CassTableMeta) -> size_t {
+    let table_meta = RefFFI::as_ref(table_meta);
+    table_meta.indexes.len() as size_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_table_meta_index(
+    table_meta: *const CassTableMeta,
+    index: size_t,
+) -> *const CassIndexMeta {
+    let table_meta = RefFFI::as_ref(table_meta);
+
+    match table_meta.indexes.iter().nth(index as usize) {
+        Some((_, index_meta)) => RefFFI::as_ptr(index_meta),
+        None => std::ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_index_meta_name(
+    index_meta: *const CassIndexMeta,
+    name: *mut *const c_char,
+    name_length: *mut size_t,
+) {
+    let index_meta = RefFFI::as_ref(index_meta);
+    write_str_to_c(index_meta.name.as_str(), name, name_length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_index_meta_type(index_meta: *const CassIndexMeta) -> CassIndexType {
+    let index_meta = RefFFI::as_ref(index_meta);
+    index_meta.index_type
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_index_meta_target(
+    index_meta: *const CassIndexMeta,
+    target: *mut *const c_char,
+    target_length: *mut size_t,
+) {
+    let index_meta = RefFFI::as_ref(index_meta);
+    write_str_to_c(index_meta.target.as_str(), target, target_length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_index_meta_options(
+    index_meta: *const CassIndexMeta,
+) -> *const CassValue {
+    let index_meta = RefFFI::as_ref(index_meta);
+    RefFFI::as_ptr(&index_meta.options)
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_table_meta_materialized_view_by_name(
     table_meta: *const CassTableMeta,
@@ -485,6 +1345,15 @@ pub unsafe extern "C" fn cass_materialized_view_meta_partition_key(
     }
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_materialized_view_meta_clustering_key_order(
+    view_meta: *const CassMaterializedViewMeta,
+    index: size_t,
+) -> CassClusteringOrder {
+    let view_meta = RefFFI::as_ref(view_meta);
+    clustering_key_order(&view_meta.view_metadata, index)
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_materialized_view_meta_clustering_key_count(
     view_meta: *const CassMaterializedViewMeta,
@@ -507,3 +1376,733 @@ pub unsafe extern "C" fn cass_materialized_view_meta_clustering_key(
         None => std::ptr::null(),
     }
 }
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_function_by_name(
+    keyspace_meta: *const CassKeyspaceMeta,
+    name: *const c_char,
+    arguments: *const c_char,
+) -> *const CassFunctionMeta {
+    cass_keyspace_meta_function_by_name_n(
+        keyspace_meta,
+        name,
+        strlen(name),
+        arguments,
+        strlen(arguments),
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_function_by_name_n(
+    keyspace_meta: *const CassKeyspaceMeta,
+    name: *const c_char,
+    name_length: size_t,
+    arguments: *const c_char,
+    arguments_length: size_t,
+) -> *const CassFunctionMeta {
+    if name.is_null() || arguments.is_null() {
+        return std::ptr::null();
+    }
+
+    let keyspace_meta = RefFFI::as_ref(keyspace_meta);
+    let function_name = ptr_to_cstr_n(name, name_length).unwrap();
+    let arguments = ptr_to_cstr_n(arguments, arguments_length).unwrap();
+
+    match keyspace_meta
+        .functions
+        .get(&full_function_name(function_name, [arguments]))
+    {
+        Some(function_meta) => RefFFI::as_ptr(function_meta.as_ref()),
+        None => std::ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_aggregate_by_name(
+    keyspace_meta: *const CassKeyspaceMeta,
+    name: *const c_char,
+    arguments: *const c_char,
+) -> *const CassAggregateMeta {
+    cass_keyspace_meta_aggregate_by_name_n(
+        keyspace_meta,
+        name,
+        strlen(name),
+        arguments,
+        strlen(arguments),
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_aggregate_by_name_n(
+    keyspace_meta: *const CassKeyspaceMeta,
+    name: *const c_char,
+    name_length: size_t,
+    arguments: *const c_char,
+    arguments_length: size_t,
+) -> *const CassAggregateMeta {
+    if name.is_null() || arguments.is_null() {
+        return std::ptr::null();
+    }
+
+    let keyspace_meta = RefFFI::as_ref(keyspace_meta);
+    let aggregate_name = ptr_to_cstr_n(name, name_length).unwrap();
+    let arguments = ptr_to_cstr_n(arguments, arguments_length).unwrap();
+
+    match keyspace_meta
+        .aggregates
+        .get(&full_function_name(aggregate_name, [arguments]))
+    {
+        Some(aggregate_meta) => RefFFI::as_ptr(aggregate_meta.as_ref()),
+        None => std::ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_function_meta_name(
+    function_meta: *const CassFunctionMeta,
+    name: *mut *const c_char,
+    name_length: *mut size_t,
+) {
+    let function_meta = RefFFI::as_ref(function_meta);
+    write_str_to_c(function_meta.name.as_str(), name, name_length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_function_meta_full_name(
+    function_meta: *const CassFunctionMeta,
+    full_name: *mut *const c_char,
+    full_name_length: *mut size_t,
+) {
+    let function_meta = RefFFI::as_ref(function_meta);
+    write_str_to_c(
+        function_meta.full_name.as_str(),
+        full_name,
+        full_name_length,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_function_meta_body(
+    function_meta: *const CassFunctionMeta,
+    body: *mut *const c_char,
+    body_length: *mut size_t,
+) {
+    let function_meta = RefFFI::as_ref(function_meta);
+    write_str_to_c(function_meta.body.as_str(), body, body_length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_function_meta_language(
+    function_meta: *const CassFunctionMeta,
+    language: *mut *const c_char,
+    language_length: *mut size_t,
+) {
+    let function_meta = RefFFI::as_ref(function_meta);
+    write_str_to_c(function_meta.language.as_str(), language, language_length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_function_meta_called_on_null_input(
+    function_meta: *const CassFunctionMeta,
+) -> cass_bool_t {
+    let function_meta = RefFFI::as_ref(function_meta);
+    function_meta.called_on_null_input as cass_bool_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_function_meta_argument_count(
+    function_meta: *const CassFunctionMeta,
+) -> size_t {
+    let function_meta = RefFFI::as_ref(function_meta);
+    function_meta.arguments.len() as size_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_function_meta_argument(
+    function_meta: *const CassFunctionMeta,
+    index: size_t,
+    name: *mut *const c_char,
+    name_length: *mut size_t,
+    type_: *mut *const CassDataType,
+) -> CassError {
+    let function_meta = RefFFI::as_ref(function_meta);
+
+    match function_meta.arguments.get(index as usize) {
+        Some((argument_name, argument_type)) => {
+            write_str_to_c(argument_name.as_str(), name, name_length);
+            *type_ = ArcFFI::as_ptr(argument_type);
+            CassError::CASS_OK
+        }
+        None => CassError::CASS_ERROR_LIB_INDEX_OUT_OF_BOUNDS,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_function_meta_argument_type_by_name(
+    function_meta: *const CassFunctionMeta,
+    name: *const c_char,
+) -> *const CassDataType {
+    cass_function_meta_argument_type_by_name_n(function_meta, name, strlen(name))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_function_meta_argument_type_by_name_n(
+    function_meta: *const CassFunctionMeta,
+    name: *const c_char,
+    name_length: size_t,
+) -> *const CassDataType {
+    if name.is_null() {
+        return std::ptr::null();
+    }
+
+    let function_meta = RefFFI::as_ref(function_meta);
+    let argument_name = ptr_to_cstr_n(name, name_length).unwrap();
+
+    match function_meta
+        .arguments
+        .iter()
+        .find(|(arg_name, _)| arg_name == argument_name)
+    {
+        Some((_, argument_type)) => ArcFFI::as_ptr(argument_type),
+        None => std::ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_function_meta_return_type(
+    function_meta: *const CassFunctionMeta,
+) -> *const CassDataType {
+    let function_meta = RefFFI::as_ref(function_meta);
+    ArcFFI::as_ptr(&function_meta.return_type)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_aggregate_meta_name(
+    aggregate_meta: *const CassAggregateMeta,
+    name: *mut *const c_char,
+    name_length: *mut size_t,
+) {
+    let aggregate_meta = RefFFI::as_ref(aggregate_meta);
+    write_str_to_c(aggregate_meta.name.as_str(), name, name_length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_aggregate_meta_full_name(
+    aggregate_meta: *const CassAggregateMeta,
+    full_name: *mut *const c_char,
+    full_name_length: *mut size_t,
+) {
+    let aggregate_meta = RefFFI::as_ref(aggregate_meta);
+    write_str_to_c(
+        aggregate_meta.full_name.as_str(),
+        full_name,
+        full_name_length,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_aggregate_meta_argument_count(
+    aggregate_meta: *const CassAggregateMeta,
+) -> size_t {
+    let aggregate_meta = RefFFI::as_ref(aggregate_meta);
+    aggregate_meta.argument_types.len() as size_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_aggregate_meta_argument_type(
+    aggregate_meta: *const CassAggregateMeta,
+    index: size_t,
+) -> *const CassDataType {
+    let aggregate_meta = RefFFI::as_ref(aggregate_meta);
+
+    match aggregate_meta.argument_types.get(index as usize) {
+        Some(argument_type) => ArcFFI::as_ptr(argument_type),
+        None => std::ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_aggregate_meta_return_type(
+    aggregate_meta: *const CassAggregateMeta,
+) -> *const CassDataType {
+    let aggregate_meta = RefFFI::as_ref(aggregate_meta);
+    ArcFFI::as_ptr(&aggregate_meta.return_type)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_aggregate_meta_state_type(
+    aggregate_meta: *const CassAggregateMeta,
+) -> *const CassDataType {
+    let aggregate_meta = RefFFI::as_ref(aggregate_meta);
+    ArcFFI::as_ptr(&aggregate_meta.state_type)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_aggregate_meta_state_func(
+    aggregate_meta: *const CassAggregateMeta,
+) -> *const CassFunctionMeta {
+    let aggregate_meta = RefFFI::as_ref(aggregate_meta);
+
+    match &aggregate_meta.state_func {
+        Some(function_meta) => RefFFI::as_ptr(function_meta.as_ref()),
+        None => std::ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_aggregate_meta_final_func(
+    aggregate_meta: *const CassAggregateMeta,
+) -> *const CassFunctionMeta {
+    let aggregate_meta = RefFFI::as_ref(aggregate_meta);
+
+    match &aggregate_meta.final_func {
+        Some(function_meta) => RefFFI::as_ptr(function_meta.as_ref()),
+        None => std::ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_aggregate_meta_init_cond(
+    aggregate_meta: *const CassAggregateMeta,
+) -> *const CassValue {
+    let aggregate_meta = RefFFI::as_ref(aggregate_meta);
+    RefFFI::as_ptr(&aggregate_meta.init_cond)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_field_by_name(
+    keyspace_meta: *const CassKeyspaceMeta,
+    name: *const c_char,
+) -> *const CassValue {
+    cass_keyspace_meta_field_by_name_n(keyspace_meta, name, strlen(name))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_keyspace_meta_field_by_name_n(
+    keyspace_meta: *const CassKeyspaceMeta,
+    name: *const c_char,
+    name_length: size_t,
+) -> *const CassValue {
+    if name.is_null() {
+        return std::ptr::null();
+    }
+
+    let keyspace_meta = RefFFI::as_ref(keyspace_meta);
+    let field_name = ptr_to_cstr_n(name, name_length).unwrap();
+
+    match keyspace_meta.fields.get(field_name) {
+        Some(value) => RefFFI::as_ptr(value),
+        None => std::ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_table_meta_field_by_name(
+    table_meta: *const CassTableMeta,
+    name: *const c_char,
+) -> *const CassValue {
+    cass_table_meta_field_by_name_n(table_meta, name, strlen(name))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_table_meta_field_by_name_n(
+    table_meta: *const CassTableMeta,
+    name: *const c_char,
+    name_length: size_t,
+) -> *const CassValue {
+    if name.is_null() {
+        return std::ptr::null();
+    }
+
+    let table_meta = RefFFI::as_ref(table_meta);
+    let field_name = ptr_to_cstr_n(name, name_length).unwrap();
+
+    match table_meta.fields.get(field_name) {
+        Some(value) => RefFFI::as_ptr(value),
+        None => std::ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_materialized_view_meta_field_by_name(
+    view_meta: *const CassMaterializedViewMeta,
+    name: *const c_char,
+) -> *const CassValue {
+    cass_materialized_view_meta_field_by_name_n(view_meta, name, strlen(name))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_materialized_view_meta_field_by_name_n(
+    view_meta: *const CassMaterializedViewMeta,
+    name: *const c_char,
+    name_length: size_t,
+) -> *const CassValue {
+    if name.is_null() {
+        return std::ptr::null();
+    }
+
+    let view_meta = RefFFI::as_ref(view_meta);
+    let field_name = ptr_to_cstr_n(name, name_length).unwrap();
+
+    match view_meta.view_metadata.fields.get(field_name) {
+        Some(value) => RefFFI::as_ptr(value),
+        None => std::ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_column_meta_field_by_name(
+    column_meta: *const CassColumnMeta,
+    name: *const c_char,
+) -> *const CassValue {
+    cass_column_meta_field_by_name_n(column_meta, name, strlen(name))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_column_meta_field_by_name_n(
+    column_meta: *const CassColumnMeta,
+    name: *const c_char,
+    name_length: size_t,
+) -> *const CassValue {
+    if name.is_null() {
+        return std::ptr::null();
+    }
+
+    let column_meta = RefFFI::as_ref(column_meta);
+    let field_name = ptr_to_cstr_n(name, name_length).unwrap();
+
+    match column_meta.fields.get(field_name) {
+        Some(value) => RefFFI::as_ptr(value),
+        None => std::ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_index_meta_field_by_name(
+    index_meta: *const CassIndexMeta,
+    name: *const c_char,
+) -> *const CassValue {
+    cass_index_meta_field_by_name_n(index_meta, name, strlen(name))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_index_meta_field_by_name_n(
+    index_meta: *const CassIndexMeta,
+    name: *const c_char,
+    name_length: size_t,
+) -> *const CassValue {
+    if name.is_null() {
+        return std::ptr::null();
+    }
+
+    let index_meta = RefFFI::as_ref(index_meta);
+    let field_name = ptr_to_cstr_n(name, name_length).unwrap();
+
+    match index_meta.fields.get(field_name) {
+        Some(value) => RefFFI::as_ptr(value),
+        None => std::ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_function_meta_field_by_name(
+    function_meta: *const CassFunctionMeta,
+    name: *const c_char,
+) -> *const CassValue {
+    cass_function_meta_field_by_name_n(function_meta, name, strlen(name))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_function_meta_field_by_name_n(
+    function_meta: *const CassFunctionMeta,
+    name: *const c_char,
+    name_length: size_t,
+) -> *const CassValue {
+    if name.is_null() {
+        return std::ptr::null();
+    }
+
+    let function_meta = RefFFI::as_ref(function_meta);
+    let field_name = ptr_to_cstr_n(name, name_length).unwrap();
+
+    match function_meta.fields.get(field_name) {
+        Some(value) => RefFFI::as_ptr(value),
+        None => std::ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_aggregate_meta_field_by_name(
+    aggregate_meta: *const CassAggregateMeta,
+    name: *const c_char,
+) -> *const CassValue {
+    cass_aggregate_meta_field_by_name_n(aggregate_meta, name, strlen(name))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_aggregate_meta_field_by_name_n(
+    aggregate_meta: *const CassAggregateMeta,
+    name: *const c_char,
+    name_length: size_t,
+) -> *const CassValue {
+    if name.is_null() {
+        return std::ptr::null();
+    }
+
+    let aggregate_meta = RefFFI::as_ref(aggregate_meta);
+    let field_name = ptr_to_cstr_n(name, name_length).unwrap();
+
+    match aggregate_meta.fields.get(field_name) {
+        Some(value) => RefFFI::as_ptr(value),
+        None => std::ptr::null(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::make_fields;
+
+    fn text_type() -> Arc<CassDataType> {
+        CassDataType::new_arced(CassDataTypeInner::Value(
+            CassValueType::CASS_VALUE_TYPE_VARCHAR,
+        ))
+    }
+
+    fn int_type() -> Arc<CassDataType> {
+        CassDataType::new_arced(CassDataTypeInner::Value(CassValueType::CASS_VALUE_TYPE_INT))
+    }
+
+    fn text(text: &str) -> Option<CqlValue> {
+        Some(CqlValue::Text(text.to_owned()))
+    }
+
+    #[test]
+    fn meta_fields_test() {
+        let list_type = CassDataType::new_arced(CassDataTypeInner::List {
+            typ: Some(text_type()),
+            frozen: true,
+        });
+        let fields = make_fields(vec![
+            ("function_name", text_type(), text("f")),
+            (
+                "argument_types",
+                list_type.clone(),
+                Some(CqlValue::List(vec![
+                    CqlValue::Text("int".to_owned()),
+                    CqlValue::Text("frozen<list<int>>".to_owned()),
+                ])),
+            ),
+            ("argument_names", list_type, None),
+        ]);
+
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields.nth(1).map(|(name, _)| name), Some("argument_types"));
+        assert!(fields.nth(3).is_none());
+        assert!(fields.get("body").is_none());
+        assert_eq!(fields.text("function_name"), Some("f"));
+        assert_eq!(fields.text("argument_types"), None);
+        assert_eq!(
+            fields.text_list("argument_types"),
+            vec!["int", "frozen<list<int>>"]
+        );
+        assert!(fields.text_list("argument_names").is_empty());
+        assert_eq!(
+            full_function_name("f", fields.text_list("argument_types")),
+            "f(int,frozen<list<int>>)"
+        );
+
+        let empty_fields = CassMetaFields::default();
+        assert!(empty_fields.is_empty());
+        assert!(empty_fields.get("function_name").is_none());
+    }
+
+    #[test]
+    fn schema_rows_from_fields_test() {
+        let list_type = CassDataType::new_arced(CassDataTypeInner::List {
+            typ: Some(text_type()),
+            frozen: true,
+        });
+        let map_type = CassDataType::new_arced(CassDataTypeInner::Map {
+            typ: MapDataType::KeyAndValue(text_type(), text_type()),
+            frozen: true,
+        });
+        let text_list = |texts: &[&str]| {
+            Some(CqlValue::List(
+                texts
+                    .iter()
+                    .map(|&text| CqlValue::Text(text.to_owned()))
+                    .collect(),
+            ))
+        };
+
+        let index_fields = make_fields(vec![
+            ("keyspace_name", text_type(), text("ks")),
+            ("table_name", text_type(), text("t")),
+            ("index_name", text_type(), text("t_v_idx")),
+            ("kind", text_type(), text("COMPOSITES")),
+            (
+                "options",
+                map_type,
+                Some(CqlValue::Map(vec![(
+                    CqlValue::Text("target".to_owned()),
+                    CqlValue::Text("v".to_owned()),
+                )])),
+            ),
+        ]);
+        let index_row = IndexRow::from_fields(&index_fields).unwrap();
+        assert_eq!(index_row.index_name, "t_v_idx");
+        assert_eq!(index_row.kind.as_deref(), Some("COMPOSITES"));
+        assert_eq!(
+            index_row.options,
+            Some(BTreeMap::from([("target".to_owned(), "v".to_owned())]))
+        );
+
+        let function_fields = make_fields(vec![
+            ("keyspace_name", text_type(), text("ks")),
+            ("function_name", text_type(), text("f")),
+            ("argument_names", list_type.clone(), text_list(&["a", "b"])),
+            (
+                "argument_types",
+                list_type.clone(),
+                text_list(&["int", "text"]),
+            ),
+            ("body", text_type(), text("return a;")),
+            ("language", text_type(), text("lua")),
+            ("return_type", text_type(), text("int")),
+        ]);
+        let function_row = FunctionRow::from_fields(&function_fields).unwrap();
+        assert_eq!(function_row.argument_names, vec!["a", "b"]);
+        assert_eq!(function_row.argument_types, vec!["int", "text"]);
+        assert_eq!(function_row.body.as_deref(), Some("return a;"));
+        assert_eq!(function_row.called_on_null_input, None);
+
+        let aggregate_fields = make_fields(vec![
+            ("keyspace_name", text_type(), text("ks")),
+            ("aggregate_name", text_type(), text("agg")),
+            ("argument_types", list_type, None),
+            ("state_func", text_type(), text("f")),
+            ("state_type", text_type(), text("int")),
+        ]);
+        let aggregate_row = AggregateRow::from_fields(&aggregate_fields).unwrap();
+        assert_eq!(aggregate_row.aggregate_name, "agg");
+        assert!(aggregate_row.argument_types.is_empty());
+        assert_eq!(aggregate_row.state_func.as_deref(), Some("f"));
+        assert_eq!(aggregate_row.final_func, None);
+
+        // Rows without the name of the object are rejected.
+        let nameless_fields = make_fields(vec![("keyspace_name", text_type(), text("ks"))]);
+        assert!(IndexRow::from_fields(&nameless_fields).is_none());
+        assert!(FunctionRow::from_fields(&nameless_fields).is_none());
+        assert!(AggregateRow::from_fields(&nameless_fields).is_none());
+    }
+
+    #[test]
+    fn release_version_test() {
+        let version = |major_version, minor_version, patch_version| CassVersion {
+            major_version,
+            minor_version,
+            patch_version,
+        };
+
+        assert_eq!(parse_release_version("3.0.8"), version(3, 0, 8));
+        assert_eq!(parse_release_version("4.1-SNAPSHOT"), version(4, 1, 0));
+        assert_eq!(parse_release_version("5.0.0.1"), version(5, 0, 0));
+        assert_eq!(parse_release_version(""), version(0, 0, 0));
+    }
+
+    #[test]
+    fn keyspace_replication_test() {
+        let replication = KeyspaceReplication::new(&Strategy::NetworkTopologyStrategy {
+            datacenter_repfactors: HashMap::from([("dc2".to_owned(), 2), ("dc1".to_owned(), 3)]),
+        });
+        assert_eq!(
+            replication.strategy,
+            CassReplicationStrategy::CASS_REPLICATION_STRATEGY_NETWORK_TOPOLOGY
+        );
+        assert_eq!(
+            replication.class_name,
+            "org.apache.cassandra.locator.NetworkTopologyStrategy"
+        );
+        assert_eq!(replication.replication_factor, 5);
+        assert_eq!(
+            replication
+                .datacenter_replication_factors
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![("dc1".to_owned(), 3), ("dc2".to_owned(), 2)]
+        );
+
+        let replication = KeyspaceReplication::new(&Strategy::SimpleStrategy {
+            replication_factor: 3,
+        });
+        assert_eq!(
+            replication.strategy,
+            CassReplicationStrategy::CASS_REPLICATION_STRATEGY_SIMPLE
+        );
+        assert_eq!(replication.replication_factor, 3);
+        assert!(replication.datacenter_replication_factors.is_empty());
+
+        let replication = KeyspaceReplication::new(&Strategy::Other {
+            name: "com.scylladb.locator.EverywhereStrategy".to_owned(),
+            data: HashMap::new(),
+        });
+        assert_eq!(
+            replication.strategy,
+            CassReplicationStrategy::CASS_REPLICATION_STRATEGY_OTHER
+        );
+        assert_eq!(
+            replication.class_name,
+            "com.scylladb.locator.EverywhereStrategy"
+        );
+        assert_eq!(replication.replication_factor, 0);
+    }
+
+    #[test]
+    fn virtual_table_test() {
+        let column = |name: &str, kind: &str, position: i32, order: &str, typ: &str| {
+            make_fields(vec![
+                ("column_name", text_type(), text(name)),
+                ("kind", text_type(), text(kind)),
+                ("position", int_type(), Some(CqlValue::Int(position))),
+                ("clustering_order", text_type(), text(order)),
+                ("type", text_type(), text(typ)),
+            ])
+        };
+        let columns = vec![
+            column("value", "regular", -1, "none", "text"),
+            column("ck2", "clustering", 1, "asc", "int"),
+            column("pk", "partition_key", 0, "none", "text"),
+            column("ck1", "clustering", 0, "desc", "timestamp"),
+        ];
+
+        let table_meta =
+            create_virtual_table_metadata("ks", "tbl", CassMetaFields::default(), columns);
+
+        assert!(table_meta.is_virtual);
+        assert_eq!(table_meta.partition_keys, vec!["pk"]);
+        assert_eq!(table_meta.clustering_keys, vec!["ck1", "ck2"]);
+        assert_eq!(
+            clustering_key_order(&table_meta, 0),
+            CassClusteringOrder::CASS_CLUSTERING_ORDER_DESC
+        );
+        assert_eq!(
+            clustering_key_order(&table_meta, 1),
+            CassClusteringOrder::CASS_CLUSTERING_ORDER_ASC
+        );
+        assert_eq!(
+            clustering_key_order(&table_meta, 2),
+            CassClusteringOrder::CASS_CLUSTERING_ORDER_NONE
+        );
+
+        let value_meta = &table_meta.columns_metadata["value"];
+        assert_eq!(
+            value_meta.column_kind,
+            CassColumnType::CASS_COLUMN_TYPE_REGULAR
+        );
+        assert_eq!(
+            unsafe { value_meta.column_type.get_unchecked() },
+            &CassDataTypeInner::Value(CassValueType::CASS_VALUE_TYPE_TEXT)
+        );
+    }
+}
@@ -0,0 +1,822 @@
+//! Conversions of varints and decimals from and to base-10 strings and primitive types.
+//!
+//! Varints are represented as signed big-endian two's complement bytes, the same way
+//! they are returned by `cass_value_get_bytes()`. Decimals are represented as
+//! a varint (the unscaled value) and a scale, the same way they are returned by
+//! `cass_value_get_decimal()`.
+
+use crate::argconv::*;
+use crate::cass_error::CassError;
+use crate::types::*;
+use scylla::frame::value::CqlDecimal;
+use std::fmt::Write;
+use std::os::raw::c_char;
+
+/// Strips the redundant sign extension bytes from a varint.
+fn normalize_varint(bytes: &[u8]) -> &[u8] {
+    let mut start = 0;
+    while start + 1 < bytes.len() {
+        let (b, next) = (bytes[start], bytes[start + 1]);
+        let redundant = (b == 0x00 && next & 0x80 == 0) || (b == 0xFF && next & 0x80 != 0);
+        if !redundant {
+            break;
+        }
+        start += 1;
+    }
+    &bytes[start..]
+}
+
+/// Two's complement negation: inverts the bits and adds one.
+fn negate_in_place(bytes: &mut [u8]) {
+    bytes.iter_mut().for_each(|b| *b = !*b);
+    for b in bytes.iter_mut().rev() {
+        let (sum, overflow) = b.overflowing_add(1);
+        *b = sum;
+        if !overflow {
+            break;
+        }
+    }
+}
+
+/// Parses a non-empty string of ASCII digits into an unsigned big-endian magnitude.
+fn magnitude_from_digits(digits: &str) -> Vec<u8> {
+    let mut magnitude: Vec<u8> = Vec::new();
+    // Multiply the magnitude by 10^9 and add the next chunk of (up to) 9 digits at a time.
+    for chunk in digits.as_bytes().chunks(9) {
+        let multiplier = 10u64.pow(chunk.len() as u32);
+        let mut carry = chunk
+            .iter()
+            .fold(0u64, |acc, d| acc * 10 + (d - b'0') as u64);
+        for b in magnitude.iter_mut().rev() {
+            let current = *b as u64 * multiplier + carry;
+            *b = current as u8;
+            carry = current >> 8;
+        }
+        while carry > 0 {
+            magnitude.insert(0, carry as u8);
+            carry >>= 8;
+        }
+    }
+    magnitude
+}
+
+/// Encodes a sign and an unsigned big-endian magnitude as a minimal varint.
+fn varint_from_magnitude(negative: bool, magnitude: &[u8]) -> Vec<u8> {
+    // The leading zero byte leaves room for the sign bit.
+    let mut bytes = Vec::with_capacity(magnitude.len() + 1);
+    bytes.push(0);
+    bytes.extend_from_slice(magnitude);
+    if negative {
+        negate_in_place(&mut bytes);
+    }
+    normalize_varint(&bytes).to_vec()
+}
+
+fn split_sign(s: &str) -> (bool, &str) {
+    match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    }
+}
+
+fn is_digits(s: &str) -> bool {
+    s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Formats a varint, given as signed big-endian two's complement bytes, in base 10.
+pub(crate) fn varint_to_string(bytes: &[u8]) -> String {
+    let negative = bytes.first().is_some_and(|b| b & 0x80 != 0);
+
+    let mut magnitude = bytes.to_vec();
+    if negative {
+        negate_in_place(&mut magnitude);
+    }
+
+    // Repeatedly divide the magnitude by 10^9, collecting the remainders
+    // (least significant chunk first).
+    let mut chunks: Vec<u32> = Vec::new();
+    let mut dividend: Vec<u8> = magnitude.into_iter().skip_while(|b| *b == 0).collect();
+    while !dividend.is_empty() {
+        let mut remainder: u64 = 0;
+        let mut quotient: Vec<u8> = Vec::with_capacity(dividend.len());
+        for b in dividend {
+            let current = (remainder << 8) | b as u64;
+            let q = current / 1_000_000_000;
+            remainder = current % 1_000_000_000;
+            if !quotient.is_empty() || q != 0 {
+                quotient.push(q as u8);
+            }
+        }
+        chunks.push(remainder as u32);
+        dividend = quotient;
+    }
+
+    let mut result = String::new();
+    match chunks.split_last() {
+        None => result.push('0'),
+        Some((most_significant, rest)) => {
+            if negative {
+                result.push('-');
+            }
+            write!(result, "{}", most_significant).unwrap();
+            for chunk in rest.iter().rev() {
+                write!(result, "{:09}", chunk).unwrap();
+            }
+        }
+    }
+
+    result
+}
+
+/// Formats a decimal the same way as Java's `BigDecimal::toString()` does,
+/// i.e. uses the scientific notation for negative scales and very small numbers.
+pub(crate) fn decimal_to_string(decimal: &CqlDecimal) -> String {
+    let (unscaled, scale) = decimal.as_signed_be_bytes_slice_and_exponent();
+    let unscaled = varint_to_string(unscaled);
+    let (sign, coefficient) = match unscaled.strip_prefix('-') {
+        Some(abs) => ("-", abs),
+        None => ("", unscaled.as_str()),
+    };
+
+    let scale = scale as i64;
+    let digits = coefficient.len() as i64;
+    let adjusted_exponent = digits - 1 - scale;
+
+    let mut result = String::from(sign);
+    if scale >= 0 && adjusted_exponent >= -6 {
+        if scale == 0 {
+            result.push_str(coefficient);
+        } else if digits > scale {
+            let (integer_part, fraction_part) = coefficient.split_at((digits - scale) as usize);
+            write!(result, "{}.{}", integer_part, fraction_part).unwrap();
+        } else {
+            result.push_str("0.");
+            (0..scale - digits).for_each(|_| result.push('0'));
+            result.push_str(coefficient);
+        }
+    } else {
+        let (first_digit, rest) = coefficient.split_at(1);
+        result.push_str(first_digit);
+        if !rest.is_empty() {
+            write!(result, ".{}", rest).unwrap();
+        }
+        write!(result, "E{:+}", adjusted_exponent).unwrap();
+    }
+
+    result
+}
+
+/// Parses a base-10 integer with an optional sign, e.g. `-123`, into a minimal varint.
+pub(crate) fn varint_from_string(s: &str) -> Option<Vec<u8>> {
+    let (negative, digits) = split_sign(s);
+    if digits.is_empty() || !is_digits(digits) {
+        return None;
+    }
+    Some(varint_from_magnitude(
+        negative,
+        &magnitude_from_digits(digits),
+    ))
+}
+
+/// Returns `None` if the varint doesn't fit in an `i64`.
+pub(crate) fn varint_to_i64(bytes: &[u8]) -> Option<i64> {
+    let bytes = normalize_varint(bytes);
+    if bytes.len() > 8 {
+        return None;
+    }
+    let negative = bytes.first().is_some_and(|b| b & 0x80 != 0);
+    let mut buf = if negative { [0xFF; 8] } else { [0x00; 8] };
+    buf[8 - bytes.len()..].copy_from_slice(bytes);
+    Some(i64::from_be_bytes(buf))
+}
+
+pub(crate) fn varint_from_i64(value: i64) -> Vec<u8> {
+    normalize_varint(&value.to_be_bytes()).to_vec()
+}
+
+/// Parses a decimal in the format accepted by Java's `BigDecimal(String)`,
+/// e.g. `123`, `-1.23`, `.5`, `1.23E-7` or `1e+2`.
+pub(crate) fn decimal_from_string(s: &str) -> Option<CqlDecimal> {
+    let (negative, rest) = split_sign(s);
+    let (mantissa, exponent) = match rest.find(['e', 'E']) {
+        Some(pos) => (&rest[..pos], Some(&rest[pos + 1..])),
+        None => (rest, None),
+    };
+    let (integer_part, fraction_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if integer_part.len() + fraction_part.len() == 0
+        || !is_digits(integer_part)
+        || !is_digits(fraction_part)
+    {
+        return None;
+    }
+
+    let exponent: i64 = match exponent {
+        Some(e) => e.parse().ok()?,
+        None => 0,
+    };
+    let scale = i32::try_from(fraction_part.len() as i64 - exponent).ok()?;
+
+    let digits = format!("{}{}", integer_part, fraction_part);
+    let unscaled = varint_from_magnitude(negative, &magnitude_from_digits(&digits));
+    Some(CqlDecimal::from_signed_be_bytes_slice_and_exponent(
+        &unscaled, scale,
+    ))
+}
+
+/// Returns the base-10 unscaled value and the scale of the decimal
+/// with the trailing zeros of the unscaled value stripped,
+/// so that equal decimals (e.g. `1.0` and `1.00`) have equal representations.
+fn normalize_decimal(decimal: &CqlDecimal) -> (String, i64) {
+    let (unscaled, scale) = decimal.as_signed_be_bytes_slice_and_exponent();
+    let mut unscaled = varint_to_string(unscaled);
+    let mut scale = scale as i64;
+    if unscaled == "0" {
+        return (unscaled, 0);
+    }
+    while unscaled.ends_with('0') {
+        unscaled.pop();
+        scale -= 1;
+    }
+    (unscaled, scale)
+}
+
+/// Returns `None` if the decimal has a non-zero fractional part or doesn't fit in an `i64`.
+pub(crate) fn decimal_to_i64(decimal: &CqlDecimal) -> Option<i64> {
+    let (unscaled, scale) = normalize_decimal(decimal);
+    if scale > 0 {
+        return None;
+    }
+    let unscaled: i64 = unscaled.parse().ok()?;
+    let multiplier = 10i64.checked_pow(u32::try_from(-scale).ok()?)?;
+    unscaled.checked_mul(multiplier)
+}
+
+pub(crate) fn decimal_from_i64(value: i64) -> CqlDecimal {
+    CqlDecimal::from_signed_be_bytes_slice_and_exponent(&varint_from_i64(value), 0)
+}
+
+/// Converts the decimal to an `f64`. Returns `None` unless the `f64` is exactly
+/// equal to the decimal, i.e. the conversion is lossless.
+pub(crate) fn decimal_to_f64(decimal: &CqlDecimal) -> Option<f64> {
+    let value: f64 = decimal_to_string(decimal).parse().ok()?;
+    let exact = normalize_decimal(&exact_decimal_from_f64(value)?) == normalize_decimal(decimal);
+    exact.then_some(value)
+}
+
+/// Returns the decimal exactly equal to `value`, e.g.
+/// `0.1000000000000000055511151231257827021181583404541015625` for `0.1`,
+/// or `None` for NaN and infinities.
+fn exact_decimal_from_f64(value: f64) -> Option<CqlDecimal> {
+    if !value.is_finite() {
+        return None;
+    }
+    let bits = value.to_bits();
+    let negative = bits >> 63 != 0;
+    let biased_exponent = ((bits >> 52) & 0x7FF) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    // value = mantissa * 2^exponent
+    let (mantissa, exponent) = match biased_exponent {
+        0 => (fraction, -1074),
+        _ => (fraction | (1 << 52), biased_exponent - 1075),
+    };
+
+    // The magnitude as base-10^9 chunks, least significant chunk first.
+    let mut chunks: Vec<u32> = Vec::new();
+    let mut rest = mantissa;
+    while rest > 0 {
+        chunks.push((rest % 1_000_000_000) as u32);
+        rest /= 1_000_000_000;
+    }
+    let multiply = |chunks: &mut Vec<u32>, factor: u64| {
+        let mut carry = 0u64;
+        for chunk in chunks.iter_mut() {
+            let current = *chunk as u64 * factor + carry;
+            *chunk = (current % 1_000_000_000) as u32;
+            carry = current / 1_000_000_000;
+        }
+        if carry > 0 {
+            chunks.push(carry as u32);
+        }
+    };
+    // mantissa * 2^exponent is an integer for non-negative exponents,
+    // and equal to mantissa * 5^-exponent / 10^-exponent otherwise.
+    let (factor, scale) = if exponent >= 0 {
+        (2, 0)
+    } else {
+        (5, -exponent)
+    };
+    (0..exponent.abs()).for_each(|_| multiply(&mut chunks, factor));
+
+    let mut digits = String::new();
+    match chunks.split_last() {
+        None => digits.push('0'),
+        Some((most_significant, rest)) => {
+            write!(digits, "{}", most_significant).unwrap();
+            for chunk in rest.iter().rev() {
+                write!(digits, "{:09}", chunk).unwrap();
+            }
+        }
+    }
+
+    let unscaled = varint_from_magnitude(negative, &magnitude_from_digits(&digits));
+    Some(CqlDecimal::from_signed_be_bytes_slice_and_exponent(
+        &unscaled, scale,
+    ))
+}
+
+/// Returns the shortest decimal that converts back to `value`,
+/// or `None` for NaN and infinities.
+pub(crate) fn decimal_from_f64(value: f64) -> Option<CqlDecimal> {
+    if !value.is_finite() {
+        return None;
+    }
+    decimal_from_string(&format!("{:e}", value))
+}
+
+unsafe fn varint_from_raw<'a>(varint: *const cass_byte_t, varint_size: size_t) -> &'a [u8] {
+    if varint_size == 0 {
+        return &[];
+    }
+    std::slice::from_raw_parts(varint, varint_size as usize)
+}
+
+/// Copies `varint` into a caller-supplied buffer.
+/// The size of `varint` is always written to `varint_size` (if it's not null),
+/// so the caller can learn the required size of the buffer.
+unsafe fn write_varint_to_buffer(
+    varint: &[u8],
+    output: *mut cass_byte_t,
+    output_size: size_t,
+    varint_size: *mut size_t,
+) -> CassError {
+    if !varint_size.is_null() {
+        std::ptr::write(varint_size, varint.len() as size_t);
+    }
+
+    if output.is_null() || (output_size as usize) < varint.len() {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+
+    std::ptr::copy_nonoverlapping(varint.as_ptr(), output, varint.len());
+
+    CassError::CASS_OK
+}
+
+unsafe fn write_decimal_to_buffer(
+    decimal: &CqlDecimal,
+    varint: *mut cass_byte_t,
+    varint_buffer_size: size_t,
+    varint_size: *mut size_t,
+    scale: *mut cass_int32_t,
+) -> CassError {
+    let (unscaled, exponent) = decimal.as_signed_be_bytes_slice_and_exponent();
+    if !scale.is_null() {
+        std::ptr::write(scale, exponent);
+    }
+    write_varint_to_buffer(unscaled, varint, varint_buffer_size, varint_size)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_varint_from_string(
+    value: *const c_char,
+    output: *mut cass_byte_t,
+    output_size: size_t,
+    varint_size: *mut size_t,
+) -> CassError {
+    if value.is_null() {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+    match ptr_to_cstr(value).and_then(varint_from_string) {
+        Some(bytes) => write_varint_to_buffer(&bytes, output, output_size, varint_size),
+        None => CassError::CASS_ERROR_LIB_BAD_PARAMS,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_varint_to_string(
+    varint: *const cass_byte_t,
+    varint_size: size_t,
+    output: *mut c_char,
+    output_size: size_t,
+    string_length: *mut size_t,
+) -> CassError {
+    let s = varint_to_string(varint_from_raw(varint, varint_size));
+    write_str_to_buffer(&s, output, output_size, string_length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_varint_from_int64(
+    value: cass_int64_t,
+    output: *mut cass_byte_t,
+    output_size: size_t,
+    varint_size: *mut size_t,
+) -> CassError {
+    write_varint_to_buffer(&varint_from_i64(value), output, output_size, varint_size)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_varint_to_int64(
+    varint: *const cass_byte_t,
+    varint_size: size_t,
+    output: *mut cass_int64_t,
+) -> CassError {
+    if output.is_null() {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+    match varint_to_i64(varint_from_raw(varint, varint_size)) {
+        Some(v) => {
+            std::ptr::write(output, v);
+            CassError::CASS_OK
+        }
+        None => CassError::CASS_ERROR_LIB_INVALID_DATA,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_decimal_from_string(
+    value: *const c_char,
+    varint: *mut cass_byte_t,
+    varint_buffer_size: size_t,
+    varint_size: *mut size_t,
+    scale: *mut cass_int32_t,
+) -> CassError {
+    if value.is_null() {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+    match ptr_to_cstr(value).and_then(decimal_from_string) {
+        Some(decimal) => {
+            write_decimal_to_buffer(&decimal, varint, varint_buffer_size, varint_size, scale)
+        }
+        None => CassError::CASS_ERROR_LIB_BAD_PARAMS,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_decimal_to_string(
+    varint: *const cass_byte_t,
+    varint_size: size_t,
+    scale: cass_int32_t,
+    output: *mut c_char,
+    output_size: size_t,
+    string_length: *mut size_t,
+) -> CassError {
+    let decimal = CqlDecimal::from_signed_be_bytes_slice_and_exponent(
+        varint_from_raw(varint, varint_size),
+        scale,
+    );
+    let s = decimal_to_string(&decimal);
+    write_str_to_buffer(&s, output, output_size, string_length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_decimal_from_int64(
+    value: cass_int64_t,
+    varint: *mut cass_byte_t,
+    varint_buffer_size: size_t,
+    varint_size: *mut size_t,
+    scale: *mut cass_int32_t,
+) -> CassError {
+    let decimal = decimal_from_i64(value);
+    write_decimal_to_buffer(&decimal, varint, varint_buffer_size, varint_size, scale)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_decimal_to_int64(
+    varint: *const cass_byte_t,
+    varint_size: size_t,
+    scale: cass_int32_t,
+    output: *mut cass_int64_t,
+) -> CassError {
+    if output.is_null() {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+    let decimal = CqlDecimal::from_signed_be_bytes_slice_and_exponent(
+        varint_from_raw(varint, varint_size),
+        scale,
+    );
+    match decimal_to_i64(&decimal) {
+        Some(v) => {
+            std::ptr::write(output, v);
+            CassError::CASS_OK
+        }
+        None => CassError::CASS_ERROR_LIB_INVALID_DATA,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_decimal_from_double(
+    value: cass_double_t,
+    varint: *mut cass_byte_t,
+    varint_buffer_size: size_t,
+    varint_size: *mut size_t,
+    scale: *mut cass_int32_t,
+) -> CassError {
+    match decimal_from_f64(value) {
+        Some(decimal) => {
+            write_decimal_to_buffer(&decimal, varint, varint_buffer_size, varint_size, scale)
+        }
+        None => CassError::CASS_ERROR_LIB_INVALID_DATA,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_decimal_to_double(
+    varint: *const cass_byte_t,
+    varint_size: size_t,
+    scale: cass_int32_t,
+    output: *mut cass_double_t,
+) -> CassError {
+    if output.is_null() {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+    let decimal = CqlDecimal::from_signed_be_bytes_slice_and_exponent(
+        varint_from_raw(varint, varint_size),
+        scale,
+    );
+    match decimal_to_f64(&decimal) {
+        Some(v) => {
+            std::ptr::write(output, v);
+            CassError::CASS_OK
+        }
+        None => CassError::CASS_ERROR_LIB_INVALID_DATA,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use scylla::frame::value::CqlDecimal;
+
+    use crate::argconv::ptr_to_cstr_n;
+    use crate::cass_error::CassError;
+    use crate::testing::assert_cass_error_eq;
+    use crate::types::size_t;
+
+    use super::{
+        cass_decimal_from_string, cass_decimal_to_double, cass_varint_to_string, decimal_from_f64,
+        decimal_from_string, decimal_to_f64, decimal_to_i64, decimal_to_string, varint_from_i64,
+        varint_from_string, varint_to_i64, varint_to_string,
+    };
+
+    fn decimal(unscaled: &[u8], scale: i32) -> CqlDecimal {
+        CqlDecimal::from_signed_be_bytes_slice_and_exponent(unscaled, scale)
+    }
+
+    #[test]
+    fn varint_to_string_test() {
+        let cases: &[(&[u8], &str)] = &[
+            (&[], "0"),
+            (&[0x00], "0"),
+            (&[0x01], "1"),
+            (&[0x7F], "127"),
+            (&[0x00, 0x80], "128"),
+            (&[0xFF], "-1"),
+            (&[0x80], "-128"),
+            (&[0xFF, 0x7F], "-129"),
+            (&[0x3B, 0x9A, 0xCA, 0x00], "1000000000"),
+            (
+                &[0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+                "9223372036854775807",
+            ),
+            (
+                &[0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+                "-9223372036854775808",
+            ),
+            (
+                &[0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+                "18446744073709551616",
+            ),
+        ];
+
+        for (bytes, expected) in cases {
+            assert_eq!(*expected, varint_to_string(bytes), "bytes: {:?}", bytes);
+        }
+    }
+
+    #[test]
+    fn decimal_to_string_test() {
+        let cases: &[(&[u8], i32, &str)] = &[
+            (&[0x00], 0, "0"),
+            (&[0x7B], 0, "123"),
+            (&[0x7B], 2, "1.23"),
+            (&[0x85], 2, "-1.23"),
+            (&[0x7B], 5, "0.00123"),
+            (&[0x7B], 9, "1.23E-7"),
+            (&[0x7B], -1, "1.23E+3"),
+            (&[0x01], -2, "1E+2"),
+        ];
+
+        for (unscaled, scale, expected) in cases {
+            let decimal = CqlDecimal::from_signed_be_bytes_slice_and_exponent(unscaled, *scale);
+            assert_eq!(*expected, decimal_to_string(&decimal));
+        }
+    }
+
+    #[test]
+    fn varint_from_string_test() {
+        let cases: &[(&str, &[u8])] = &[
+            ("0", &[0x00]),
+            ("-0", &[0x00]),
+            ("+1", &[0x01]),
+            ("127", &[0x7F]),
+            ("128", &[0x00, 0x80]),
+            ("-1", &[0xFF]),
+            ("-128", &[0x80]),
+            ("-129", &[0xFF, 0x7F]),
+            ("0001000000000", &[0x3B, 0x9A, 0xCA, 0x00]),
+            (
+                "18446744073709551616",
+                &[0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+            ),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(
+                Some(expected.to_vec()),
+                varint_from_string(s),
+                "string: {}",
+                s
+            );
+        }
+
+        for s in ["", "-", "+-1", "1.0", "12a", " 1"] {
+            assert_eq!(None, varint_from_string(s), "string: {:?}", s);
+        }
+
+        // Round trip through the string representation.
+        for s in [
+            "-98765432109876543210987654321",
+            "340282366920938463463374607431768211456",
+        ] {
+            assert_eq!(s, varint_to_string(&varint_from_string(s).unwrap()));
+        }
+    }
+
+    #[test]
+    fn varint_int64_test() {
+        for v in [0, 1, -1, 127, 128, -128, -129, i64::MAX, i64::MIN] {
+            let bytes = varint_from_i64(v);
+            assert_eq!(v.to_string(), varint_to_string(&bytes));
+            assert_eq!(Some(v), varint_to_i64(&bytes));
+        }
+
+        // Redundant sign extension bytes are accepted.
+        assert_eq!(Some(-1), varint_to_i64(&[0xFF; 12]));
+        assert_eq!(Some(0), varint_to_i64(&[]));
+        assert_eq!(
+            None,
+            varint_to_i64(&varint_from_string("9223372036854775808").unwrap())
+        );
+    }
+
+    #[test]
+    fn decimal_from_string_test() {
+        let cases: &[(&str, &[u8], i32)] = &[
+            ("0", &[0x00], 0),
+            ("123", &[0x7B], 0),
+            ("1.23", &[0x7B], 2),
+            ("-1.23", &[0x85], 2),
+            ("+.5", &[0x05], 1),
+            ("5.", &[0x05], 0),
+            ("0.00123", &[0x7B], 5),
+            ("1.23E-7", &[0x7B], 9),
+            ("1.23e+3", &[0x7B], -1),
+            ("1E2", &[0x01], -2),
+        ];
+        for (s, unscaled, scale) in cases {
+            assert_eq!(
+                Some(decimal(unscaled, *scale)),
+                decimal_from_string(s),
+                "string: {}",
+                s
+            );
+        }
+
+        for s in [
+            "",
+            ".",
+            "-",
+            "1.2.3",
+            "1e",
+            "e5",
+            "1e+",
+            "1e99999999999",
+            "1,5",
+        ] {
+            assert_eq!(None, decimal_from_string(s), "string: {:?}", s);
+        }
+
+        // Round trip through the string representation.
+        for s in [
+            "1.23E-7",
+            "-0.00123",
+            "1E+2",
+            "123456789012345678901234567890.5",
+        ] {
+            assert_eq!(s, decimal_to_string(&decimal_from_string(s).unwrap()));
+        }
+    }
+
+    #[test]
+    fn decimal_conversion_test() {
+        let to_i64 = |s: &str| decimal_to_i64(&decimal_from_string(s).unwrap());
+        assert_eq!(Some(123), to_i64("123"));
+        assert_eq!(Some(-123), to_i64("-123.000"));
+        assert_eq!(Some(1200), to_i64("1.2E3"));
+        assert_eq!(Some(0), to_i64("0.000"));
+        assert_eq!(Some(i64::MIN), to_i64("-9223372036854775808"));
+        assert_eq!(None, to_i64("1.5"));
+        assert_eq!(None, to_i64("9223372036854775808"));
+        assert_eq!(None, to_i64("1E+19"));
+
+        let to_f64 = |s: &str| decimal_to_f64(&decimal_from_string(s).unwrap());
+        assert_eq!(
+            Some(0.1),
+            to_f64("0.1000000000000000055511151231257827021181583404541015625")
+        );
+        assert_eq!(Some(-2.5), to_f64("-2.50"));
+        assert_eq!(
+            Some(2f64.powi(100)),
+            to_f64("1267650600228229401496703205376")
+        );
+        assert_eq!(Some(0.375), to_f64("3.75E-1"));
+        assert_eq!(Some(0.0), to_f64("0"));
+        // Converts to the nearest f64, which isn't exactly equal to the decimal.
+        assert_eq!(None, to_f64("0.1"));
+        assert_eq!(None, to_f64("1E+300"));
+        assert_eq!(None, to_f64("1E+400"));
+        assert_eq!(None, to_f64("1E-400"));
+
+        assert_eq!(decimal_from_f64(0.1), decimal_from_string("1E-1"));
+        assert_eq!(decimal_from_f64(-1234.5), decimal_from_string("-1.2345E3"));
+        assert_eq!(None, decimal_from_f64(f64::NAN));
+        assert_eq!(None, decimal_from_f64(f64::INFINITY));
+    }
+
+    #[test]
+    fn ffi_buffers_test() {
+        unsafe {
+            let mut varint = [0u8; 4];
+            let mut varint_size: size_t = 0;
+            let mut scale = 0;
+            assert_cass_error_eq!(
+                cass_decimal_from_string(
+                    c"-1.23".as_ptr(),
+                    varint.as_mut_ptr(),
+                    varint.len() as size_t,
+                    &mut varint_size,
+                    &mut scale
+                ),
+                CassError::CASS_OK
+            );
+            assert_eq!(&[0x85], &varint[..varint_size as usize]);
+            assert_eq!(2, scale);
+
+            assert_cass_error_eq!(
+                cass_decimal_from_string(
+                    c"1,5".as_ptr(),
+                    varint.as_mut_ptr(),
+                    varint.len() as size_t,
+                    &mut varint_size,
+                    &mut scale
+                ),
+                CassError::CASS_ERROR_LIB_BAD_PARAMS
+            );
+
+            let mut double = 0.0;
+            assert_cass_error_eq!(
+                cass_decimal_to_double(varint.as_ptr(), 1, 2, &mut double),
+                CassError::CASS_OK
+            );
+            assert_eq!(-1.23, double);
+
+            // The required size is reported even if the buffer is too small.
+            let mut output = [0 as std::os::raw::c_char; 3];
+            let mut length: size_t = 0;
+            let bytes = [0x00, 0x80];
+            assert_cass_error_eq!(
+                cass_varint_to_string(
+                    bytes.as_ptr(),
+                    bytes.len() as size_t,
+                    output.as_mut_ptr(),
+                    output.len() as size_t,
+                    &mut length
+                ),
+                CassError::CASS_ERROR_LIB_BAD_PARAMS
+            );
+            assert_eq!(3, length);
+
+            let mut output = [0 as std::os::raw::c_char; 4];
+            assert_cass_error_eq!(
+                cass_varint_to_string(
+                    bytes.as_ptr(),
+                    bytes.len() as size_t,
+                    output.as_mut_ptr(),
+                    output.len() as size_t,
+                    &mut length
+                ),
+                CassError::CASS_OK
+            );
+            assert_eq!(Some("128"), ptr_to_cstr_n(output.as_ptr(), length));
+        }
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
     cass_types::{get_column_type, CassDataType},
     query_result::CassResultMetadata,
     statement::{BoundPreparedStatement, BoundStatement, CassStatement},
-    types::size_t,
+    types::{cass_bool_t, cass_byte_t, size_t},
 };
 use scylla::prepared_statement::PreparedStatement;
 
@@ -19,6 +19,10 @@ pub struct CassPrepared {
     // Cached result metadata. Arc'ed since we want to share it
     // with result metadata after execution.
     pub result_metadata: Arc<CassResultMetadata>,
+
+    // Indexes of bound parameters which make up the partition key,
+    // in the partition key order.
+    pub partition_key_indexes: Vec<usize>,
     pub statement: PreparedStatement,
 }
 
@@ -44,9 +48,17 @@ impl CassPrepared {
             statement.get_result_set_col_specs(),
         ));
 
+        let mut pk_indexes = statement.get_variable_pk_indexes().to_vec();
+        pk_indexes.sort_unstable_by_key(|pk_index| pk_index.sequence);
+        let partition_key_indexes = pk_indexes
+            .iter()
+            .map(|pk_index| pk_index.index as usize)
+            .collect();
+
         Self {
             variable_col_data_types,
             result_metadata,
+            partition_key_indexes,
             statement,
         }
     }
@@ -101,6 +113,7 @@ pub unsafe extern "C" fn cass_prepared_bind(
         paging_enabled: false,
         request_timeout_ms: None,
         exec_profile: None,
+        record_execution_history: false,
     }))
 }
 
@@ -163,3 +176,222 @@ pub unsafe extern "C" fn cass_prepared_parameter_data_type_by_name_n(
         None => std::ptr::null(),
     }
 }
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_prepared_result_column_count(
+    prepared_raw: *const CassPrepared,
+) -> size_t {
+    let prepared = ArcFFI::as_ref(prepared_raw);
+
+    prepared.result_metadata.col_specs.len() as size_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_prepared_result_column_name(
+    prepared_raw: *const CassPrepared,
+    index: size_t,
+    name: *mut *const c_char,
+    name_length: *mut size_t,
+) -> CassError {
+    let prepared = ArcFFI::as_ref(prepared_raw);
+
+    match prepared.result_metadata.col_specs.get(index as usize) {
+        Some(col_spec) => {
+            write_str_to_c(&col_spec.name, name, name_length);
+            CassError::CASS_OK
+        }
+        None => CassError::CASS_ERROR_LIB_INDEX_OUT_OF_BOUNDS,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_prepared_result_column_data_type(
+    prepared_raw: *const CassPrepared,
+    index: size_t,
+) -> *const CassDataType {
+    let prepared = ArcFFI::as_ref(prepared_raw);
+
+    match prepared.result_metadata.col_specs.get(index as usize) {
+        Some(col_spec) => ArcFFI::as_ptr(&col_spec.data_type),
+        None => std::ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_prepared_id(
+    prepared_raw: *const CassPrepared,
+    id: *mut *const cass_byte_t,
+    id_length: *mut size_t,
+) {
+    let prepared = ArcFFI::as_ref(prepared_raw);
+    let prepared_id = prepared.statement.get_id();
+
+    *id = prepared_id.as_ptr();
+    *id_length = prepared_id.len() as size_t;
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_prepared_partition_key_index_count(
+    prepared_raw: *const CassPrepared,
+) -> size_t {
+    let prepared = ArcFFI::as_ref(prepared_raw);
+
+    prepared.partition_key_indexes.len() as size_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_prepared_partition_key_index(
+    prepared_raw: *const CassPrepared,
+    index: size_t,
+    parameter_index: *mut size_t,
+) -> CassError {
+    let prepared = ArcFFI::as_ref(prepared_raw);
+
+    match prepared.partition_key_indexes.get(index as usize) {
+        Some(pk_index) => {
+            *parameter_index = *pk_index as size_t;
+            CassError::CASS_OK
+        }
+        None => CassError::CASS_ERROR_LIB_INDEX_OUT_OF_BOUNDS,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_prepared_is_lwt(prepared_raw: *const CassPrepared) -> cass_bool_t {
+    let prepared = ArcFFI::as_ref(prepared_raw);
+
+    prepared.statement.is_confirmed_lwt() as cass_bool_t
+}
+
+#[cfg(test)]
+mod tests {
+    use scylla::frame::response::result::ColumnType;
+
+    use super::*;
+    use crate::cass_types::{cass_data_type_type, CassValueType};
+    use crate::session::tests::{prepare_with_forged_metadata, ForgedPrepared};
+    use crate::testing::assert_cass_error_eq;
+
+    unsafe fn result_column_name(prepared_raw: *const CassPrepared, index: size_t) -> &'static str {
+        let mut name: *const c_char = std::ptr::null();
+        let mut name_length: size_t = 0;
+        assert_cass_error_eq!(
+            cass_prepared_result_column_name(prepared_raw, index, &mut name, &mut name_length),
+            CassError::CASS_OK
+        );
+        ptr_to_cstr_n(name, name_length).unwrap()
+    }
+
+    unsafe fn partition_key_index(prepared_raw: *const CassPrepared, index: size_t) -> size_t {
+        let mut parameter_index: size_t = 0;
+        assert_cass_error_eq!(
+            cass_prepared_partition_key_index(prepared_raw, index, &mut parameter_index),
+            CassError::CASS_OK
+        );
+        parameter_index
+    }
+
+    #[tokio::test]
+    #[ntest::timeout(5000)]
+    async fn prepared_metadata() {
+        // `CREATE TABLE ks.tbl (pk1 bigint, pk2 text, ck int, v text, l list<int>,
+        //     PRIMARY KEY ((pk1, pk2), ck))`
+        let prepared = prepare_with_forged_metadata(
+            "SELECT v, l FROM ks.tbl WHERE ck = ? AND pk2 = ? AND pk1 = ?",
+            ForgedPrepared {
+                id: b"select_id",
+                variables: vec![
+                    ("ck", ColumnType::Int),
+                    ("pk2", ColumnType::Text),
+                    ("pk1", ColumnType::BigInt),
+                ],
+                // The partition key order differs from the order of the variables.
+                pk_indexes: vec![2, 1],
+                result_columns: vec![
+                    ("v", ColumnType::Text),
+                    ("l", ColumnType::List(Box::new(ColumnType::Int))),
+                ],
+                is_lwt: false,
+            },
+        )
+        .await;
+        let prepared_raw = ArcFFI::into_ptr(Arc::new(CassPrepared::new_from_prepared_statement(
+            prepared,
+        )));
+
+        unsafe {
+            assert_eq!(2, cass_prepared_result_column_count(prepared_raw));
+            assert_eq!("v", result_column_name(prepared_raw, 0));
+            assert_eq!("l", result_column_name(prepared_raw, 1));
+            let mut name: *const c_char = std::ptr::null();
+            let mut name_length: size_t = 0;
+            assert_cass_error_eq!(
+                cass_prepared_result_column_name(prepared_raw, 2, &mut name, &mut name_length),
+                CassError::CASS_ERROR_LIB_INDEX_OUT_OF_BOUNDS
+            );
+
+            assert_eq!(
+                CassValueType::CASS_VALUE_TYPE_TEXT,
+                cass_data_type_type(cass_prepared_result_column_data_type(prepared_raw, 0))
+            );
+            assert_eq!(
+                CassValueType::CASS_VALUE_TYPE_LIST,
+                cass_data_type_type(cass_prepared_result_column_data_type(prepared_raw, 1))
+            );
+            assert!(cass_prepared_result_column_data_type(prepared_raw, 2).is_null());
+
+            let mut id: *const cass_byte_t = std::ptr::null();
+            let mut id_length: size_t = 0;
+            cass_prepared_id(prepared_raw, &mut id, &mut id_length);
+            assert_eq!(
+                b"select_id",
+                std::slice::from_raw_parts(id, id_length as usize)
+            );
+
+            // The indexes are returned in the partition key order.
+            assert_eq!(2, cass_prepared_partition_key_index_count(prepared_raw));
+            assert_eq!(2, partition_key_index(prepared_raw, 0));
+            assert_eq!(1, partition_key_index(prepared_raw, 1));
+            let mut parameter_index: size_t = 0;
+            assert_cass_error_eq!(
+                cass_prepared_partition_key_index(prepared_raw, 2, &mut parameter_index),
+                CassError::CASS_ERROR_LIB_INDEX_OUT_OF_BOUNDS
+            );
+
+            assert_eq!(0, cass_prepared_is_lwt(prepared_raw));
+
+            cass_prepared_free(prepared_raw);
+        }
+    }
+
+    #[tokio::test]
+    #[ntest::timeout(5000)]
+    async fn prepared_lwt() {
+        let prepared = prepare_with_forged_metadata(
+            "UPDATE ks.tbl SET v = ? WHERE pk = ? IF v = ?",
+            ForgedPrepared {
+                id: b"update_id",
+                variables: vec![
+                    ("v", ColumnType::Text),
+                    ("pk", ColumnType::Int),
+                    ("v", ColumnType::Text),
+                ],
+                pk_indexes: vec![1],
+                result_columns: vec![("[applied]", ColumnType::Boolean)],
+                is_lwt: true,
+            },
+        )
+        .await;
+        let prepared_raw = ArcFFI::into_ptr(Arc::new(CassPrepared::new_from_prepared_statement(
+            prepared,
+        )));
+
+        unsafe {
+            assert_eq!(1, cass_prepared_is_lwt(prepared_raw));
+            assert_eq!(1, cass_prepared_partition_key_index_count(prepared_raw));
+            assert_eq!(1, partition_key_index(prepared_raw, 0));
+
+            cass_prepared_free(prepared_raw);
+        }
+    }
+}
@@ -4,12 +4,15 @@ use crate::cass_types::{
     cass_data_type_type, get_column_type, CassColumnSpec, CassDataType, CassDataTypeInner,
     CassValueType, MapDataType,
 };
+use crate::future::CassFuture;
 use crate::inet::CassInet;
 use crate::metadata::{
-    CassColumnMeta, CassKeyspaceMeta, CassMaterializedViewMeta, CassSchemaMeta, CassTableMeta,
+    CassAggregateMeta, CassColumnMeta, CassFunctionMeta, CassIndexMeta, CassKeyspaceMeta,
+    CassMaterializedViewMeta, CassMetaFields, CassSchemaMeta, CassTableMeta,
 };
 use crate::query_error::CassErrorResult;
 use crate::query_result::Value::{CollectionValue, RegularValue};
+use crate::request_history::{CassErrorDetail, CassExecutionAttempt};
 use crate::types::*;
 use crate::uuid::CassUuid;
 use scylla::frame::response::result::{ColumnSpec, CqlValue, Row};
@@ -17,7 +20,7 @@ use scylla::transport::query_result::{ColumnSpecs, IntoRowsResultError};
 use scylla::transport::PagingStateResponse;
 use scylla::QueryResult;
 use std::convert::TryInto;
-use std::os::raw::c_char;
+use std::os::raw::{c_char, c_void};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -35,6 +38,9 @@ pub struct CassResult {
     pub tracing_id: Option<Uuid>,
     pub paging_state_response: PagingStateResponse,
     pub kind: CassResultKind,
+    /// Whether the schema was in agreement after the statement was executed.
+    /// Always `true` for statements that do not change the schema.
+    pub schema_in_agreement: bool,
 }
 
 impl CassResult {
@@ -75,6 +81,7 @@ impl CassResult {
                         rows: cass_rows,
                         metadata,
                     }),
+                    schema_in_agreement: true,
                 };
 
                 Ok(cass_result)
@@ -84,6 +91,7 @@ impl CassResult {
                     tracing_id: result.tracing_id(),
                     paging_state_response,
                     kind: CassResultKind::NonRows,
+                    schema_in_agreement: true,
                 };
 
                 Ok(cass_result)
@@ -201,7 +209,7 @@ fn create_cass_row_columns(row: Row, metadata: &Arc<CassResultMetadata>) -> Vec<
         .collect()
 }
 
-fn get_column_value(column: CqlValue, column_type: &Arc<CassDataType>) -> Value {
+pub(crate) fn get_column_value(column: CqlValue, column_type: &Arc<CassDataType>) -> Value {
     match (column, unsafe { column_type.get_unchecked() }) {
         (
             CqlValue::List(list),
@@ -347,12 +355,28 @@ pub struct CassTableMetaIterator {
     position: Option<usize>,
 }
 
+pub struct CassMetaFieldIterator {
+    value: &'static CassMetaFields,
+    count: usize,
+    position: Option<usize>,
+}
+
 pub struct CassViewMetaIterator {
     value: &'static CassMaterializedViewMeta,
     count: usize,
     position: Option<usize>,
 }
 
+pub struct CassErrorDetailIterator {
+    details: Vec<CassErrorDetail>,
+    position: Option<usize>,
+}
+
+pub struct CassExecutionAttemptIterator {
+    attempts: Vec<CassExecutionAttempt>,
+    position: Option<usize>,
+}
+
 pub enum CassIterator {
     CassResultIterator(CassResultIterator),
     CassRowIterator(CassRowIterator),
@@ -363,8 +387,14 @@ pub enum CassIterator {
     CassKeyspaceMetaTableIterator(CassKeyspaceMetaIterator),
     CassKeyspaceMetaUserTypeIterator(CassKeyspaceMetaIterator),
     CassKeyspaceMetaViewIterator(CassKeyspaceMetaIterator),
+    CassKeyspaceMetaFunctionIterator(CassKeyspaceMetaIterator),
+    CassKeyspaceMetaAggregateIterator(CassKeyspaceMetaIterator),
     CassTableMetaIterator(CassTableMetaIterator),
+    CassTableMetaIndexIterator(CassTableMetaIterator),
     CassViewMetaIterator(CassViewMetaIterator),
+    CassMetaFieldIterator(CassMetaFieldIterator),
+    CassErrorDetailIterator(CassErrorDetailIterator),
+    CassExecutionAttemptIterator(CassExecutionAttemptIterator),
 }
 
 impl BoxFFI for CassIterator {}
@@ -458,6 +488,24 @@ pub unsafe extern "C" fn cass_iterator_next(iterator: *mut CassIterator) -> cass
 
             (new_pos < keyspace_meta_iterator.count) as cass_bool_t
         }
+        CassIterator::CassKeyspaceMetaFunctionIterator(keyspace_meta_iterator) => {
+            let new_pos: usize = keyspace_meta_iterator
+                .position
+                .map_or(0, |prev_pos| prev_pos + 1);
+
+            keyspace_meta_iterator.position = Some(new_pos);
+
+            (new_pos < keyspace_meta_iterator.count) as cass_bool_t
+        }
+        CassIterator::CassKeyspaceMetaAggregateIterator(keyspace_meta_iterator) => {
+            let new_pos: usize = keyspace_meta_iterator
+                .position
+                .map_or(0, |prev_pos| prev_pos + 1);
+
+            keyspace_meta_iterator.position = Some(new_pos);
+
+            (new_pos < keyspace_meta_iterator.count) as cass_bool_t
+        }
         CassIterator::CassTableMetaIterator(table_iterator) => {
             let new_pos: usize = table_iterator.position.map_or(0, |prev_pos| prev_pos + 1);
 
@@ -465,6 +513,13 @@ pub unsafe extern "C" fn cass_iterator_next(iterator: *mut CassIterator) -> cass
 
             (new_pos < table_iterator.count) as cass_bool_t
         }
+        CassIterator::CassTableMetaIndexIterator(table_iterator) => {
+            let new_pos: usize = table_iterator.position.map_or(0, |prev_pos| prev_pos + 1);
+
+            table_iterator.position = Some(new_pos);
+
+            (new_pos < table_iterator.count) as cass_bool_t
+        }
         CassIterator::CassViewMetaIterator(view_iterator) => {
             let new_pos: usize = view_iterator.position.map_or(0, |prev_pos| prev_pos + 1);
 
@@ -472,6 +527,27 @@ pub unsafe extern "C" fn cass_iterator_next(iterator: *mut CassIterator) -> cass
 
             (new_pos < view_iterator.count) as cass_bool_t
         }
+        CassIterator::CassMetaFieldIterator(field_iterator) => {
+            let new_pos: usize = field_iterator.position.map_or(0, |prev_pos| prev_pos + 1);
+
+            field_iterator.position = Some(new_pos);
+
+            (new_pos < field_iterator.count) as cass_bool_t
+        }
+        CassIterator::CassErrorDetailIterator(detail_iterator) => {
+            let new_pos: usize = detail_iterator.position.map_or(0, |prev_pos| prev_pos + 1);
+
+            detail_iterator.position = Some(new_pos);
+
+            (new_pos < detail_iterator.details.len()) as cass_bool_t
+        }
+        CassIterator::CassExecutionAttemptIterator(attempt_iterator) => {
+            let new_pos: usize = attempt_iterator.position.map_or(0, |prev_pos| prev_pos + 1);
+
+            attempt_iterator.position = Some(new_pos);
+
+            (new_pos < attempt_iterator.attempts.len()) as cass_bool_t
+        }
     }
 }
 
@@ -763,6 +839,60 @@ pub unsafe extern "C" fn cass_iterator_get_user_type(
     std::ptr::null()
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_get_function_meta(
+    iterator: *const CassIterator,
+) -> *const CassFunctionMeta {
+    let iter = BoxFFI::as_ref(iterator);
+
+    if let CassIterator::CassKeyspaceMetaFunctionIterator(keyspace_meta_iterator) = iter {
+        let iter_position = match keyspace_meta_iterator.position {
+            Some(pos) => pos,
+            None => return std::ptr::null(),
+        };
+
+        let function_meta_entry_opt = keyspace_meta_iterator
+            .value
+            .functions
+            .iter()
+            .nth(iter_position);
+
+        return match function_meta_entry_opt {
+            Some(function_meta_entry) => RefFFI::as_ptr(function_meta_entry.1.as_ref()),
+            None => std::ptr::null(),
+        };
+    }
+
+    std::ptr::null()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_get_aggregate_meta(
+    iterator: *const CassIterator,
+) -> *const CassAggregateMeta {
+    let iter = BoxFFI::as_ref(iterator);
+
+    if let CassIterator::CassKeyspaceMetaAggregateIterator(keyspace_meta_iterator) = iter {
+        let iter_position = match keyspace_meta_iterator.position {
+            Some(pos) => pos,
+            None => return std::ptr::null(),
+        };
+
+        let aggregate_meta_entry_opt = keyspace_meta_iterator
+            .value
+            .aggregates
+            .iter()
+            .nth(iter_position);
+
+        return match aggregate_meta_entry_opt {
+            Some(aggregate_meta_entry) => RefFFI::as_ptr(aggregate_meta_entry.1.as_ref()),
+            None => std::ptr::null(),
+        };
+    }
+
+    std::ptr::null()
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_iterator_get_column_meta(
     iterator: *const CassIterator,
@@ -809,6 +939,76 @@ pub unsafe extern "C" fn cass_iterator_get_column_meta(
     }
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_get_index_meta(
+    iterator: *const CassIterator,
+) -> *const CassIndexMeta {
+    let iter = BoxFFI::as_ref(iterator);
+
+    if let CassIterator::CassTableMetaIndexIterator(table_meta_iterator) = iter {
+        let iter_position = match table_meta_iterator.position {
+            Some(pos) => pos,
+            None => return std::ptr::null(),
+        };
+
+        let index_meta_entry_opt = table_meta_iterator.value.indexes.iter().nth(iter_position);
+
+        return match index_meta_entry_opt {
+            Some(index_meta_entry) => RefFFI::as_ptr(index_meta_entry.1),
+            None => std::ptr::null(),
+        };
+    }
+
+    std::ptr::null()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_get_meta_field_name(
+    iterator: *const CassIterator,
+    name: *mut *const c_char,
+    name_length: *mut size_t,
+) -> CassError {
+    let iter = BoxFFI::as_ref(iterator);
+
+    if let CassIterator::CassMetaFieldIterator(field_iterator) = iter {
+        let iter_position = match field_iterator.position {
+            Some(pos) => pos,
+            None => return CassError::CASS_ERROR_LIB_BAD_PARAMS,
+        };
+
+        return match field_iterator.value.nth(iter_position) {
+            Some((field_name, _)) => {
+                write_str_to_c(field_name, name, name_length);
+                CassError::CASS_OK
+            }
+            None => CassError::CASS_ERROR_LIB_BAD_PARAMS,
+        };
+    }
+
+    CassError::CASS_ERROR_LIB_BAD_PARAMS
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_get_meta_field_value(
+    iterator: *const CassIterator,
+) -> *const CassValue {
+    let iter = BoxFFI::as_ref(iterator);
+
+    if let CassIterator::CassMetaFieldIterator(field_iterator) = iter {
+        let iter_position = match field_iterator.position {
+            Some(pos) => pos,
+            None => return std::ptr::null(),
+        };
+
+        return match field_iterator.value.nth(iter_position) {
+            Some((_, value)) => RefFFI::as_ptr(value),
+            None => std::ptr::null(),
+        };
+    }
+
+    std::ptr::null()
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_iterator_get_materialized_view_meta(
     iterator: *const CassIterator,
@@ -846,6 +1046,48 @@ pub unsafe extern "C" fn cass_iterator_get_materialized_view_meta(
     }
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_get_error_detail(
+    iterator: *const CassIterator,
+) -> *const CassErrorDetail {
+    let iter = BoxFFI::as_ref(iterator);
+
+    if let CassIterator::CassErrorDetailIterator(detail_iterator) = iter {
+        let iter_position = match detail_iterator.position {
+            Some(pos) => pos,
+            None => return std::ptr::null(),
+        };
+
+        return match detail_iterator.details.get(iter_position) {
+            Some(detail) => RefFFI::as_ptr(detail),
+            None => std::ptr::null(),
+        };
+    }
+
+    std::ptr::null()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_get_execution_attempt(
+    iterator: *const CassIterator,
+) -> *const CassExecutionAttempt {
+    let iter = BoxFFI::as_ref(iterator);
+
+    if let CassIterator::CassExecutionAttemptIterator(attempt_iterator) = iter {
+        let iter_position = match attempt_iterator.position {
+            Some(pos) => pos,
+            None => return std::ptr::null(),
+        };
+
+        return match attempt_iterator.attempts.get(iter_position) {
+            Some(attempt) => RefFFI::as_ptr(attempt),
+            None => std::ptr::null(),
+        };
+    }
+
+    std::ptr::null()
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_iterator_from_result(result: *const CassResult) -> *mut CassIterator {
     let result_from_raw = ArcFFI::cloned_from_ptr(result);
@@ -858,6 +1100,36 @@ pub unsafe extern "C" fn cass_iterator_from_result(result: *const CassResult) ->
     BoxFFI::into_ptr(Box::new(CassIterator::CassResultIterator(iterator)))
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_error_details_from_future(
+    future: *const CassFuture,
+) -> *mut CassIterator {
+    let future_from_raw = ArcFFI::as_ref(future);
+
+    let iterator = CassErrorDetailIterator {
+        details: future_from_raw.error_details(),
+        position: None,
+    };
+
+    BoxFFI::into_ptr(Box::new(CassIterator::CassErrorDetailIterator(iterator)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_execution_history_from_future(
+    future: *const CassFuture,
+) -> *mut CassIterator {
+    let future_from_raw = ArcFFI::as_ref(future);
+
+    let iterator = CassExecutionAttemptIterator {
+        attempts: future_from_raw.execution_history(),
+        position: None,
+    };
+
+    BoxFFI::into_ptr(Box::new(CassIterator::CassExecutionAttemptIterator(
+        iterator,
+    )))
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_iterator_from_row(row: *const CassRow) -> *mut CassIterator {
     let row_from_raw = RefFFI::as_ref(row);
@@ -956,7 +1228,7 @@ pub unsafe extern "C" fn cass_iterator_fields_from_user_type(
 pub unsafe extern "C" fn cass_iterator_keyspaces_from_schema_meta(
     schema_meta: *const CassSchemaMeta,
 ) -> *mut CassIterator {
-    let metadata = BoxFFI::as_ref(schema_meta);
+    let metadata = ArcFFI::as_ref(schema_meta);
 
     let iterator = CassSchemaMetaIterator {
         value: metadata,
@@ -1018,6 +1290,40 @@ pub unsafe extern "C" fn cass_iterator_user_types_from_keyspace_meta(
     )))
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_functions_from_keyspace_meta(
+    keyspace_meta: *const CassKeyspaceMeta,
+) -> *mut CassIterator {
+    let metadata = RefFFI::as_ref(keyspace_meta);
+
+    let iterator = CassKeyspaceMetaIterator {
+        value: metadata,
+        count: metadata.functions.len(),
+        position: None,
+    };
+
+    BoxFFI::into_ptr(Box::new(CassIterator::CassKeyspaceMetaFunctionIterator(
+        iterator,
+    )))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_aggregates_from_keyspace_meta(
+    keyspace_meta: *const CassKeyspaceMeta,
+) -> *mut CassIterator {
+    let metadata = RefFFI::as_ref(keyspace_meta);
+
+    let iterator = CassKeyspaceMetaIterator {
+        value: metadata,
+        count: metadata.aggregates.len(),
+        position: None,
+    };
+
+    BoxFFI::into_ptr(Box::new(CassIterator::CassKeyspaceMetaAggregateIterator(
+        iterator,
+    )))
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_iterator_columns_from_table_meta(
     table_meta: *const CassTableMeta,
@@ -1033,6 +1339,133 @@ pub unsafe extern "C" fn cass_iterator_columns_from_table_meta(
     BoxFFI::into_ptr(Box::new(CassIterator::CassTableMetaIterator(iterator)))
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_indexes_from_table_meta(
+    table_meta: *const CassTableMeta,
+) -> *mut CassIterator {
+    let metadata = RefFFI::as_ref(table_meta);
+
+    let iterator = CassTableMetaIterator {
+        value: metadata,
+        count: metadata.indexes.len(),
+        position: None,
+    };
+
+    BoxFFI::into_ptr(Box::new(CassIterator::CassTableMetaIndexIterator(iterator)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_fields_from_keyspace_meta(
+    keyspace_meta: *const CassKeyspaceMeta,
+) -> *mut CassIterator {
+    let keyspace_meta = RefFFI::as_ref(keyspace_meta);
+    let fields = &keyspace_meta.fields;
+
+    let iterator = CassMetaFieldIterator {
+        value: fields,
+        count: fields.len(),
+        position: None,
+    };
+
+    BoxFFI::into_ptr(Box::new(CassIterator::CassMetaFieldIterator(iterator)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_fields_from_table_meta(
+    table_meta: *const CassTableMeta,
+) -> *mut CassIterator {
+    let table_meta = RefFFI::as_ref(table_meta);
+    let fields = &table_meta.fields;
+
+    let iterator = CassMetaFieldIterator {
+        value: fields,
+        count: fields.len(),
+        position: None,
+    };
+
+    BoxFFI::into_ptr(Box::new(CassIterator::CassMetaFieldIterator(iterator)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_fields_from_materialized_view_meta(
+    view_meta: *const CassMaterializedViewMeta,
+) -> *mut CassIterator {
+    let view_meta = RefFFI::as_ref(view_meta);
+    let fields = &view_meta.view_metadata.fields;
+
+    let iterator = CassMetaFieldIterator {
+        value: fields,
+        count: fields.len(),
+        position: None,
+    };
+
+    BoxFFI::into_ptr(Box::new(CassIterator::CassMetaFieldIterator(iterator)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_fields_from_column_meta(
+    column_meta: *const CassColumnMeta,
+) -> *mut CassIterator {
+    let column_meta = RefFFI::as_ref(column_meta);
+    let fields = &column_meta.fields;
+
+    let iterator = CassMetaFieldIterator {
+        value: fields,
+        count: fields.len(),
+        position: None,
+    };
+
+    BoxFFI::into_ptr(Box::new(CassIterator::CassMetaFieldIterator(iterator)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_fields_from_index_meta(
+    index_meta: *const CassIndexMeta,
+) -> *mut CassIterator {
+    let index_meta = RefFFI::as_ref(index_meta);
+    let fields = &index_meta.fields;
+
+    let iterator = CassMetaFieldIterator {
+        value: fields,
+        count: fields.len(),
+        position: None,
+    };
+
+    BoxFFI::into_ptr(Box::new(CassIterator::CassMetaFieldIterator(iterator)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_fields_from_function_meta(
+    function_meta: *const CassFunctionMeta,
+) -> *mut CassIterator {
+    let function_meta = RefFFI::as_ref(function_meta);
+    let fields = &function_meta.fields;
+
+    let iterator = CassMetaFieldIterator {
+        value: fields,
+        count: fields.len(),
+        position: None,
+    };
+
+    BoxFFI::into_ptr(Box::new(CassIterator::CassMetaFieldIterator(iterator)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_iterator_fields_from_aggregate_meta(
+    aggregate_meta: *const CassAggregateMeta,
+) -> *mut CassIterator {
+    let aggregate_meta = RefFFI::as_ref(aggregate_meta);
+    let fields = &aggregate_meta.fields;
+
+    let iterator = CassMetaFieldIterator {
+        value: fields,
+        count: fields.len(),
+        position: None,
+    };
+
+    BoxFFI::into_ptr(Box::new(CassIterator::CassMetaFieldIterator(iterator)))
+}
+
 pub unsafe extern "C" fn cass_iterator_materialized_views_from_table_meta(
     table_meta: *const CassTableMeta,
 ) -> *mut CassIterator {
@@ -1568,6 +2001,35 @@ pub unsafe extern "C" fn cass_result_column_count(result_raw: *const CassResult)
     metadata.col_specs.len() as size_t
 }
 
+/// Name of the column the server uses to report the outcome of a conditional statement.
+const LWT_APPLIED_COLUMN_NAME: &str = "[applied]";
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_result_lwt_applied(result_raw: *const CassResult) -> cass_bool_t {
+    let result = ArcFFI::as_ref(result_raw);
+
+    // Non-conditional statements are always applied.
+    let CassResultKind::Rows(CassRowsResult { rows, metadata }) = &result.kind else {
+        return true as cass_bool_t;
+    };
+    let Some(index) = metadata
+        .col_specs
+        .iter()
+        .position(|col_spec| col_spec.name == LWT_APPLIED_COLUMN_NAME)
+    else {
+        return true as cass_bool_t;
+    };
+
+    // For batches, the server returns one row per conditional statement.
+    // All of them carry the same `[applied]` value.
+    let applied = rows
+        .first()
+        .and_then(|row| row.columns.get(index))
+        .map(|value| matches!(value.value, Some(RegularValue(CqlValue::Boolean(true)))));
+
+    applied.unwrap_or(true) as cass_bool_t
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_result_first_row(result_raw: *const CassResult) -> *const CassRow {
     let result = ArcFFI::as_ref(result_raw);
@@ -1613,12 +2075,329 @@ pub unsafe extern "C" fn cass_result_paging_state_token(
     CassError::CASS_OK
 }
 
+/// Returns the rows of the result along with the value type of the column at `index`.
+/// Used by the columnar extraction functions below.
+unsafe fn result_column<'a>(
+    result: *const CassResult,
+    index: size_t,
+) -> Result<(&'a [CassRow], CassValueType), CassError> {
+    let result_from_raw = ArcFFI::as_ref(result);
+
+    let CassResultKind::Rows(CassRowsResult { rows, metadata }) = &result_from_raw.kind else {
+        return Err(CassError::CASS_ERROR_LIB_INDEX_OUT_OF_BOUNDS);
+    };
+
+    let col_spec = metadata
+        .col_specs
+        .get(index as usize)
+        .ok_or(CassError::CASS_ERROR_LIB_INDEX_OUT_OF_BOUNDS)?;
+
+    Ok((rows, col_spec.data_type.get_unchecked().get_value_type()))
+}
+
+/// Size in bytes of a single element of a fixed-width column of given type.
+fn fixed_width_of(value_type: CassValueType) -> Option<usize> {
+    let width = match value_type {
+        CassValueType::CASS_VALUE_TYPE_TINY_INT => std::mem::size_of::<cass_int8_t>(),
+        CassValueType::CASS_VALUE_TYPE_SMALL_INT => std::mem::size_of::<cass_int16_t>(),
+        CassValueType::CASS_VALUE_TYPE_INT => std::mem::size_of::<cass_int32_t>(),
+        CassValueType::CASS_VALUE_TYPE_DATE => std::mem::size_of::<cass_uint32_t>(),
+        CassValueType::CASS_VALUE_TYPE_BIGINT
+        | CassValueType::CASS_VALUE_TYPE_COUNTER
+        | CassValueType::CASS_VALUE_TYPE_TIME
+        | CassValueType::CASS_VALUE_TYPE_TIMESTAMP => std::mem::size_of::<cass_int64_t>(),
+        CassValueType::CASS_VALUE_TYPE_FLOAT => std::mem::size_of::<cass_float_t>(),
+        CassValueType::CASS_VALUE_TYPE_DOUBLE => std::mem::size_of::<cass_double_t>(),
+        CassValueType::CASS_VALUE_TYPE_UUID | CassValueType::CASS_VALUE_TYPE_TIMEUUID => {
+            std::mem::size_of::<CassUuid>()
+        }
+        _ => return None,
+    };
+
+    Some(width)
+}
+
+/// Returns the bytes of a variable-width (text or blob) value.
+fn variable_width_bytes(value: &CqlValue) -> Option<&[u8]> {
+    match value {
+        CqlValue::Ascii(s) | CqlValue::Text(s) => Some(s.as_bytes()),
+        CqlValue::Blob(b) => Some(b.as_slice()),
+        _ => None,
+    }
+}
+
+/// Writes an Arrow-compatible validity bitmap (LSB bit order, bit set means non-null)
+/// for the column at `index`. Null `validity` pointer means that the caller is not interested
+/// in the bitmap.
+unsafe fn write_validity_bitmap(
+    rows: &[CassRow],
+    index: usize,
+    validity: *mut cass_uint8_t,
+    validity_size: size_t,
+) -> Result<(), CassError> {
+    if validity.is_null() {
+        return Ok(());
+    }
+
+    let bitmap_len = rows.len().div_ceil(8);
+    if (validity_size as usize) < bitmap_len {
+        return Err(CassError::CASS_ERROR_LIB_BAD_PARAMS);
+    }
+
+    let bitmap = std::slice::from_raw_parts_mut(validity, bitmap_len);
+    bitmap.fill(0);
+    for (row_index, row) in rows.iter().enumerate() {
+        if row.columns[index].value.is_some() {
+            bitmap[row_index / 8] |= 1 << (row_index % 8);
+        }
+    }
+
+    Ok(())
+}
+
+/// Copies the column at `index` into `values` interpreted as an array of `T`.
+/// Null values are written as zeroed `T`.
+unsafe fn copy_fixed_width_column<T: Copy>(
+    rows: &[CassRow],
+    index: usize,
+    values: *mut c_void,
+    extract: impl Fn(&CqlValue) -> Option<T>,
+) -> Result<(), CassError> {
+    let values = values as *mut T;
+
+    for (row_index, row) in rows.iter().enumerate() {
+        let value = match &row.columns[index].value {
+            Some(Value::RegularValue(value)) => {
+                extract(value).ok_or(CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE)?
+            }
+            Some(Value::CollectionValue(_)) => {
+                return Err(CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE)
+            }
+            // SAFETY: All of the types we copy are plain numeric types (or structs of them),
+            // for which all-zero bit pattern is a valid value.
+            None => std::mem::zeroed(),
+        };
+        // Caller provided buffer is not guaranteed to be aligned to `T`.
+        std::ptr::write_unaligned(values.add(row_index), value);
+    }
+
+    Ok(())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_result_column_fixed_width(
+    result: *const CassResult,
+    index: size_t,
+    width: *mut size_t,
+) -> CassError {
+    let (_, value_type) = match result_column(result, index) {
+        Ok(column) => column,
+        Err(e) => return e,
+    };
+
+    match fixed_width_of(value_type) {
+        Some(w) => {
+            std::ptr::write(width, w as size_t);
+            CassError::CASS_OK
+        }
+        None => CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_result_column_copy_fixed(
+    result: *const CassResult,
+    index: size_t,
+    values: *mut c_void,
+    values_size: size_t,
+    validity: *mut cass_uint8_t,
+    validity_size: size_t,
+) -> CassError {
+    let (rows, value_type) = match result_column(result, index) {
+        Ok(column) => column,
+        Err(e) => return e,
+    };
+    let index = index as usize;
+
+    let Some(width) = fixed_width_of(value_type) else {
+        return CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE;
+    };
+    if values.is_null() || (values_size as usize) < rows.len() * width {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+
+    if let Err(e) = write_validity_bitmap(rows, index, validity, validity_size) {
+        return e;
+    }
+
+    let copy_result = match value_type {
+        CassValueType::CASS_VALUE_TYPE_TINY_INT => {
+            copy_fixed_width_column(rows, index, values, |v| match v {
+                CqlValue::TinyInt(i) => Some(*i),
+                _ => None,
+            })
+        }
+        CassValueType::CASS_VALUE_TYPE_SMALL_INT => {
+            copy_fixed_width_column(rows, index, values, |v| match v {
+                CqlValue::SmallInt(i) => Some(*i),
+                _ => None,
+            })
+        }
+        CassValueType::CASS_VALUE_TYPE_INT => {
+            copy_fixed_width_column(rows, index, values, |v| match v {
+                CqlValue::Int(i) => Some(*i),
+                _ => None,
+            })
+        }
+        CassValueType::CASS_VALUE_TYPE_DATE => {
+            copy_fixed_width_column(rows, index, values, |v| match v {
+                CqlValue::Date(d) => Some(d.0),
+                _ => None,
+            })
+        }
+        CassValueType::CASS_VALUE_TYPE_BIGINT
+        | CassValueType::CASS_VALUE_TYPE_COUNTER
+        | CassValueType::CASS_VALUE_TYPE_TIME
+        | CassValueType::CASS_VALUE_TYPE_TIMESTAMP => {
+            copy_fixed_width_column(rows, index, values, |v| match v {
+                CqlValue::BigInt(i) => Some(*i),
+                CqlValue::Counter(c) => Some(c.0),
+                CqlValue::Time(t) => Some(t.0),
+                CqlValue::Timestamp(t) => Some(t.0),
+                _ => None,
+            })
+        }
+        CassValueType::CASS_VALUE_TYPE_FLOAT => {
+            copy_fixed_width_column(rows, index, values, |v| match v {
+                CqlValue::Float(f) => Some(*f),
+                _ => None,
+            })
+        }
+        CassValueType::CASS_VALUE_TYPE_DOUBLE => {
+            copy_fixed_width_column(rows, index, values, |v| match v {
+                CqlValue::Double(d) => Some(*d),
+                _ => None,
+            })
+        }
+        CassValueType::CASS_VALUE_TYPE_UUID | CassValueType::CASS_VALUE_TYPE_TIMEUUID => {
+            copy_fixed_width_column(rows, index, values, |v| match v {
+                CqlValue::Uuid(uuid) => Some(CassUuid::from(*uuid)),
+                CqlValue::Timeuuid(uuid) => Some(CassUuid::from(Into::<Uuid>::into(*uuid))),
+                _ => None,
+            })
+        }
+        _ => Err(CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE),
+    };
+
+    match copy_result {
+        Ok(()) => CassError::CASS_OK,
+        Err(e) => e,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_result_column_data_size(
+    result: *const CassResult,
+    index: size_t,
+    data_size: *mut size_t,
+) -> CassError {
+    let (rows, _) = match result_column(result, index) {
+        Ok(column) => column,
+        Err(e) => return e,
+    };
+    let index = index as usize;
+
+    let mut total_size: usize = 0;
+    for row in rows {
+        match &row.columns[index].value {
+            Some(Value::RegularValue(value)) => match variable_width_bytes(value) {
+                Some(bytes) => total_size += bytes.len(),
+                None => return CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+            },
+            Some(Value::CollectionValue(_)) => return CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+            None => (),
+        }
+    }
+
+    std::ptr::write(data_size, total_size as size_t);
+    CassError::CASS_OK
+}
+
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn cass_result_column_copy_variable(
+    result: *const CassResult,
+    index: size_t,
+    offsets: *mut cass_int32_t,
+    offsets_count: size_t,
+    data: *mut cass_byte_t,
+    data_size: size_t,
+    validity: *mut cass_uint8_t,
+    validity_size: size_t,
+) -> CassError {
+    let (rows, value_type) = match result_column(result, index) {
+        Ok(column) => column,
+        Err(e) => return e,
+    };
+    let index = index as usize;
+
+    if !matches!(
+        value_type,
+        CassValueType::CASS_VALUE_TYPE_ASCII
+            | CassValueType::CASS_VALUE_TYPE_TEXT
+            | CassValueType::CASS_VALUE_TYPE_VARCHAR
+            | CassValueType::CASS_VALUE_TYPE_BLOB
+    ) {
+        return CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE;
+    }
+
+    let mut required_data_size: size_t = 0;
+    let rc = cass_result_column_data_size(result, index as size_t, &mut required_data_size);
+    if rc != CassError::CASS_OK {
+        return rc;
+    }
+
+    // Offsets are 32-bit, as in Arrow's (non-large) Utf8 and Binary layouts.
+    if offsets.is_null()
+        || (offsets_count as usize) < rows.len() + 1
+        || data_size < required_data_size
+        || (required_data_size > 0 && data.is_null())
+        || required_data_size > cass_int32_t::MAX as size_t
+    {
+        return CassError::CASS_ERROR_LIB_BAD_PARAMS;
+    }
+
+    if let Err(e) = write_validity_bitmap(rows, index, validity, validity_size) {
+        return e;
+    }
+
+    let offsets = std::slice::from_raw_parts_mut(offsets, rows.len() + 1);
+    let mut position: usize = 0;
+    offsets[0] = 0;
+    for (row_index, row) in rows.iter().enumerate() {
+        // Types were already verified by `cass_result_column_data_size`.
+        if let Some(Value::RegularValue(value)) = &row.columns[index].value {
+            let bytes = variable_width_bytes(value).unwrap();
+            if !bytes.is_empty() {
+                std::ptr::copy_nonoverlapping(bytes.as_ptr(), data.add(position), bytes.len());
+                position += bytes.len();
+            }
+        }
+        offsets[row_index + 1] = position as cass_int32_t;
+    }
+
+    CassError::CASS_OK
+}
+
 #[cfg(test)]
 mod tests {
-    use std::{ffi::c_char, ptr::addr_of_mut, sync::Arc};
+    use std::{
+        ffi::{c_char, c_void},
+        ptr::addr_of_mut,
+    };
 
     use scylla::{
-        frame::response::result::{ColumnSpec, ColumnType, CqlValue, Row, TableSpec},
+        frame::response::result::{ColumnType, CqlValue},
         transport::PagingStateResponse,
     };
 
@@ -1628,52 +2407,42 @@ mod tests {
         cass_types::{CassDataType, CassDataTypeInner, CassValueType},
         query_result::{
             cass_result_column_data_type, cass_result_column_name, cass_result_first_row,
-            ptr_to_cstr_n, size_t,
+            cass_result_lwt_applied, ptr_to_cstr_n, size_t,
         },
     };
 
     use super::{
-        cass_result_column_count, cass_result_column_type, create_cass_rows_from_rows, CassResult,
-        CassResultKind, CassResultMetadata, CassRowsResult,
+        cass_result_column_copy_fixed, cass_result_column_copy_variable, cass_result_column_count,
+        cass_result_column_data_size, cass_result_column_fixed_width, cass_result_column_type,
+        CassResult, CassResultKind,
     };
-
-    fn col_spec(name: &'static str, typ: ColumnType<'static>) -> ColumnSpec<'static> {
-        ColumnSpec::borrowed(name, typ, TableSpec::borrowed("ks", "tbl"))
-    }
+    use crate::testing::{assert_cass_error_eq, make_rows_result};
+    use crate::types::{cass_byte_t, cass_int32_t, cass_uint8_t};
+    use crate::uuid::CassUuid;
 
     const FIRST_COLUMN_NAME: &str = "bigint_col";
     const SECOND_COLUMN_NAME: &str = "varint_col";
     const THIRD_COLUMN_NAME: &str = "list_double_col";
     fn create_cass_rows_result() -> CassResult {
-        let metadata = Arc::new(CassResultMetadata::from_column_specs(&[
-            col_spec(FIRST_COLUMN_NAME, ColumnType::BigInt),
-            col_spec(SECOND_COLUMN_NAME, ColumnType::Varint),
-            col_spec(
-                THIRD_COLUMN_NAME,
-                ColumnType::List(Box::new(ColumnType::Double)),
-            ),
-        ]));
-
-        let rows = create_cass_rows_from_rows(
-            vec![Row {
-                columns: vec![
-                    Some(CqlValue::BigInt(42)),
-                    None,
-                    Some(CqlValue::List(vec![
-                        CqlValue::Float(0.5),
-                        CqlValue::Float(42.42),
-                        CqlValue::Float(9999.9999),
-                    ])),
-                ],
-            }],
-            &metadata,
-        );
-
-        CassResult {
-            tracing_id: None,
-            paging_state_response: PagingStateResponse::NoMorePages,
-            kind: CassResultKind::Rows(CassRowsResult { rows, metadata }),
-        }
+        make_rows_result(
+            &[
+                (FIRST_COLUMN_NAME, ColumnType::BigInt),
+                (SECOND_COLUMN_NAME, ColumnType::Varint),
+                (
+                    THIRD_COLUMN_NAME,
+                    ColumnType::List(Box::new(ColumnType::Double)),
+                ),
+            ],
+            vec![vec![
+                Some(CqlValue::BigInt(42)),
+                None,
+                Some(CqlValue::List(vec![
+                    CqlValue::Float(0.5),
+                    CqlValue::Float(42.42),
+                    CqlValue::Float(9999.9999),
+                ])),
+            ]],
+        )
     }
 
     unsafe fn cass_result_column_name_rust_str(
@@ -1765,11 +2534,177 @@ mod tests {
         }
     }
 
+    fn create_columnar_cass_result() -> CassResult {
+        let uuid = uuid::Uuid::from_u64_pair(1, 2);
+        make_rows_result(
+            &[
+                ("int_col", ColumnType::Int),
+                ("text_col", ColumnType::Text),
+                ("uuid_col", ColumnType::Uuid),
+                ("varint_col", ColumnType::Varint),
+            ],
+            vec![
+                vec![
+                    Some(CqlValue::Int(1)),
+                    Some(CqlValue::Text("foo".to_owned())),
+                    Some(CqlValue::Uuid(uuid)),
+                    None,
+                ],
+                vec![None, None, None, None],
+                vec![
+                    Some(CqlValue::Int(3)),
+                    Some(CqlValue::Text("".to_owned())),
+                    None,
+                    None,
+                ],
+                vec![
+                    Some(CqlValue::Int(-4)),
+                    Some(CqlValue::Text("barbaz".to_owned())),
+                    None,
+                    None,
+                ],
+            ],
+        )
+    }
+
+    #[test]
+    fn columnar_copy_test() {
+        let result = create_columnar_cass_result();
+
+        unsafe {
+            let result_ptr = std::ptr::addr_of!(result);
+
+            // Fixed-width column.
+            {
+                let mut width: size_t = 0;
+                assert_cass_error_eq!(
+                    cass_result_column_fixed_width(result_ptr, 0, addr_of_mut!(width)),
+                    CassError::CASS_OK
+                );
+                assert_eq!(4, width);
+
+                let mut values = [0 as cass_int32_t; 4];
+                let mut validity = [0xFF as cass_uint8_t; 1];
+
+                // Buffer too small.
+                assert_cass_error_eq!(
+                    cass_result_column_copy_fixed(
+                        result_ptr,
+                        0,
+                        values.as_mut_ptr() as *mut c_void,
+                        12,
+                        validity.as_mut_ptr(),
+                        1,
+                    ),
+                    CassError::CASS_ERROR_LIB_BAD_PARAMS
+                );
+
+                assert_cass_error_eq!(
+                    cass_result_column_copy_fixed(
+                        result_ptr,
+                        0,
+                        values.as_mut_ptr() as *mut c_void,
+                        16,
+                        validity.as_mut_ptr(),
+                        1,
+                    ),
+                    CassError::CASS_OK
+                );
+                assert_eq!([1, 0, 3, -4], values);
+                assert_eq!(0b1101, validity[0]);
+            }
+
+            // UUID column, without validity bitmap.
+            {
+                let mut values = [CassUuid {
+                    time_and_version: 0,
+                    clock_seq_and_node: 0,
+                }; 4];
+                assert_cass_error_eq!(
+                    cass_result_column_copy_fixed(
+                        result_ptr,
+                        2,
+                        values.as_mut_ptr() as *mut c_void,
+                        std::mem::size_of_val(&values) as size_t,
+                        std::ptr::null_mut(),
+                        0,
+                    ),
+                    CassError::CASS_OK
+                );
+                let expected: CassUuid = uuid::Uuid::from_u64_pair(1, 2).into();
+                assert_eq!(expected, values[0]);
+                assert_eq!(0, values[1].time_and_version);
+            }
+
+            // Variable-width column.
+            {
+                let mut data_size: size_t = 0;
+                assert_cass_error_eq!(
+                    cass_result_column_data_size(result_ptr, 1, addr_of_mut!(data_size)),
+                    CassError::CASS_OK
+                );
+                assert_eq!(9, data_size);
+
+                let mut offsets = [0 as cass_int32_t; 5];
+                let mut data = [0 as cass_byte_t; 9];
+                let mut validity = [0 as cass_uint8_t; 1];
+                assert_cass_error_eq!(
+                    cass_result_column_copy_variable(
+                        result_ptr,
+                        1,
+                        offsets.as_mut_ptr(),
+                        5,
+                        data.as_mut_ptr(),
+                        9,
+                        validity.as_mut_ptr(),
+                        1,
+                    ),
+                    CassError::CASS_OK
+                );
+                assert_eq!([0, 3, 3, 3, 9], offsets);
+                assert_eq!(b"foobarbaz", &data);
+                assert_eq!(0b1101, validity[0]);
+            }
+
+            // Unsupported types and bad indexes.
+            {
+                let mut width: size_t = 0;
+                assert_cass_error_eq!(
+                    cass_result_column_fixed_width(result_ptr, 1, addr_of_mut!(width)),
+                    CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE
+                );
+                assert_cass_error_eq!(
+                    cass_result_column_fixed_width(result_ptr, 3, addr_of_mut!(width)),
+                    CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE
+                );
+                assert_cass_error_eq!(
+                    cass_result_column_fixed_width(result_ptr, 4, addr_of_mut!(width)),
+                    CassError::CASS_ERROR_LIB_INDEX_OUT_OF_BOUNDS
+                );
+                let mut offsets = [0 as cass_int32_t; 5];
+                assert_cass_error_eq!(
+                    cass_result_column_copy_variable(
+                        result_ptr,
+                        0,
+                        offsets.as_mut_ptr(),
+                        5,
+                        std::ptr::null_mut(),
+                        0,
+                        std::ptr::null_mut(),
+                        0,
+                    ),
+                    CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE
+                );
+            }
+        }
+    }
+
     fn create_non_rows_cass_result() -> CassResult {
         CassResult {
             tracing_id: None,
             paging_state_response: PagingStateResponse::NoMorePages,
             kind: CassResultKind::NonRows,
+            schema_in_agreement: true,
         }
     }
 
@@ -1802,6 +2737,34 @@ mod tests {
             }
         }
     }
+
+    fn create_lwt_cass_result(applied: bool) -> CassResult {
+        make_rows_result(
+            &[("[applied]", ColumnType::Boolean), ("id", ColumnType::Int)],
+            vec![vec![
+                Some(CqlValue::Boolean(applied)),
+                Some(CqlValue::Int(1)),
+            ]],
+        )
+    }
+
+    #[test]
+    fn lwt_applied_test() {
+        unsafe {
+            let applied = create_lwt_cass_result(true);
+            assert_ne!(0, cass_result_lwt_applied(std::ptr::addr_of!(applied)));
+
+            let not_applied = create_lwt_cass_result(false);
+            assert_eq!(0, cass_result_lwt_applied(std::ptr::addr_of!(not_applied)));
+
+            // Results of non-conditional statements are always applied.
+            let non_rows = create_non_rows_cass_result();
+            assert_ne!(0, cass_result_lwt_applied(std::ptr::addr_of!(non_rows)));
+
+            let rows = create_cass_rows_result();
+            assert_ne!(0, cass_result_lwt_applied(std::ptr::addr_of!(rows)));
+        }
+    }
 }
 
 // CassResult functions:
@@ -0,0 +1,473 @@
+//! Records the attempts made on behalf of a request, by hooking into the driver's
+//! history listener mechanism. The attempts are exposed on the request's future
+//! as per-host error details, which tell apart e.g. unreachable hosts
+//! from hosts rejecting the credentials. If enabled for the statement, the whole
+//! execution history - including speculative fibers and retry decisions - is
+//! exposed as well.
+
+use crate::argconv::*;
+use crate::cass_error::{CassError, CassErrorMessage, ToCassError};
+use crate::cass_error_types::CassRetryDecision;
+use crate::cass_types::CassConsistency;
+use crate::types::*;
+use scylla::history::{AttemptId, HistoryListener, QueryId, SpeculativeId};
+use scylla::retry_policy::RetryDecision;
+use scylla::statement::Consistency;
+use scylla::transport::errors::QueryError;
+use std::net::SocketAddr;
+use std::os::raw::c_char;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Outcome of a single attempt, made to a single host.
+pub struct CassErrorDetail {
+    host: String,
+    error_code: CassError,
+    message: String,
+    latency: Duration,
+}
+
+impl RefFFI for CassErrorDetail {}
+
+pub struct CassExecutionAttempt {
+    host: String,
+    // 0 for the non-speculative fiber, n for the n-th speculative one.
+    speculative_fiber: usize,
+    // Since the start of the request.
+    start_offset: Duration,
+    latency: Duration,
+    error_code: CassError,
+    message: String,
+    retry_decision: CassRetryDecision,
+    retry_consistency: Option<Consistency>,
+}
+
+impl RefFFI for CassExecutionAttempt {}
+
+#[derive(Debug)]
+struct Attempt {
+    host: String,
+    speculative_fiber: usize,
+    start: Instant,
+    // `None` until the attempt completes.
+    outcome: Option<(Duration, Result<(), (CassError, String)>)>,
+    retry_decision: CassRetryDecision,
+    retry_consistency: Option<Consistency>,
+}
+
+#[derive(Debug, Default)]
+struct RequestHistoryState {
+    started_at: Option<Instant>,
+    speculative_fibers: usize,
+    attempts: Vec<Attempt>,
+    // Attempts, which are still in flight once the request completes
+    // (e.g. due to the request timeout), got no response in time.
+    finished_at: Option<Instant>,
+}
+
+impl RequestHistoryState {
+    fn attempt_outcome(&self, attempt: &Attempt) -> (Duration, CassError, String) {
+        let (latency, result) = match &attempt.outcome {
+            Some((latency, result)) => (*latency, result.clone()),
+            None => (
+                self.finished_at
+                    .unwrap_or_else(Instant::now)
+                    .saturating_duration_since(attempt.start),
+                Err((
+                    CassError::CASS_ERROR_LIB_REQUEST_TIMED_OUT,
+                    "No response received before the request completed".msg(),
+                )),
+            ),
+        };
+        let (error_code, message) = result.unwrap_or((CassError::CASS_OK, String::new()));
+
+        (latency, error_code, message)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RequestHistory {
+    state: Mutex<RequestHistoryState>,
+    // The attempts are recorded regardless, as they back the error details.
+    execution_history_enabled: bool,
+}
+
+impl RequestHistory {
+    pub(crate) fn new(execution_history_enabled: bool) -> Self {
+        Self {
+            state: Default::default(),
+            execution_history_enabled,
+        }
+    }
+
+    /// Records an attempt, which was made outside of the driver's request
+    /// execution, e.g. an attempt to connect to a contact point.
+    pub(crate) fn record_attempt(
+        &self,
+        host: String,
+        latency: Duration,
+        result: Result<(), (CassError, String)>,
+    ) {
+        self.state.lock().unwrap().attempts.push(Attempt {
+            host,
+            speculative_fiber: 0,
+            start: Instant::now(),
+            outcome: Some((latency, result)),
+            retry_decision: CassRetryDecision::CASS_RETRY_DECISION_NONE,
+            retry_consistency: None,
+        });
+    }
+
+    pub(crate) fn finish(&self) {
+        self.state
+            .lock()
+            .unwrap()
+            .finished_at
+            .get_or_insert_with(Instant::now);
+    }
+
+    pub(crate) fn error_details(&self) -> Vec<CassErrorDetail> {
+        let state = self.state.lock().unwrap();
+
+        state
+            .attempts
+            .iter()
+            .map(|attempt| {
+                let (latency, error_code, message) = state.attempt_outcome(attempt);
+
+                CassErrorDetail {
+                    host: attempt.host.clone(),
+                    error_code,
+                    message,
+                    latency,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the attempts, along with their speculative fibers and retry decisions,
+    /// if the execution history is enabled for the request.
+    pub(crate) fn execution_history(&self) -> Vec<CassExecutionAttempt> {
+        if !self.execution_history_enabled {
+            return Vec::new();
+        }
+
+        let state = self.state.lock().unwrap();
+
+        state
+            .attempts
+            .iter()
+            .map(|attempt| {
+                let (latency, error_code, message) = state.attempt_outcome(attempt);
+                let started_at = state.started_at.unwrap_or(attempt.start);
+
+                CassExecutionAttempt {
+                    host: attempt.host.clone(),
+                    speculative_fiber: attempt.speculative_fiber,
+                    start_offset: attempt.start.saturating_duration_since(started_at),
+                    latency,
+                    error_code,
+                    message,
+                    retry_decision: attempt.retry_decision,
+                    retry_consistency: attempt.retry_consistency,
+                }
+            })
+            .collect()
+    }
+
+    fn complete_attempt(
+        &self,
+        attempt_id: AttemptId,
+        result: Result<(), (CassError, String)>,
+        retry_decision: Option<&RetryDecision>,
+    ) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(attempt) = state.attempts.get_mut(attempt_id.0) {
+            attempt.outcome = Some((attempt.start.elapsed(), result));
+            (attempt.retry_decision, attempt.retry_consistency) = match retry_decision {
+                None => (CassRetryDecision::CASS_RETRY_DECISION_NONE, None),
+                Some(RetryDecision::RetrySameNode(consistency)) => (
+                    CassRetryDecision::CASS_RETRY_DECISION_RETRY_SAME_NODE,
+                    *consistency,
+                ),
+                Some(RetryDecision::RetryNextNode(consistency)) => (
+                    CassRetryDecision::CASS_RETRY_DECISION_RETRY_NEXT_NODE,
+                    *consistency,
+                ),
+                Some(RetryDecision::DontRetry) => {
+                    (CassRetryDecision::CASS_RETRY_DECISION_DONT_RETRY, None)
+                }
+                Some(RetryDecision::IgnoreWriteError) => (
+                    CassRetryDecision::CASS_RETRY_DECISION_IGNORE_WRITE_ERROR,
+                    None,
+                ),
+            };
+        }
+    }
+}
+
+impl HistoryListener for RequestHistory {
+    fn log_query_start(&self) -> QueryId {
+        self.state
+            .lock()
+            .unwrap()
+            .started_at
+            .get_or_insert_with(Instant::now);
+        QueryId(0)
+    }
+
+    fn log_query_success(&self, _query_id: QueryId) {}
+
+    fn log_query_error(&self, _query_id: QueryId, _error: &QueryError) {}
+
+    fn log_new_speculative_fiber(&self, _query_id: QueryId) -> SpeculativeId {
+        let mut state = self.state.lock().unwrap();
+        state.speculative_fibers += 1;
+        SpeculativeId(state.speculative_fibers - 1)
+    }
+
+    fn log_attempt_start(
+        &self,
+        _query_id: QueryId,
+        speculative_id: Option<SpeculativeId>,
+        node_addr: SocketAddr,
+    ) -> AttemptId {
+        let mut state = self.state.lock().unwrap();
+        state.attempts.push(Attempt {
+            host: node_addr.to_string(),
+            speculative_fiber: speculative_id.map_or(0, |id| id.0 + 1),
+            start: Instant::now(),
+            outcome: None,
+            retry_decision: CassRetryDecision::CASS_RETRY_DECISION_NONE,
+            retry_consistency: None,
+        });
+        AttemptId(state.attempts.len() - 1)
+    }
+
+    fn log_attempt_success(&self, attempt_id: AttemptId) {
+        self.complete_attempt(attempt_id, Ok(()), None);
+    }
+
+    fn log_attempt_error(
+        &self,
+        attempt_id: AttemptId,
+        error: &QueryError,
+        retry_decision: &RetryDecision,
+    ) {
+        self.complete_attempt(
+            attempt_id,
+            Err((error.to_cass_error(), error.msg())),
+            Some(retry_decision),
+        );
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_error_detail_host(
+    detail: *const CassErrorDetail,
+    host: *mut *const c_char,
+    host_length: *mut size_t,
+) {
+    write_str_to_c(&RefFFI::as_ref(detail).host, host, host_length);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_error_detail_error_code(detail: *const CassErrorDetail) -> CassError {
+    RefFFI::as_ref(detail).error_code
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_error_detail_message(
+    detail: *const CassErrorDetail,
+    message: *mut *const c_char,
+    message_length: *mut size_t,
+) {
+    write_str_to_c(&RefFFI::as_ref(detail).message, message, message_length);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_error_detail_latency(
+    detail: *const CassErrorDetail,
+) -> cass_uint64_t {
+    RefFFI::as_ref(detail).latency.as_micros() as cass_uint64_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_attempt_host(
+    attempt: *const CassExecutionAttempt,
+    host: *mut *const c_char,
+    host_length: *mut size_t,
+) {
+    write_str_to_c(&RefFFI::as_ref(attempt).host, host, host_length);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_attempt_speculative_fiber(
+    attempt: *const CassExecutionAttempt,
+) -> cass_uint32_t {
+    RefFFI::as_ref(attempt).speculative_fiber as cass_uint32_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_attempt_start_offset(
+    attempt: *const CassExecutionAttempt,
+) -> cass_uint64_t {
+    RefFFI::as_ref(attempt).start_offset.as_micros() as cass_uint64_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_attempt_latency(
+    attempt: *const CassExecutionAttempt,
+) -> cass_uint64_t {
+    RefFFI::as_ref(attempt).latency.as_micros() as cass_uint64_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_attempt_error_code(
+    attempt: *const CassExecutionAttempt,
+) -> CassError {
+    RefFFI::as_ref(attempt).error_code
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_attempt_message(
+    attempt: *const CassExecutionAttempt,
+    message: *mut *const c_char,
+    message_length: *mut size_t,
+) {
+    write_str_to_c(&RefFFI::as_ref(attempt).message, message, message_length);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_attempt_retry_decision(
+    attempt: *const CassExecutionAttempt,
+) -> CassRetryDecision {
+    RefFFI::as_ref(attempt).retry_decision
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_execution_attempt_retry_consistency(
+    attempt: *const CassExecutionAttempt,
+) -> CassConsistency {
+    match RefFFI::as_ref(attempt).retry_consistency {
+        Some(consistency) => CassConsistency::from(consistency),
+        None => CassConsistency::CASS_CONSISTENCY_UNKNOWN,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scylla::transport::errors::DbError;
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn request_history_error_details_test() {
+        let history = RequestHistory::default();
+        let node_addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 9042));
+
+        let failed = history.log_attempt_start(QueryId(0), None, node_addr);
+        history.log_attempt_error(
+            failed,
+            &QueryError::DbError(DbError::Overloaded, "Overloaded".to_owned()),
+            &RetryDecision::RetryNextNode(None),
+        );
+        let succeeded = history.log_attempt_start(QueryId(0), None, node_addr);
+        history.log_attempt_success(succeeded);
+        let _abandoned = history.log_attempt_start(QueryId(0), Some(SpeculativeId(0)), node_addr);
+        history.finish();
+        history.record_attempt(
+            "unresolvable:9042".to_owned(),
+            Duration::from_millis(5),
+            Err((
+                CassError::CASS_ERROR_LIB_HOST_RESOLUTION,
+                "Unable to resolve host".to_owned(),
+            )),
+        );
+
+        let details = history.error_details();
+        let codes: Vec<_> = details.iter().map(|detail| detail.error_code).collect();
+        assert_eq!(
+            codes,
+            [
+                CassError::CASS_ERROR_SERVER_OVERLOADED,
+                CassError::CASS_OK,
+                CassError::CASS_ERROR_LIB_REQUEST_TIMED_OUT,
+                CassError::CASS_ERROR_LIB_HOST_RESOLUTION,
+            ]
+        );
+        assert_eq!(details[0].host, "127.0.0.1:9042");
+        assert!(details[1].message.is_empty());
+        assert_eq!(details[3].host, "unresolvable:9042");
+        assert_eq!(details[3].latency, Duration::from_millis(5));
+
+        // Once finished, the latency of the abandoned attempt doesn't grow.
+        let latency = details[2].latency;
+        std::thread::sleep(Duration::from_millis(2));
+        assert_eq!(history.error_details()[2].latency, latency);
+    }
+
+    #[test]
+    fn request_history_execution_history_test() {
+        let node_addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 9042));
+        let log_attempts = |history: &RequestHistory| {
+            let query_id = history.log_query_start();
+            let retried = history.log_attempt_start(query_id, None, node_addr);
+            history.log_attempt_error(
+                retried,
+                &QueryError::DbError(DbError::Overloaded, "Overloaded".to_owned()),
+                &RetryDecision::RetrySameNode(Some(Consistency::One)),
+            );
+            let speculative_id = history.log_new_speculative_fiber(query_id);
+            let speculative = history.log_attempt_start(query_id, Some(speculative_id), node_addr);
+            history.log_attempt_success(speculative);
+            history.log_query_success(query_id);
+            history.finish();
+        };
+
+        // Disabled by default, while the attempts still back the error details.
+        let history = RequestHistory::default();
+        log_attempts(&history);
+        assert!(history.execution_history().is_empty());
+        assert_eq!(history.error_details().len(), 2);
+
+        let history = RequestHistory::new(true);
+        log_attempts(&history);
+        let attempts = history.execution_history();
+        assert_eq!(attempts.len(), 2);
+
+        unsafe {
+            assert_eq!(cass_execution_attempt_speculative_fiber(&attempts[0]), 0);
+            assert_eq!(
+                cass_execution_attempt_error_code(&attempts[0]),
+                CassError::CASS_ERROR_SERVER_OVERLOADED
+            );
+            assert_eq!(
+                cass_execution_attempt_retry_decision(&attempts[0]),
+                CassRetryDecision::CASS_RETRY_DECISION_RETRY_SAME_NODE
+            );
+            assert_eq!(
+                cass_execution_attempt_retry_consistency(&attempts[0]),
+                CassConsistency::CASS_CONSISTENCY_ONE
+            );
+
+            assert_eq!(cass_execution_attempt_speculative_fiber(&attempts[1]), 1);
+            assert_eq!(
+                cass_execution_attempt_error_code(&attempts[1]),
+                CassError::CASS_OK
+            );
+            assert_eq!(
+                cass_execution_attempt_retry_decision(&attempts[1]),
+                CassRetryDecision::CASS_RETRY_DECISION_NONE
+            );
+            assert_eq!(
+                cass_execution_attempt_retry_consistency(&attempts[1]),
+                CassConsistency::CASS_CONSISTENCY_UNKNOWN
+            );
+            assert!(
+                cass_execution_attempt_start_offset(&attempts[1])
+                    >= cass_execution_attempt_start_offset(&attempts[0])
+            );
+        }
+    }
+}
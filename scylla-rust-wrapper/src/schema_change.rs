@@ -0,0 +1,684 @@
+use crate::argconv::*;
+use crate::cass_error::CassError;
+use crate::cass_schema_change_types::{CassSchemaChangeTarget, CassSchemaChangeType};
+use crate::metadata::{AggregateRow, CassSchemaMeta, FunctionRow};
+use crate::session::{create_schema_meta, fetch_local_versions, SchemaRows};
+use crate::types::*;
+use crate::RUNTIME;
+use scylla::transport::topology::Keyspace;
+use scylla::transport::ClusterData;
+use scylla::Session;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::hash::Hash;
+use std::os::raw::{c_char, c_void};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+use uuid::Uuid;
+
+/// How often the schema refresh task checks whether the driver has refreshed its cluster metadata.
+const SCHEMA_CHANGE_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CassSchemaChangeEvent {
+    change_type: CassSchemaChangeType,
+    target: CassSchemaChangeTarget,
+    keyspace: String,
+    name: String,
+    argument_types: Vec<String>,
+}
+
+impl RefFFI for CassSchemaChangeEvent {}
+
+pub type CassSchemaChangeCallback =
+    Option<unsafe extern "C" fn(event: *const CassSchemaChangeEvent, data: *mut c_void)>;
+
+#[derive(Clone, Copy)]
+pub struct SchemaChangeListener {
+    cb: unsafe extern "C" fn(event: *const CassSchemaChangeEvent, data: *mut c_void),
+    data: *mut c_void,
+}
+
+// *mut c_void is not Send, so Rust will have to take our word
+// that the user data can be used from the driver's threads.
+unsafe impl Send for SchemaChangeListener {}
+
+impl SchemaChangeListener {
+    pub(crate) fn new(cb: CassSchemaChangeCallback, data: *mut c_void) -> Option<Self> {
+        cb.map(|cb| SchemaChangeListener { cb, data })
+    }
+
+    fn invoke(&self, event: &CassSchemaChangeEvent) {
+        unsafe { (self.cb)(event as *const CassSchemaChangeEvent, self.data) }
+    }
+}
+
+/// Functions and aggregates are keyed by their keyspace, name and argument types.
+type FunctionKey = (String, String, Vec<String>);
+
+/// Keyspace options, which are not (fully) described by the driver's metadata.
+#[derive(Debug, PartialEq)]
+struct KeyspaceOptions {
+    replication: Option<BTreeMap<String, String>>,
+    durable_writes: Option<bool>,
+    // Scylla's tablets options.
+    initial_tablets: Option<i32>,
+}
+
+/// The part of the schema which is compared to detect changes.
+struct SchemaSnapshot {
+    cluster_data: Arc<ClusterData>,
+    keyspace_options: HashMap<String, KeyspaceOptions>,
+    functions: HashMap<FunctionKey, FunctionRow>,
+    aggregates: HashMap<FunctionKey, AggregateRow>,
+}
+
+impl SchemaSnapshot {
+    /// Extracts the parts of the schema missing in the driver's metadata from the rows
+    /// of the schema tables.
+    fn new(cluster_data: Arc<ClusterData>, rows: &SchemaRows) -> Self {
+        let initial_tablets: HashMap<&str, i32> = rows
+            .get("system_schema.scylla_keyspaces")
+            .iter()
+            .filter_map(|fields| {
+                Some((
+                    fields.text("keyspace_name")?,
+                    fields.int("initial_tablets")?,
+                ))
+            })
+            .collect();
+        let keyspace_options = rows
+            .get("system_schema.keyspaces")
+            .iter()
+            .filter_map(|fields| {
+                let keyspace_name = fields.text("keyspace_name")?;
+                let options = KeyspaceOptions {
+                    replication: fields.text_map("replication"),
+                    durable_writes: fields.boolean("durable_writes"),
+                    initial_tablets: initial_tablets.get(keyspace_name).copied(),
+                };
+                Some((keyspace_name.to_owned(), options))
+            })
+            .collect();
+        let functions = rows
+            .get("system_schema.functions")
+            .iter()
+            .filter_map(|fields| {
+                let row = FunctionRow::from_fields(fields)?;
+                let key = (
+                    row.keyspace_name.clone(),
+                    row.function_name.clone(),
+                    row.argument_types.clone(),
+                );
+                Some((key, row))
+            })
+            .collect();
+        let aggregates = rows
+            .get("system_schema.aggregates")
+            .iter()
+            .filter_map(|fields| {
+                let row = AggregateRow::from_fields(fields)?;
+                let key = (
+                    row.keyspace_name.clone(),
+                    row.aggregate_name.clone(),
+                    row.argument_types.clone(),
+                );
+                Some((key, row))
+            })
+            .collect();
+
+        SchemaSnapshot {
+            cluster_data,
+            keyspace_options,
+            functions,
+            aggregates,
+        }
+    }
+}
+
+/// The driver's cluster metadata along with the versions of the node,
+/// fetched after the metadata was refreshed.
+pub(crate) struct SchemaVersions {
+    pub(crate) cluster_data: Arc<ClusterData>,
+    pub(crate) release_version: Option<String>,
+    pub(crate) schema_version: Option<Uuid>,
+}
+
+impl SchemaVersions {
+    async fn fetch(session: &Session) -> Self {
+        let cluster_data = session.get_cluster_data();
+        let (release_version, schema_version) = fetch_local_versions(session).await;
+        SchemaVersions {
+            cluster_data,
+            release_version,
+            schema_version,
+        }
+    }
+}
+
+/// The schema metadata of a session, kept up to date by the schema refresh task.
+pub(crate) struct SchemaMetaCache {
+    // The driver's metadata, which the snapshot was last found to reflect.
+    cluster_data: Arc<ClusterData>,
+    schema_meta: Arc<CassSchemaMeta>,
+}
+
+impl SchemaMetaCache {
+    pub(crate) fn schema_meta(&self) -> Arc<CassSchemaMeta> {
+        Arc::clone(&self.schema_meta)
+    }
+
+    /// Checks whether the snapshot reflects the schema, given the refreshed metadata
+    /// and the current schema version. The keyspaces are compared as well, because
+    /// the driver's metadata may lag behind the schema version.
+    fn is_current(&self, versions: &SchemaVersions) -> bool {
+        versions.schema_version.is_some()
+            && self.schema_meta.schema_version == versions.schema_version
+            && self.cluster_data.get_keyspace_info() == versions.cluster_data.get_keyspace_info()
+    }
+}
+
+/// Fetches the schema and builds its metadata, along with the snapshot compared
+/// to detect changes, if requested. The rows of the schema tables are only fetched
+/// if the driver fetches its schema metadata as well.
+async fn fetch_schema(
+    session: &Session,
+    versions: SchemaVersions,
+    fetch_schema_metadata: bool,
+    snapshot_version: u32,
+    take_snapshot: bool,
+) -> (SchemaMetaCache, Option<SchemaSnapshot>) {
+    let rows = if fetch_schema_metadata {
+        SchemaRows::fetch(session).await
+    } else {
+        SchemaRows::default()
+    };
+    let snapshot =
+        take_snapshot.then(|| SchemaSnapshot::new(Arc::clone(&versions.cluster_data), &rows));
+
+    let mut schema_meta = create_schema_meta(&versions, rows);
+    schema_meta.snapshot_version = snapshot_version;
+    let cache = SchemaMetaCache {
+        cluster_data: versions.cluster_data,
+        schema_meta: Arc::new(schema_meta),
+    };
+    (cache, snapshot)
+}
+
+/// Fetches the schema of a newly connected session and starts a task, which keeps
+/// the schema metadata up to date and reports schema changes to the listener, if there is one.
+///
+/// The scylla driver refreshes its cluster metadata on every SCHEMA_CHANGE event, but it
+/// doesn't expose the events, so the task checks whether the metadata was replaced, which
+/// is a cheap pointer comparison. As the metadata is also refreshed periodically, the rest
+/// of the schema is only fetched and compared if the schema version (or the driver's
+/// metadata) has changed.
+///
+/// The listener is called on a blocking thread, outside of the async runtime, which allows
+/// it to use the blocking session API, e.g. `cass_session_get_schema_meta`, which already
+/// returns the new schema by then. The task stops once the session is closed.
+pub(crate) async fn start_schema_refresh(
+    session: &Arc<Session>,
+    fetch_schema_metadata: bool,
+    listener: Option<SchemaChangeListener>,
+) -> Arc<Mutex<SchemaMetaCache>> {
+    let versions = SchemaVersions::fetch(session).await;
+    let (cache, snapshot) = fetch_schema(
+        session,
+        versions,
+        fetch_schema_metadata,
+        1,
+        listener.is_some(),
+    )
+    .await;
+    let cache = Arc::new(Mutex::new(cache));
+
+    RUNTIME.spawn(refresh_schema(
+        Arc::downgrade(session),
+        Arc::clone(&cache),
+        fetch_schema_metadata,
+        listener.zip(snapshot),
+    ));
+    cache
+}
+
+async fn refresh_schema(
+    session: Weak<Session>,
+    cache: Arc<Mutex<SchemaMetaCache>>,
+    fetch_schema_metadata: bool,
+    mut listener: Option<(SchemaChangeListener, SchemaSnapshot)>,
+) {
+    let mut interval = tokio::time::interval(SCHEMA_CHANGE_POLL_INTERVAL);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+        let Some(session) = session.upgrade() else {
+            return;
+        };
+        let cluster_data = session.get_cluster_data();
+        if Arc::ptr_eq(&cache.lock().unwrap().cluster_data, &cluster_data) {
+            continue;
+        }
+
+        let versions = SchemaVersions::fetch(&session).await;
+        let snapshot_version = {
+            let mut cache = cache.lock().unwrap();
+            if cache.is_current(&versions) {
+                cache.cluster_data = versions.cluster_data;
+                continue;
+            }
+            cache.schema_meta.snapshot_version.wrapping_add(1)
+        };
+
+        let (current, current_snapshot) = fetch_schema(
+            &session,
+            versions,
+            fetch_schema_metadata,
+            snapshot_version,
+            listener.is_some(),
+        )
+        .await;
+        *cache.lock().unwrap() = current;
+        // Do not keep the session alive while running user code.
+        drop(session);
+
+        let (Some((listener, previous)), Some(current)) = (&mut listener, current_snapshot) else {
+            continue;
+        };
+        let events = diff_schema(
+            previous.cluster_data.get_keyspace_info(),
+            current.cluster_data.get_keyspace_info(),
+            (&previous.keyspace_options, &current.keyspace_options),
+            (&previous.functions, &current.functions),
+            (&previous.aggregates, &current.aggregates),
+        );
+        *previous = current;
+        if !events.is_empty() {
+            let listener = *listener;
+            let invoke_listener = move || {
+                for event in &events {
+                    listener.invoke(event);
+                }
+            };
+            // Fails only if the runtime is shutting down.
+            let _ = tokio::task::spawn_blocking(invoke_listener).await;
+        }
+    }
+}
+
+/// Compares two maps of schema objects and returns the changed keys, in key order.
+fn diff_objects<'a, K: Ord + Hash + 'a, V: PartialEq>(
+    previous: &'a HashMap<K, V>,
+    current: &'a HashMap<K, V>,
+) -> impl Iterator<Item = (CassSchemaChangeType, &'a K)> {
+    let keys: BTreeSet<&K> = previous.keys().chain(current.keys()).collect();
+    keys.into_iter()
+        .filter_map(move |key| match (previous.get(key), current.get(key)) {
+            (None, Some(_)) => Some((CassSchemaChangeType::CASS_SCHEMA_CHANGE_TYPE_CREATED, key)),
+            (Some(_), None) => Some((CassSchemaChangeType::CASS_SCHEMA_CHANGE_TYPE_DROPPED, key)),
+            (Some(previous), Some(current)) if previous != current => {
+                Some((CassSchemaChangeType::CASS_SCHEMA_CHANGE_TYPE_UPDATED, key))
+            }
+            _ => None,
+        })
+}
+
+/// Computes the events describing the difference between two schema snapshots.
+///
+/// Like the server, which sends a single event for a created or dropped keyspace,
+/// objects are only compared within keyspaces present in both snapshots.
+fn diff_schema<O: PartialEq, F: PartialEq, A: PartialEq>(
+    previous_keyspaces: &HashMap<String, Keyspace>,
+    current_keyspaces: &HashMap<String, Keyspace>,
+    (previous_keyspace_options, current_keyspace_options): (
+        &HashMap<String, O>,
+        &HashMap<String, O>,
+    ),
+    (previous_functions, current_functions): (&HashMap<FunctionKey, F>, &HashMap<FunctionKey, F>),
+    (previous_aggregates, current_aggregates): (&HashMap<FunctionKey, A>, &HashMap<FunctionKey, A>),
+) -> Vec<CassSchemaChangeEvent> {
+    let mut events = Vec::new();
+    let object_event = |change_type, target, keyspace: &str, name: &str| CassSchemaChangeEvent {
+        change_type,
+        target,
+        keyspace: keyspace.to_owned(),
+        name: name.to_owned(),
+        argument_types: Vec::new(),
+    };
+
+    let keyspace_names: BTreeSet<&String> = previous_keyspaces
+        .keys()
+        .chain(current_keyspaces.keys())
+        .collect();
+    for keyspace_name in keyspace_names {
+        let (previous, current) = match (
+            previous_keyspaces.get(keyspace_name),
+            current_keyspaces.get(keyspace_name),
+        ) {
+            (Some(previous), Some(current)) => (previous, current),
+            (previous, _) => {
+                let change_type = if previous.is_none() {
+                    CassSchemaChangeType::CASS_SCHEMA_CHANGE_TYPE_CREATED
+                } else {
+                    CassSchemaChangeType::CASS_SCHEMA_CHANGE_TYPE_DROPPED
+                };
+                events.push(object_event(
+                    change_type,
+                    CassSchemaChangeTarget::CASS_SCHEMA_CHANGE_TARGET_KEYSPACE,
+                    keyspace_name,
+                    "",
+                ));
+                continue;
+            }
+        };
+
+        if previous.strategy != current.strategy
+            || previous_keyspace_options.get(keyspace_name)
+                != current_keyspace_options.get(keyspace_name)
+        {
+            events.push(object_event(
+                CassSchemaChangeType::CASS_SCHEMA_CHANGE_TYPE_UPDATED,
+                CassSchemaChangeTarget::CASS_SCHEMA_CHANGE_TARGET_KEYSPACE,
+                keyspace_name,
+                "",
+            ));
+        }
+        for (change_type, name) in
+            diff_objects(&previous.user_defined_types, &current.user_defined_types)
+        {
+            events.push(object_event(
+                change_type,
+                CassSchemaChangeTarget::CASS_SCHEMA_CHANGE_TARGET_TYPE,
+                keyspace_name,
+                name,
+            ));
+        }
+        // Materialized views are reported as tables, just like the server does.
+        for (change_type, name) in diff_objects(&previous.tables, &current.tables)
+            .chain(diff_objects(&previous.views, &current.views))
+        {
+            events.push(object_event(
+                change_type,
+                CassSchemaChangeTarget::CASS_SCHEMA_CHANGE_TARGET_TABLE,
+                keyspace_name,
+                name,
+            ));
+        }
+    }
+
+    let is_compared = |keyspace_name: &String| {
+        previous_keyspaces.contains_key(keyspace_name)
+            && current_keyspaces.contains_key(keyspace_name)
+    };
+    let function_events = diff_objects(previous_functions, current_functions).map(|change| {
+        (
+            change,
+            CassSchemaChangeTarget::CASS_SCHEMA_CHANGE_TARGET_FUNCTION,
+        )
+    });
+    let aggregate_events = diff_objects(previous_aggregates, current_aggregates).map(|change| {
+        (
+            change,
+            CassSchemaChangeTarget::CASS_SCHEMA_CHANGE_TARGET_AGGREGATE,
+        )
+    });
+    for ((change_type, (keyspace_name, name, argument_types)), target) in
+        function_events.chain(aggregate_events)
+    {
+        if is_compared(keyspace_name) {
+            events.push(CassSchemaChangeEvent {
+                change_type,
+                target,
+                keyspace: keyspace_name.clone(),
+                name: name.clone(),
+                argument_types: argument_types.clone(),
+            });
+        }
+    }
+
+    events
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_schema_change_event_type(
+    event: *const CassSchemaChangeEvent,
+) -> CassSchemaChangeType {
+    let event = RefFFI::as_ref(event);
+    event.change_type
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_schema_change_event_target(
+    event: *const CassSchemaChangeEvent,
+) -> CassSchemaChangeTarget {
+    let event = RefFFI::as_ref(event);
+    event.target
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_schema_change_event_keyspace(
+    event: *const CassSchemaChangeEvent,
+    keyspace: *mut *const c_char,
+    keyspace_length: *mut size_t,
+) {
+    let event = RefFFI::as_ref(event);
+    write_str_to_c(event.keyspace.as_str(), keyspace, keyspace_length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_schema_change_event_name(
+    event: *const CassSchemaChangeEvent,
+    name: *mut *const c_char,
+    name_length: *mut size_t,
+) {
+    let event = RefFFI::as_ref(event);
+    write_str_to_c(event.name.as_str(), name, name_length)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_schema_change_event_argument_count(
+    event: *const CassSchemaChangeEvent,
+) -> size_t {
+    let event = RefFFI::as_ref(event);
+    event.argument_types.len() as size_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_schema_change_event_argument_type(
+    event: *const CassSchemaChangeEvent,
+    index: size_t,
+    type_: *mut *const c_char,
+    type_length: *mut size_t,
+) -> CassError {
+    let event = RefFFI::as_ref(event);
+
+    match event.argument_types.get(index as usize) {
+        Some(argument_type) => {
+            write_str_to_c(argument_type.as_str(), type_, type_length);
+            CassError::CASS_OK
+        }
+        None => CassError::CASS_ERROR_LIB_INDEX_OUT_OF_BOUNDS,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scylla::transport::topology::Strategy;
+
+    fn keyspace(replication_factor: usize) -> Keyspace {
+        Keyspace {
+            strategy: Strategy::SimpleStrategy { replication_factor },
+            tables: HashMap::new(),
+            views: HashMap::new(),
+            user_defined_types: HashMap::new(),
+        }
+    }
+
+    fn event(
+        change_type: CassSchemaChangeType,
+        target: CassSchemaChangeTarget,
+        keyspace: &str,
+        name: &str,
+        argument_types: &[&str],
+    ) -> CassSchemaChangeEvent {
+        CassSchemaChangeEvent {
+            change_type,
+            target,
+            keyspace: keyspace.to_owned(),
+            name: name.to_owned(),
+            argument_types: argument_types.iter().map(|&t| t.to_owned()).collect(),
+        }
+    }
+
+    fn function_key(keyspace: &str, name: &str, argument_types: &[&str]) -> FunctionKey {
+        (
+            keyspace.to_owned(),
+            name.to_owned(),
+            argument_types.iter().map(|&t| t.to_owned()).collect(),
+        )
+    }
+
+    #[test]
+    fn diff_schema_test() {
+        use CassSchemaChangeTarget::*;
+        use CassSchemaChangeType::*;
+
+        let previous_keyspaces = HashMap::from([
+            ("ks".to_owned(), keyspace(1)),
+            ("altered".to_owned(), keyspace(1)),
+            ("dropped".to_owned(), keyspace(1)),
+        ]);
+        let current_keyspaces = HashMap::from([
+            ("ks".to_owned(), keyspace(1)),
+            ("altered".to_owned(), keyspace(3)),
+            ("created".to_owned(), keyspace(1)),
+        ]);
+
+        let previous_functions = HashMap::from([
+            (function_key("ks", "f", &["int"]), "return 1"),
+            (function_key("ks", "g", &[]), "return 2"),
+            (function_key("dropped", "f", &[]), "return 3"),
+        ]);
+        let current_functions = HashMap::from([
+            (function_key("ks", "f", &["int"]), "return 1"),
+            (function_key("ks", "f", &["int", "text"]), "return 1"),
+            (function_key("ks", "g", &[]), "return 4"),
+        ]);
+        let previous_aggregates = HashMap::from([(function_key("ks", "agg", &["int"]), 1)]);
+        let current_aggregates = HashMap::new();
+
+        let keyspace_options = HashMap::from([("ks".to_owned(), true)]);
+
+        let events = diff_schema(
+            &previous_keyspaces,
+            &current_keyspaces,
+            (&keyspace_options, &keyspace_options),
+            (&previous_functions, &current_functions),
+            (&previous_aggregates, &current_aggregates),
+        );
+
+        assert_eq!(
+            events,
+            vec![
+                event(
+                    CASS_SCHEMA_CHANGE_TYPE_UPDATED,
+                    CASS_SCHEMA_CHANGE_TARGET_KEYSPACE,
+                    "altered",
+                    "",
+                    &[]
+                ),
+                event(
+                    CASS_SCHEMA_CHANGE_TYPE_CREATED,
+                    CASS_SCHEMA_CHANGE_TARGET_KEYSPACE,
+                    "created",
+                    "",
+                    &[]
+                ),
+                event(
+                    CASS_SCHEMA_CHANGE_TYPE_DROPPED,
+                    CASS_SCHEMA_CHANGE_TARGET_KEYSPACE,
+                    "dropped",
+                    "",
+                    &[]
+                ),
+                event(
+                    CASS_SCHEMA_CHANGE_TYPE_CREATED,
+                    CASS_SCHEMA_CHANGE_TARGET_FUNCTION,
+                    "ks",
+                    "f",
+                    &["int", "text"]
+                ),
+                event(
+                    CASS_SCHEMA_CHANGE_TYPE_UPDATED,
+                    CASS_SCHEMA_CHANGE_TARGET_FUNCTION,
+                    "ks",
+                    "g",
+                    &[]
+                ),
+                event(
+                    CASS_SCHEMA_CHANGE_TYPE_DROPPED,
+                    CASS_SCHEMA_CHANGE_TARGET_AGGREGATE,
+                    "ks",
+                    "agg",
+                    &["int"]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_schema_keyspace_options_test() {
+        use CassSchemaChangeTarget::*;
+        use CassSchemaChangeType::*;
+
+        let keyspaces = HashMap::from([
+            ("ks".to_owned(), keyspace(1)),
+            ("other".to_owned(), keyspace(1)),
+        ]);
+        let options = |durable_writes, initial_tablets| KeyspaceOptions {
+            replication: None,
+            durable_writes: Some(durable_writes),
+            initial_tablets,
+        };
+        let previous_options = HashMap::from([
+            ("ks".to_owned(), options(true, None)),
+            ("other".to_owned(), options(true, Some(8))),
+        ]);
+        let no_functions: HashMap<FunctionKey, ()> = HashMap::new();
+
+        // Only the durable writes option of "ks" is altered.
+        let current_options = HashMap::from([
+            ("ks".to_owned(), options(false, None)),
+            ("other".to_owned(), options(true, Some(8))),
+        ]);
+        let events = diff_schema(
+            &keyspaces,
+            &keyspaces,
+            (&previous_options, &current_options),
+            (&no_functions, &no_functions),
+            (&no_functions, &no_functions),
+        );
+        assert_eq!(
+            events,
+            vec![event(
+                CASS_SCHEMA_CHANGE_TYPE_UPDATED,
+                CASS_SCHEMA_CHANGE_TARGET_KEYSPACE,
+                "ks",
+                "",
+                &[]
+            )]
+        );
+
+        // Unchanged options yield no events.
+        let events = diff_schema(
+            &keyspaces,
+            &keyspaces,
+            (&previous_options, &previous_options),
+            (&no_functions, &no_functions),
+            (&no_functions, &no_functions),
+        );
+        assert!(events.is_empty());
+    }
+}
@@ -1,36 +1,51 @@
 use crate::argconv::*;
-use crate::batch::CassBatch;
+use crate::batch::{CassBatch, CassBatchState};
+use crate::callback_executor::CallbackExecutor;
 use crate::cass_error::*;
 use crate::cass_types::{CassDataType, CassDataTypeInner, UDTDataType};
 use crate::cluster::build_session_builder;
 use crate::cluster::CassCluster;
 use crate::exec_profile::{CassExecProfile, ExecProfileName, PerStatementExecProfile};
 use crate::future::{CassFuture, CassFutureResult, CassResultValue};
-use crate::metadata::create_table_metadata;
-use crate::metadata::{CassKeyspaceMeta, CassMaterializedViewMeta, CassSchemaMeta};
+use crate::metadata::{
+    create_aggregate_metadata, create_function_metadata, create_index_metadata,
+    create_table_metadata, create_virtual_table_metadata, fill_column_fields, full_function_name,
+    parse_release_version, AggregateRow, FunctionRow, IndexRow, SELECT_LOCAL_QUERY,
+};
+use crate::metadata::{
+    CassKeyspaceMeta, CassMaterializedViewMeta, CassMetaFields, CassSchemaMeta, KeyspaceReplication,
+};
 use crate::prepared::CassPrepared;
 use crate::query_result::{CassResult, CassResultKind, CassResultMetadata};
+use crate::request_history::RequestHistory;
+use crate::schema_change::{
+    start_schema_refresh, SchemaChangeListener, SchemaMetaCache, SchemaVersions,
+};
 use crate::statement::{BoundStatement, CassStatement, SimpleQueryRowSerializer};
 use crate::types::{cass_uint64_t, size_t};
 use crate::uuid::CassUuid;
+use scylla::deserialize::DeserializeRow;
 use scylla::frame::types::Consistency;
 use scylla::query::Query;
-use scylla::transport::errors::QueryError;
+use scylla::transport::errors::{DbError, NewSessionError, QueryError};
 use scylla::transport::execution_profile::ExecutionProfileHandle;
+use scylla::transport::topology::{MaterializedView, Strategy};
 use scylla::transport::PagingStateResponse;
 use scylla::{QueryResult, Session, SessionBuilder};
 use std::collections::HashMap;
 use std::future::Future;
 use std::ops::Deref;
 use std::os::raw::c_char;
-use std::sync::Arc;
-use std::time::Duration;
-use tokio::sync::RwLock;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{oneshot, RwLock};
 
 pub struct CassSessionInner {
-    session: Session,
+    session: Arc<Session>,
     exec_profile_map: HashMap<ExecProfileName, ExecutionProfileHandle>,
     client_id: uuid::Uuid,
+    schema_meta_cache: Arc<Mutex<SchemaMetaCache>>,
+    callback_executor: CallbackExecutor,
 }
 
 impl CassSessionInner {
@@ -78,23 +93,38 @@ impl CassSessionInner {
         let session_builder = build_session_builder(cluster);
         let exec_profile_map = cluster.execution_profile_map().clone();
 
-        CassFuture::make_raw(Self::connect_fut(
-            session_opt,
-            session_builder,
-            exec_profile_map,
-            cluster
-                .get_client_id()
-                // If user did not set a client id, generate a random uuid v4.
-                .unwrap_or_else(uuid::Uuid::new_v4),
-            keyspace,
-        ))
+        let callback_executor = cluster.get_callback_executor();
+        let request_history = Arc::new(RequestHistory::default());
+
+        CassFuture::make_raw_with_request_history(
+            Self::connect_fut(
+                session_opt,
+                session_builder,
+                cluster.get_known_nodes(),
+                Arc::clone(&request_history),
+                exec_profile_map,
+                cluster
+                    .get_client_id()
+                    // If user did not set a client id, generate a random uuid v4.
+                    .unwrap_or_else(uuid::Uuid::new_v4),
+                cluster.get_schema_change_listener(),
+                callback_executor.clone(),
+                keyspace,
+            ),
+            async move { callback_executor },
+            request_history,
+        )
     }
 
     async fn connect_fut(
         session_opt: &RwLock<Option<CassSessionInner>>,
         session_builder_fut: impl Future<Output = SessionBuilder>,
+        known_nodes: Vec<String>,
+        request_history: Arc<RequestHistory>,
         exec_profile_builder_map: HashMap<ExecProfileName, CassExecProfile>,
         client_id: uuid::Uuid,
+        schema_change_listener: Option<SchemaChangeListener>,
+        callback_executor: CallbackExecutor,
         keyspace: Option<String>,
     ) -> CassFutureResult {
         // This can sleep for a long time, but only if someone connects/closes session
@@ -122,20 +152,58 @@ impl CassSessionInner {
             session_builder = session_builder.use_keyspace(keyspace, false);
         }
 
-        let session = session_builder
-            .build()
-            .await
-            .map_err(|err| (err.to_cass_error(), err.msg()))?;
+        let fetch_schema_metadata = session_builder.config.fetch_schema_metadata;
+        let connect_start = Instant::now();
+        let session = match session_builder.build().await {
+            Ok(session) => Arc::new(session),
+            Err(err) => {
+                record_contact_point_errors(
+                    &known_nodes,
+                    &err,
+                    connect_start.elapsed(),
+                    &request_history,
+                );
+                return Err((err.to_cass_error(), err.msg()));
+            }
+        };
+
+        let schema_meta_cache =
+            start_schema_refresh(&session, fetch_schema_metadata, schema_change_listener).await;
 
         *session_guard = Some(CassSessionInner {
             session,
             exec_profile_map,
             client_id,
+            schema_meta_cache,
+            callback_executor,
         });
         Ok(CassResultValue::Empty)
     }
 }
 
+/// Records the reasons of the connection failure for the contact points.
+/// The driver doesn't expose the outcomes of its attempts to connect to each
+/// contact point, but a single error for the whole session, so each contact
+/// point is attributed that error - unless the error lists the hostnames
+/// which failed to resolve.
+fn record_contact_point_errors(
+    known_nodes: &[String],
+    error: &NewSessionError,
+    latency: Duration,
+    request_history: &RequestHistory,
+) {
+    let (hosts, cass_error) = match error {
+        NewSessionError::FailedToResolveAnyHostname(hostnames) => (
+            hostnames.as_slice(),
+            CassError::CASS_ERROR_LIB_HOST_RESOLUTION,
+        ),
+        _ => (known_nodes, error.to_cass_error()),
+    };
+    for host in hosts {
+        request_history.record_attempt(host.clone(), latency, Err((cass_error, error.msg())));
+    }
+}
+
 pub type CassSession = RwLock<Option<CassSessionInner>>;
 
 impl ArcFFI for CassSession {}
@@ -192,9 +260,16 @@ pub unsafe extern "C" fn cass_session_execute_batch(
 
     // DO NOT refer to `batch_from_raw` inside the async block, as I've done just to face a segfault.
     let batch_exec_profile = batch_from_raw.exec_profile.clone();
+    let size_warning_threshold = batch_from_raw.size_warning_threshold;
+    let split_by_partition = batch_from_raw.split_by_partition;
+    let record_execution_history = batch_from_raw.record_execution_history;
     #[allow(unused, clippy::let_unit_value)]
     let batch_from_raw = (); // Hardening shadow to avoid use-after-free.
 
+    let request_history = Arc::new(RequestHistory::new(record_execution_history));
+    let history_listener = Arc::clone(&request_history);
+    let (executor_sender, callback_executor) = callback_executor_channel();
+
     let future = async move {
         let session_guard = session_opt.read().await;
         if session_guard.is_none() {
@@ -205,39 +280,209 @@ pub unsafe extern "C" fn cass_session_execute_batch(
         }
 
         let cass_session_inner = &session_guard.as_ref().unwrap();
+        let _ = executor_sender.send(cass_session_inner.callback_executor.clone());
         let session = &cass_session_inner.session;
 
         let handle = cass_session_inner
             .get_or_resolve_profile_handle(batch_exec_profile.as_ref())
             .await?;
 
-        Arc::make_mut(&mut state)
-            .batch
-            .set_execution_profile_handle(handle);
+        let batch = &mut Arc::make_mut(&mut state).batch;
+        batch.set_execution_profile_handle(handle);
+        batch.set_history_listener(history_listener);
+
+        if let Some(threshold) = size_warning_threshold {
+            let estimated_size = state.estimated_size();
+            if estimated_size > threshold {
+                tracing::warn!(
+                    "Batch of {} statements has an estimated size of {} bytes, exceeding the threshold of {} bytes",
+                    state.batch.statements.len(),
+                    estimated_size,
+                    threshold
+                );
+            }
+        }
 
-        let query_res = session.batch(&state.batch, &state.bound_values).await;
+        let query_res = if split_by_partition {
+            execute_split_batch(session, &state).await
+        } else {
+            session.batch(&state.batch, &state.bound_values).await
+        };
         match query_res {
-            Ok(_result) => Ok(CassResultValue::QueryResult(Arc::new(CassResult {
-                tracing_id: None,
-                paging_state_response: PagingStateResponse::NoMorePages,
-                kind: CassResultKind::NonRows,
-            }))),
+            // Conditional batches return the `[applied]` column, along with
+            // the existing values of the rows, if the batch was not applied.
+            Ok(result) => match CassResult::from_result_payload(
+                result,
+                PagingStateResponse::NoMorePages,
+                None,
+            ) {
+                Ok(result) => Ok(CassResultValue::QueryResult(Arc::new(result))),
+                Err(e) => Ok(CassResultValue::QueryError(Arc::new(e))),
+            },
             Err(err) => Ok(CassResultValue::QueryError(Arc::new(err.into()))),
         }
     };
 
-    match request_timeout_ms {
-        Some(timeout_ms) => {
-            CassFuture::make_raw(async move { request_with_timeout(timeout_ms, future).await })
+    make_request_future(
+        request_with_timeout(request_timeout_ms, future),
+        callback_executor,
+        request_history,
+    )
+}
+
+/// Creates the future of a request, which records its attempts
+/// in `request_history`.
+fn make_request_future(
+    future: impl Future<Output = CassFutureResult> + Send + 'static,
+    callback_executor: impl Future<Output = CallbackExecutor> + Send + 'static,
+    request_history: Arc<RequestHistory>,
+) -> *const CassFuture {
+    let history = Arc::clone(&request_history);
+    let request = async move {
+        let result = future.await;
+        history.finish();
+        result
+    };
+
+    CassFuture::make_raw_with_request_history(request, callback_executor, request_history)
+}
+
+/// Creates a channel, through which a request hands over the executor of the session's
+/// future callbacks, cloned while the request holds the session lock. The executor
+/// is then known once the request completes, without taking the lock again.
+/// The default executor is used if the request fails to send it, e.g. when
+/// the session is not connected.
+fn callback_executor_channel() -> (
+    oneshot::Sender<CallbackExecutor>,
+    impl Future<Output = CallbackExecutor> + Send + 'static,
+) {
+    let (executor_sender, executor_receiver) = oneshot::channel();
+    (executor_sender, async move {
+        executor_receiver.await.unwrap_or_default()
+    })
+}
+
+/// Executes the UNLOGGED batch as concurrent per-partition sub-batches.
+/// Fails with the first error encountered, otherwise returns the result
+/// of the first sub-batch. The sub-batches are independent requests,
+/// so some of them may have been applied even if the batch fails.
+async fn execute_split_batch(
+    session: &Session,
+    state: &CassBatchState,
+) -> Result<QueryResult, QueryError> {
+    let sub_batches = state.split_by_token();
+    if sub_batches.len() <= 1 {
+        return session.batch(&state.batch, &state.bound_values).await;
+    }
+
+    let results = futures::future::join_all(
+        sub_batches
+            .iter()
+            .map(|sub_batch| session.batch(&sub_batch.batch, &sub_batch.bound_values)),
+    )
+    .await;
+
+    let mut first_result = None;
+    for result in results {
+        let result = result?;
+        first_result.get_or_insert(result);
+    }
+    // There were at least two sub-batches.
+    Ok(first_result.unwrap())
+}
+
+/// Checks whether the statement is a schema altering one, i.e. `CREATE`, `ALTER` or `DROP`
+/// of a keyspace, table, type, index, view, function, aggregate or trigger.
+/// Statements managing roles, users, permissions or service levels don't alter the schema.
+///
+/// The driver doesn't expose the kind of the statement's result (`SCHEMA_CHANGE`),
+/// so the statement is inspected instead.
+fn is_schema_change_statement(statement: &str) -> bool {
+    const SCHEMA_OBJECTS: [&str; 11] = [
+        "KEYSPACE",
+        "SCHEMA",
+        "TABLE",
+        "COLUMNFAMILY",
+        "TYPE",
+        "INDEX",
+        "MATERIALIZED",
+        "FUNCTION",
+        "AGGREGATE",
+        "TRIGGER",
+        // CREATE CUSTOM INDEX
+        "CUSTOM",
+    ];
+
+    let (keyword, rest) = next_keyword(statement);
+    if !["CREATE", "ALTER", "DROP"]
+        .iter()
+        .any(|schema_keyword| keyword.eq_ignore_ascii_case(schema_keyword))
+    {
+        return false;
+    }
+
+    let (mut object, rest) = next_keyword(rest);
+    if object.eq_ignore_ascii_case("OR") {
+        // CREATE OR REPLACE FUNCTION/AGGREGATE
+        let (replace, rest) = next_keyword(rest);
+        if !replace.eq_ignore_ascii_case("REPLACE") {
+            return false;
+        }
+        object = next_keyword(rest).0;
+    }
+    SCHEMA_OBJECTS
+        .iter()
+        .any(|schema_object| object.eq_ignore_ascii_case(schema_object))
+}
+
+/// Splits the leading keyword off the statement, skipping leading whitespace and comments.
+fn next_keyword(statement: &str) -> (&str, &str) {
+    let mut rest = statement;
+    loop {
+        rest = rest.trim_start();
+        if let Some(comment) = rest.strip_prefix("--").or_else(|| rest.strip_prefix("//")) {
+            rest = comment.split_once('\n').map_or("", |(_, rest)| rest);
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            rest = comment.split_once("*/").map_or("", |(_, rest)| rest);
+        } else {
+            break;
         }
-        None => CassFuture::make_raw(future),
+    }
+
+    let keyword_len = rest
+        .find(|c: char| !(c.is_ascii_alphabetic() || c == '_'))
+        .unwrap_or(rest.len());
+    rest.split_at(keyword_len)
+}
+
+/// Awaits schema agreement after a schema altering statement was executed
+/// successfully, and records in its result whether the agreement was reached
+/// within the configured max schema wait time. The session is received once
+/// the statement turns out to alter the schema.
+async fn await_schema_agreement_after_change(
+    result: &mut CassFutureResult,
+    session: oneshot::Receiver<Arc<Session>>,
+) {
+    let Ok(CassResultValue::QueryResult(query_result)) = result else {
+        return;
+    };
+    let Ok(session) = session.await else {
+        return;
+    };
+    let in_agreement = session.await_schema_agreement().await.is_ok();
+    // The result has not been shared yet.
+    if let Some(query_result) = Arc::get_mut(query_result) {
+        query_result.schema_in_agreement = in_agreement;
     }
 }
 
 async fn request_with_timeout(
-    request_timeout_ms: cass_uint64_t,
+    request_timeout_ms: Option<cass_uint64_t>,
     future: impl Future<Output = Result<CassResultValue, (CassError, String)>>,
 ) -> Result<CassResultValue, (CassError, String)> {
+    let Some(request_timeout_ms) = request_timeout_ms else {
+        return future.await;
+    };
     match tokio::time::timeout(Duration::from_millis(request_timeout_ms), future).await {
         Ok(result) => result,
         Err(_timeout_err) => Ok(CassResultValue::QueryError(Arc::new(
@@ -258,12 +503,22 @@ pub unsafe extern "C" fn cass_session_execute(
     let paging_state = statement_opt.paging_state.clone();
     let paging_enabled = statement_opt.paging_enabled;
     let request_timeout_ms = statement_opt.request_timeout_ms;
+    let record_execution_history = statement_opt.record_execution_history;
 
     let mut statement = statement_opt.statement.clone();
     let statement_exec_profile = statement_opt.exec_profile.clone();
+    let changes_schema = is_schema_change_statement(match &statement {
+        BoundStatement::Simple(query) => &query.query.contents,
+        BoundStatement::Prepared(prepared) => prepared.statement.statement.get_statement(),
+    });
     #[allow(unused, clippy::let_unit_value)]
     let statement_opt = (); // Hardening shadow to avoid use-after-free.
 
+    let request_history = Arc::new(RequestHistory::new(record_execution_history));
+    let history_listener = Arc::clone(&request_history);
+    let (executor_sender, callback_executor) = callback_executor_channel();
+    let (session_sender, session_receiver) = oneshot::channel();
+
     let future = async move {
         let session_guard = session_opt.read().await;
         if session_guard.is_none() {
@@ -273,17 +528,26 @@ pub unsafe extern "C" fn cass_session_execute(
             ));
         }
         let cass_session_inner = session_guard.as_ref().unwrap();
+        let _ = executor_sender.send(cass_session_inner.callback_executor.clone());
         let session = &cass_session_inner.session;
+        if changes_schema {
+            let _ = session_sender.send(Arc::clone(session));
+        }
 
         let handle = cass_session_inner
             .get_or_resolve_profile_handle(statement_exec_profile.as_ref())
             .await?;
 
         match &mut statement {
-            BoundStatement::Simple(query) => query.query.set_execution_profile_handle(handle),
-            BoundStatement::Prepared(prepared) => Arc::make_mut(&mut prepared.statement)
-                .statement
-                .set_execution_profile_handle(handle),
+            BoundStatement::Simple(query) => {
+                query.query.set_execution_profile_handle(handle);
+                query.query.set_history_listener(history_listener);
+            }
+            BoundStatement::Prepared(prepared) => {
+                let statement = &mut Arc::make_mut(&mut prepared.statement).statement;
+                statement.set_execution_profile_handle(handle);
+                statement.set_history_listener(history_listener);
+            }
         }
 
         // Creating a type alias here to fix clippy lints.
@@ -373,12 +637,44 @@ pub unsafe extern "C" fn cass_session_execute(
         }
     };
 
-    match request_timeout_ms {
-        Some(timeout_ms) => {
-            CassFuture::make_raw(async move { request_with_timeout(timeout_ms, future).await })
-        }
-        None => CassFuture::make_raw(future),
-    }
+    // Awaiting schema agreement is not limited by the request timeout, but by
+    // the max schema wait time.
+    let request = async move {
+        let mut result = request_with_timeout(request_timeout_ms, future).await;
+        await_schema_agreement_after_change(&mut result, session_receiver).await;
+        result
+    };
+
+    make_request_future(request, callback_executor, request_history)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cass_session_wait_for_schema_agreement(
+    session_raw: *mut CassSession,
+) -> *const CassFuture {
+    let session_opt = ArcFFI::as_ref(session_raw);
+    let (executor_sender, callback_executor) = callback_executor_channel();
+
+    CassFuture::make_raw_with_callback_executor(
+        async move {
+            let session_guard = session_opt.read().await;
+            if session_guard.is_none() {
+                return Err((
+                    CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
+                    "Session is not connected".msg(),
+                ));
+            }
+            let session_inner = session_guard.as_ref().unwrap();
+            let _ = executor_sender.send(session_inner.callback_executor.clone());
+            let session = &session_inner.session;
+
+            match session.await_schema_agreement().await {
+                Ok(schema_version) => Ok(CassResultValue::SchemaVersion(schema_version)),
+                Err(err) => Ok(CassResultValue::QueryError(Arc::new(err.into()))),
+            }
+        },
+        callback_executor,
+    )
 }
 
 #[no_mangle]
@@ -389,32 +685,40 @@ pub unsafe extern "C" fn cass_session_prepare_from_existing(
     let session = ArcFFI::as_ref(cass_session);
     let cass_statement = BoxFFI::as_ref(statement);
     let statement = cass_statement.statement.clone();
+    let (executor_sender, callback_executor) = callback_executor_channel();
 
-    CassFuture::make_raw(async move {
-        let query = match &statement {
-            BoundStatement::Simple(q) => q,
-            BoundStatement::Prepared(ps) => {
-                return Ok(CassResultValue::Prepared(ps.statement.clone()));
+    CassFuture::make_raw_with_callback_executor(
+        async move {
+            let session_guard = session.read().await;
+            if let Some(session_inner) = session_guard.as_ref() {
+                let _ = executor_sender.send(session_inner.callback_executor.clone());
             }
-        };
 
-        let session_guard = session.read().await;
-        if session_guard.is_none() {
-            return Err((
-                CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
-                "Session is not connected".msg(),
-            ));
-        }
-        let session = &session_guard.as_ref().unwrap().session;
-        let prepared = session
-            .prepare(query.query.clone())
-            .await
-            .map_err(|err| (err.to_cass_error(), err.msg()))?;
+            let query = match &statement {
+                BoundStatement::Simple(q) => q,
+                BoundStatement::Prepared(ps) => {
+                    return Ok(CassResultValue::Prepared(ps.statement.clone()));
+                }
+            };
 
-        Ok(CassResultValue::Prepared(Arc::new(
-            CassPrepared::new_from_prepared_statement(prepared),
-        )))
-    })
+            if session_guard.is_none() {
+                return Err((
+                    CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
+                    "Session is not connected".msg(),
+                ));
+            }
+            let session = &session_guard.as_ref().unwrap().session;
+            let prepared = session
+                .prepare(query.query.clone())
+                .await
+                .map_err(|err| (err.to_cass_error(), err.msg()))?;
+
+            Ok(CassResultValue::Prepared(Arc::new(
+                CassPrepared::new_from_prepared_statement(prepared),
+            )))
+        },
+        callback_executor,
+    )
 }
 
 #[no_mangle]
@@ -439,29 +743,35 @@ pub unsafe extern "C" fn cass_session_prepare_n(
         .unwrap_or_default();
     let query = Query::new(query_str.to_string());
     let cass_session = ArcFFI::as_ref(cass_session_raw);
+    let (executor_sender, callback_executor) = callback_executor_channel();
 
-    CassFuture::make_raw(async move {
-        let session_guard = cass_session.read().await;
-        if session_guard.is_none() {
-            return Err((
-                CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
-                "Session is not connected".msg(),
-            ));
-        }
-        let session = &session_guard.as_ref().unwrap().session;
+    CassFuture::make_raw_with_callback_executor(
+        async move {
+            let session_guard = cass_session.read().await;
+            if session_guard.is_none() {
+                return Err((
+                    CassError::CASS_ERROR_LIB_NO_HOSTS_AVAILABLE,
+                    "Session is not connected".msg(),
+                ));
+            }
+            let session_inner = session_guard.as_ref().unwrap();
+            let _ = executor_sender.send(session_inner.callback_executor.clone());
+            let session = &session_inner.session;
 
-        let mut prepared = session
-            .prepare(query)
-            .await
-            .map_err(|err| (err.to_cass_error(), err.msg()))?;
+            let mut prepared = session
+                .prepare(query)
+                .await
+                .map_err(|err| (err.to_cass_error(), err.msg()))?;
 
-        // Set Cpp Driver default configuration for queries:
-        prepared.set_consistency(Consistency::One);
+            // Set Cpp Driver default configuration for queries:
+            prepared.set_consistency(Consistency::One);
 
-        Ok(CassResultValue::Prepared(Arc::new(
-            CassPrepared::new_from_prepared_statement(prepared),
-        )))
-    })
+            Ok(CassResultValue::Prepared(Arc::new(
+                CassPrepared::new_from_prepared_statement(prepared),
+            )))
+        },
+        callback_executor,
+    )
 }
 
 #[no_mangle]
@@ -472,20 +782,24 @@ pub unsafe extern "C" fn cass_session_free(session_raw: *mut CassSession) {
 #[no_mangle]
 pub unsafe extern "C" fn cass_session_close(session: *mut CassSession) -> *const CassFuture {
     let session_opt = ArcFFI::as_ref(session);
+    // The session is gone once closed, so its callback executor is handed over.
+    let (executor_sender, callback_executor) = callback_executor_channel();
 
-    CassFuture::make_raw(async move {
-        let mut session_guard = session_opt.write().await;
-        if session_guard.is_none() {
-            return Err((
-                CassError::CASS_ERROR_LIB_UNABLE_TO_CLOSE,
-                "Already closing or closed".msg(),
-            ));
-        }
-
-        *session_guard = None;
-
-        Ok(CassResultValue::Empty)
-    })
+    CassFuture::make_raw_with_callback_executor(
+        async move {
+            let mut session_guard = session_opt.write().await;
+            let Some(session_inner) = session_guard.take() else {
+                return Err((
+                    CassError::CASS_ERROR_LIB_UNABLE_TO_CLOSE,
+                    "Already closing or closed".msg(),
+                ));
+            };
+            let _ = executor_sender.send(session_inner.callback_executor);
+
+            Ok(CassResultValue::Empty)
+        },
+        callback_executor,
+    )
 }
 
 #[no_mangle]
@@ -501,16 +815,135 @@ pub unsafe extern "C" fn cass_session_get_schema_meta(
     session: *const CassSession,
 ) -> *const CassSchemaMeta {
     let cass_session = ArcFFI::as_ref(session);
+    let session_guard = cass_session.blocking_read();
+    let session_inner = session_guard.as_ref().unwrap();
+    // Kept up to date by the schema refresh task.
+    let schema_meta = session_inner
+        .schema_meta_cache
+        .lock()
+        .unwrap()
+        .schema_meta();
+
+    ArcFFI::into_ptr(schema_meta)
+}
+
+/// Fetches the release version and the schema version of the node.
+pub(crate) async fn fetch_local_versions(
+    session: &Session,
+) -> (Option<String>, Option<uuid::Uuid>) {
+    fetch_schema_rows::<(Option<String>, Option<uuid::Uuid>)>(session, SELECT_LOCAL_QUERY)
+        .await
+        .pop()
+        .unwrap_or_default()
+}
+
+/// The schema tables, whose raw rows are fetched along with the driver's metadata.
+const SCHEMA_TABLES: [&str; 11] = [
+    "system_schema.keyspaces",
+    "system_schema.scylla_keyspaces",
+    "system_schema.tables",
+    "system_schema.views",
+    "system_schema.columns",
+    "system_schema.indexes",
+    "system_schema.functions",
+    "system_schema.aggregates",
+    "system_virtual_schema.keyspaces",
+    "system_virtual_schema.tables",
+    "system_virtual_schema.columns",
+];
+
+/// Raw rows of the schema tables, keyed by the table name.
+#[derive(Default)]
+pub(crate) struct SchemaRows(HashMap<&'static str, Vec<CassMetaFields>>);
+
+impl SchemaRows {
+    /// Fetches the rows of all schema tables concurrently.
+    pub(crate) async fn fetch(session: &Session) -> Self {
+        let rows = futures::future::join_all(
+            SCHEMA_TABLES
+                .map(|table| async move { (table, fetch_schema_fields(session, table).await) }),
+        )
+        .await;
+        SchemaRows(rows.into_iter().collect())
+    }
+
+    pub(crate) fn get(&self, table: &str) -> &[CassMetaFields] {
+        self.0.get(table).map_or(&[], Vec::as_slice)
+    }
+
+    fn take(&mut self, table: &str) -> Vec<CassMetaFields> {
+        self.0.remove(table).unwrap_or_default()
+    }
+}
+
+/// Builds the schema metadata. The schema version should be fetched before
+/// the schema, so that it doesn't claim to reflect changes missing in the snapshot.
+/// The snapshot version is left unset.
+pub(crate) fn create_schema_meta(
+    versions: &SchemaVersions,
+    mut rows: SchemaRows,
+) -> CassSchemaMeta {
+    let cluster_data = &versions.cluster_data;
     let mut keyspaces: HashMap<String, CassKeyspaceMeta> = HashMap::new();
 
-    for (keyspace_name, keyspace) in cass_session
-        .blocking_read()
-        .as_ref()
-        .unwrap()
-        .session
-        .get_cluster_data()
-        .get_keyspace_info()
-    {
+    // Raw rows of the schema tables, exposed through the `*_meta_field_by_name` functions.
+    // Indexes, functions and aggregates are not a part of the driver's cluster metadata,
+    // so their metadata is built from these rows as well.
+    let mut keyspace_fields = SchemaFields::new(&mut rows, "system_schema.keyspaces", |fields| {
+        Some(vec![fields.text("keyspace_name")?.to_owned()])
+    });
+    // Scylla's keyspace options, e.g. tablets.
+    let mut scylla_keyspace_fields =
+        SchemaFields::new(&mut rows, "system_schema.scylla_keyspaces", |fields| {
+            Some(vec![fields.text("keyspace_name")?.to_owned()])
+        });
+    let mut table_fields = SchemaFields::new(&mut rows, "system_schema.tables", |fields| {
+        Some(vec![
+            fields.text("keyspace_name")?.to_owned(),
+            fields.text("table_name")?.to_owned(),
+        ])
+    });
+    let mut view_fields = SchemaFields::new(&mut rows, "system_schema.views", |fields| {
+        Some(vec![
+            fields.text("keyspace_name")?.to_owned(),
+            fields.text("view_name")?.to_owned(),
+        ])
+    });
+    // Columns of both tables and views.
+    let mut column_fields = SchemaFields::new(&mut rows, "system_schema.columns", |fields| {
+        Some(vec![
+            fields.text("keyspace_name")?.to_owned(),
+            fields.text("table_name")?.to_owned(),
+            fields.text("column_name")?.to_owned(),
+        ])
+    });
+    let mut index_fields = SchemaFields::new(&mut rows, "system_schema.indexes", |fields| {
+        Some(vec![
+            fields.text("keyspace_name")?.to_owned(),
+            fields.text("table_name")?.to_owned(),
+            fields.text("index_name")?.to_owned(),
+        ])
+    });
+    let mut function_fields = SchemaFields::new(&mut rows, "system_schema.functions", |fields| {
+        Some(vec![
+            fields.text("keyspace_name")?.to_owned(),
+            full_function_name(
+                fields.text("function_name")?,
+                fields.text_list("argument_types"),
+            ),
+        ])
+    });
+    let mut aggregate_fields = SchemaFields::new(&mut rows, "system_schema.aggregates", |fields| {
+        Some(vec![
+            fields.text("keyspace_name")?.to_owned(),
+            full_function_name(
+                fields.text("aggregate_name")?,
+                fields.text_list("argument_types"),
+            ),
+        ])
+    });
+
+    for (keyspace_name, keyspace) in cluster_data.get_keyspace_info() {
         let mut user_defined_type_data_type = HashMap::new();
         let mut tables = HashMap::new();
         let mut views = HashMap::new();
@@ -535,15 +968,24 @@ pub unsafe extern "C" fn cass_session_get_schema_meta(
                     table_metadata,
                     &keyspace.user_defined_types,
                 );
+                cass_table_meta.fields = table_fields.take(&[keyspace_name, table_name]);
+                fill_column_fields(&mut cass_table_meta, |table, column| {
+                    column_fields.take(&[keyspace_name, table, column])
+                });
 
                 let mut table_views = HashMap::new();
-                for (view_name, view_metadata) in &keyspace.views {
-                    let cass_view_table_meta = create_table_metadata(
+                for (view_name, view_metadata) in views_of_table(&keyspace.views, table_name) {
+                    let mut cass_view_table_meta = create_table_metadata(
                         keyspace_name,
                         view_name,
                         &view_metadata.view_metadata,
                         &keyspace.user_defined_types,
                     );
+                    cass_view_table_meta.fields = view_fields.take(&[keyspace_name, view_name]);
+                    fill_column_fields(&mut cass_view_table_meta, |table, column| {
+                        column_fields.take(&[keyspace_name, table, column])
+                    });
+
                     let cass_view_meta = CassMaterializedViewMeta {
                         name: view_name.clone(),
                         view_metadata: cass_view_table_meta,
@@ -556,6 +998,14 @@ pub unsafe extern "C" fn cass_session_get_schema_meta(
                 }
 
                 cass_table_meta.views = table_views;
+                cass_table_meta.indexes = index_fields
+                    .take_prefixed(&[keyspace_name, table_name])
+                    .into_iter()
+                    .filter_map(|fields| {
+                        let row = IndexRow::from_fields(&fields)?;
+                        Some((row.index_name.clone(), create_index_metadata(row, fields)))
+                    })
+                    .collect();
 
                 cass_table_meta
             });
@@ -563,6 +1013,33 @@ pub unsafe extern "C" fn cass_session_get_schema_meta(
             tables.insert(table_name.clone(), cass_table_meta_arced);
         }
 
+        let functions: HashMap<_, _> = function_fields
+            .take_prefixed(&[keyspace_name])
+            .into_iter()
+            .filter_map(|fields| {
+                let row = FunctionRow::from_fields(&fields)?;
+                let function_meta =
+                    create_function_metadata(row, fields, &keyspace.user_defined_types);
+                Some((function_meta.full_name.clone(), Arc::new(function_meta)))
+            })
+            .collect();
+
+        let aggregates = aggregate_fields
+            .take_prefixed(&[keyspace_name])
+            .into_iter()
+            .filter_map(|fields| {
+                let row = AggregateRow::from_fields(&fields)?;
+                let aggregate_meta = create_aggregate_metadata(
+                    row,
+                    fields,
+                    &functions,
+                    &keyspace.user_defined_types,
+                );
+                Some((aggregate_meta.full_name.clone(), Arc::new(aggregate_meta)))
+            })
+            .collect();
+
+        let fields = keyspace_fields.take(&[keyspace_name]);
         keyspaces.insert(
             keyspace_name.clone(),
             CassKeyspaceMeta {
@@ -570,20 +1047,222 @@ pub unsafe extern "C" fn cass_session_get_schema_meta(
                 user_defined_type_data_type,
                 tables,
                 views,
+                functions,
+                aggregates,
+                replication: KeyspaceReplication::new(&keyspace.strategy),
+                durable_writes: fields.boolean("durable_writes").unwrap_or(true),
+                initial_tablets: scylla_keyspace_fields
+                    .take(&[keyspace_name])
+                    .int("initial_tablets")
+                    .map(|initial_tablets| initial_tablets as u32),
+                fields,
+                is_virtual: false,
             },
         );
     }
 
-    BoxFFI::into_ptr(Box::new(CassSchemaMeta { keyspaces }))
+    // Virtual keyspaces (Cassandra 4.0+) are only described in `system_virtual_schema`.
+    let mut virtual_keyspace_fields =
+        SchemaFields::new(&mut rows, "system_virtual_schema.keyspaces", |fields| {
+            Some(vec![fields.text("keyspace_name")?.to_owned()])
+        });
+    let mut virtual_table_fields =
+        SchemaFields::new(&mut rows, "system_virtual_schema.tables", |fields| {
+            Some(vec![
+                fields.text("keyspace_name")?.to_owned(),
+                fields.text("table_name")?.to_owned(),
+            ])
+        });
+    let mut virtual_column_fields =
+        SchemaFields::new(&mut rows, "system_virtual_schema.columns", |fields| {
+            Some(vec![
+                fields.text("keyspace_name")?.to_owned(),
+                fields.text("table_name")?.to_owned(),
+                fields.text("column_name")?.to_owned(),
+            ])
+        });
+
+    for fields in virtual_keyspace_fields.take_prefixed(&[]) {
+        let Some(keyspace_name) = fields.text("keyspace_name").map(str::to_owned) else {
+            continue;
+        };
+
+        let tables = virtual_table_fields
+            .take_prefixed(&[&keyspace_name])
+            .into_iter()
+            .filter_map(|table_fields| {
+                let table_name = table_fields.text("table_name")?.to_owned();
+                let column_fields =
+                    virtual_column_fields.take_prefixed(&[&keyspace_name, &table_name]);
+                let table_meta = create_virtual_table_metadata(
+                    &keyspace_name,
+                    &table_name,
+                    table_fields,
+                    column_fields,
+                );
+                Some((table_name, Arc::new(table_meta)))
+            })
+            .collect();
+
+        keyspaces.insert(
+            keyspace_name.clone(),
+            CassKeyspaceMeta {
+                name: keyspace_name,
+                user_defined_type_data_type: HashMap::new(),
+                tables,
+                views: HashMap::new(),
+                functions: HashMap::new(),
+                aggregates: HashMap::new(),
+                // Virtual tables are local to each node.
+                replication: KeyspaceReplication::new(&Strategy::LocalStrategy),
+                durable_writes: false,
+                initial_tablets: None,
+                fields,
+                is_virtual: true,
+            },
+        );
+    }
+
+    CassSchemaMeta {
+        keyspaces,
+        snapshot_version: 0,
+        version: parse_release_version(versions.release_version.as_deref().unwrap_or_default()),
+        schema_version: versions.schema_version,
+    }
+}
+
+/// Returns the materialized views, whose base table is `table_name`.
+fn views_of_table<'a>(
+    views: &'a HashMap<String, MaterializedView>,
+    table_name: &'a str,
+) -> impl Iterator<Item = (&'a String, &'a MaterializedView)> {
+    views
+        .iter()
+        .filter(move |(_, view_metadata)| view_metadata.base_table_name == table_name)
+}
+
+/// Fetches all rows of a schema table. A missing table yields no rows. Other failures
+/// are logged, and the rows which were fetched successfully are returned.
+async fn fetch_schema_rows<R>(session: &Session, query: &str) -> Vec<R>
+where
+    R: for<'frame> DeserializeRow<'frame, 'frame>,
+{
+    let result = match session.query_unpaged(query, ()).await {
+        Ok(result) => result,
+        Err(err) => {
+            warn_schema_query_error(query, &err);
+            return Vec::new();
+        }
+    };
+    let rows_result = match result.into_rows_result() {
+        Ok(rows_result) => rows_result,
+        Err(err) => {
+            tracing::warn!("Failed to deserialize the result of {:?}: {}", query, err);
+            return Vec::new();
+        }
+    };
+    let rows = match rows_result.rows::<R>() {
+        Ok(rows) => rows,
+        Err(err) => {
+            tracing::warn!("Failed to deserialize the result of {:?}: {}", query, err);
+            return Vec::new();
+        }
+    };
+
+    rows.filter_map(|row| {
+        row.map_err(|err| tracing::warn!("Skipping a row of {:?}: {}", query, err))
+            .ok()
+    })
+    .collect()
+}
+
+/// Logs the failure of a schema query, unless the queried table doesn't exist - which is
+/// expected for tables missing on some clusters, e.g. `system_virtual_schema.*` before
+/// Cassandra 4.0.
+fn warn_schema_query_error(query: &str, error: &QueryError) {
+    if !matches!(error, QueryError::DbError(DbError::Invalid, _)) {
+        tracing::warn!("Failed to fetch the schema with {:?}: {}", query, error);
+    }
+}
+
+/// Fetches the raw rows of a schema table. A missing table yields no rows,
+/// other failures are logged.
+async fn fetch_schema_fields(session: &Session, table: &str) -> Vec<CassMetaFields> {
+    let query = format!("SELECT * FROM {}", table);
+    let result = match session.query_unpaged(query.as_str(), ()).await {
+        Ok(result) => result,
+        Err(err) => {
+            warn_schema_query_error(&query, &err);
+            return Vec::new();
+        }
+    };
+
+    match CassResult::from_result_payload(result, PagingStateResponse::NoMorePages, None) {
+        Ok(CassResult {
+            kind: CassResultKind::Rows(rows_result),
+            ..
+        }) => rows_result
+            .rows
+            .into_iter()
+            .map(CassMetaFields::new)
+            .collect(),
+        Ok(_) => Vec::new(),
+        Err(err) => {
+            tracing::warn!("Failed to deserialize the result of {:?}: {}", query, err);
+            Vec::new()
+        }
+    }
+}
+
+/// Raw rows of a schema table, keyed by the names of the schema objects they describe.
+struct SchemaFields(HashMap<Vec<String>, CassMetaFields>);
+
+impl SchemaFields {
+    fn new(
+        rows: &mut SchemaRows,
+        table: &str,
+        key: impl Fn(&CassMetaFields) -> Option<Vec<String>>,
+    ) -> Self {
+        SchemaFields(
+            rows.take(table)
+                .into_iter()
+                .filter_map(|fields| match key(&fields) {
+                    Some(key) => Some((key, fields)),
+                    None => {
+                        tracing::warn!("Skipping a row of {} without the object's name", table);
+                        None
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    fn take(&mut self, key: &[&str]) -> CassMetaFields {
+        let key: Vec<String> = key.iter().map(|part| (*part).to_owned()).collect();
+        self.0.remove(&key).unwrap_or_default()
+    }
+
+    /// Takes the fields of all objects whose key starts with `prefix`.
+    fn take_prefixed(&mut self, prefix: &[&str]) -> Vec<CassMetaFields> {
+        let keys: Vec<Vec<String>> = self
+            .0
+            .keys()
+            .filter(|key| key.len() >= prefix.len() && key.iter().zip(prefix).all(|(a, b)| a == *b))
+            .cloned()
+            .collect();
+
+        keys.iter().filter_map(|key| self.0.remove(key)).collect()
+    }
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use rusty_fork::rusty_fork_test;
-    use scylla::transport::errors::DbError;
+    use scylla::frame::response::result::ColumnType;
+    use scylla::transport::errors::{ConnectionError, ConnectionPoolError, DbError, MetadataError};
     use scylla_proxy::{
         Condition, Node, Proxy, Reaction, RequestFrame, RequestOpcode, RequestReaction,
-        RequestRule, ResponseFrame, RunningProxy,
+        RequestRule, ResponseFrame, ResponseOpcode, RunningProxy,
     };
     use tracing::instrument::WithSubscriber;
 
@@ -609,6 +1288,11 @@ mod tests {
         future::{
             cass_future_error_code, cass_future_error_message, cass_future_free, cass_future_wait,
         },
+        query_result::{
+            cass_iterator_error_details_from_future, cass_iterator_free,
+            cass_iterator_get_error_detail, cass_iterator_next,
+        },
+        request_history::{cass_error_detail_error_code, cass_error_detail_host},
         retry_policy::{cass_retry_policy_default_new, cass_retry_policy_fallthrough_new},
         statement::{cass_statement_free, cass_statement_new, cass_statement_set_retry_policy},
         testing::assert_cass_error_eq,
@@ -642,7 +1326,7 @@ mod tests {
         cass_future_free(fut);
     }
 
-    fn handshake_rules() -> impl IntoIterator<Item = RequestRule> {
+    pub(crate) fn handshake_rules() -> impl IntoIterator<Item = RequestRule> {
         [
             RequestRule(
                 Condition::RequestOpcode(RequestOpcode::Options),
@@ -661,7 +1345,7 @@ mod tests {
     }
 
     // As these are very generic, they should be put last in the rules Vec.
-    fn generic_drop_queries_rules() -> impl IntoIterator<Item = RequestRule> {
+    pub(crate) fn generic_drop_queries_rules() -> impl IntoIterator<Item = RequestRule> {
         [RequestRule(
             Condition::RequestOpcode(RequestOpcode::Query),
             // We won't respond to any queries (including metadata fetch),
@@ -697,6 +1381,194 @@ mod tests {
         let _ = proxy.finish().await;
     }
 
+    /// Metadata of a prepared statement, which the proxy responds with to PREPARE requests.
+    pub(crate) struct ForgedPrepared {
+        pub(crate) id: &'static [u8],
+        /// Bound variables of `ks.tbl` table.
+        pub(crate) variables: Vec<(&'static str, ColumnType<'static>)>,
+        /// Indexes of the variables which make up the partition key,
+        /// in the partition key order.
+        pub(crate) pk_indexes: Vec<u16>,
+        /// Columns of the result set of `ks.tbl` table.
+        pub(crate) result_columns: Vec<(&'static str, ColumnType<'static>)>,
+        pub(crate) is_lwt: bool,
+    }
+
+    // Bit mask of the LWT flag in the prepared metadata flags, advertised by the proxy.
+    const LWT_FLAG_MASK: u32 = 0x8000_0000;
+
+    impl ForgedPrepared {
+        /// Serializes the body of the RESULT (Prepared) response, as of CQL protocol v4.
+        fn response_body(&self) -> Vec<u8> {
+            fn write_string(buf: &mut Vec<u8>, s: &str) {
+                buf.extend((s.len() as u16).to_be_bytes());
+                buf.extend(s.as_bytes());
+            }
+            fn write_type(buf: &mut Vec<u8>, typ: &ColumnType) {
+                match typ {
+                    ColumnType::BigInt => buf.extend(0x0002u16.to_be_bytes()),
+                    ColumnType::Boolean => buf.extend(0x0004u16.to_be_bytes()),
+                    ColumnType::Int => buf.extend(0x0009u16.to_be_bytes()),
+                    ColumnType::Text => buf.extend(0x000Du16.to_be_bytes()),
+                    ColumnType::List(elem_type) => {
+                        buf.extend(0x0020u16.to_be_bytes());
+                        write_type(buf, elem_type);
+                    }
+                    ColumnType::UserDefinedType {
+                        type_name,
+                        keyspace,
+                        field_types,
+                    } => {
+                        buf.extend(0x0030u16.to_be_bytes());
+                        write_string(buf, keyspace);
+                        write_string(buf, type_name);
+                        buf.extend((field_types.len() as u16).to_be_bytes());
+                        for (field_name, field_type) in field_types {
+                            write_string(buf, field_name);
+                            write_type(buf, field_type);
+                        }
+                    }
+                    other => unimplemented!("Forging {:?} is not supported", other),
+                }
+            }
+            // [int flags][int columns_count] ... [global_table_spec] [col_spec]*
+            fn write_columns(buf: &mut Vec<u8>, columns: &[(&str, ColumnType)]) {
+                write_string(buf, "ks");
+                write_string(buf, "tbl");
+                for (name, typ) in columns {
+                    write_string(buf, name);
+                    write_type(buf, typ);
+                }
+            }
+            const GLOBAL_TABLES_SPEC: u32 = 0x0001;
+            const NO_METADATA: u32 = 0x0004;
+
+            let mut body = Vec::new();
+            // Prepared result kind.
+            body.extend(0x0004i32.to_be_bytes());
+            body.extend((self.id.len() as u16).to_be_bytes());
+            body.extend(self.id);
+
+            let mut flags = GLOBAL_TABLES_SPEC;
+            if self.is_lwt {
+                flags |= LWT_FLAG_MASK;
+            }
+            body.extend(flags.to_be_bytes());
+            body.extend((self.variables.len() as i32).to_be_bytes());
+            body.extend((self.pk_indexes.len() as i32).to_be_bytes());
+            for pk_index in &self.pk_indexes {
+                body.extend(pk_index.to_be_bytes());
+            }
+            write_columns(&mut body, &self.variables);
+
+            if self.result_columns.is_empty() {
+                body.extend(NO_METADATA.to_be_bytes());
+                body.extend(0i32.to_be_bytes());
+            } else {
+                body.extend(GLOBAL_TABLES_SPEC.to_be_bytes());
+                body.extend((self.result_columns.len() as i32).to_be_bytes());
+                write_columns(&mut body, &self.result_columns);
+            }
+            body
+        }
+    }
+
+    /// Prepares the statement against a proxy, which responds with the forged metadata.
+    pub(crate) async fn prepare_with_forged_metadata(
+        statement: &str,
+        forged: ForgedPrepared,
+    ) -> scylla::prepared_statement::PreparedStatement {
+        let body = forged.response_body();
+        let supported = HashMap::from([(
+            "SCYLLA_LWT_ADD_METADATA_MARK".to_owned(),
+            vec![format!("LWT_OPTIMIZATION_META_BIT_MASK={}", LWT_FLAG_MASK)],
+        )]);
+        let rules = [
+            RequestRule(
+                Condition::RequestOpcode(RequestOpcode::Options),
+                RequestReaction::forge_response(Arc::new(move |frame: RequestFrame| {
+                    ResponseFrame::forged_supported(frame.params, &supported).unwrap()
+                })),
+            ),
+            RequestRule(
+                Condition::RequestOpcode(RequestOpcode::Prepare),
+                RequestReaction::forge_response(Arc::new(move |frame: RequestFrame| {
+                    ResponseFrame {
+                        params: frame.params.for_response(),
+                        opcode: ResponseOpcode::Result,
+                        body: body.clone().into(),
+                    }
+                })),
+            ),
+        ]
+        .into_iter()
+        // The OPTIONS rule above takes precedence over the handshake one.
+        .chain(handshake_rules())
+        .chain(generic_drop_queries_rules());
+
+        let (proxy_addr, proxy) = run_dry_proxy(rules).await;
+        let session = SessionBuilder::new()
+            .known_node_addr(proxy_addr)
+            .build()
+            .await
+            .unwrap();
+        let prepared = session.prepare(statement).await.unwrap();
+
+        let _ = proxy.finish().await;
+        prepared
+    }
+
+    /// Returns the error of the driver's failed connection to a node,
+    /// which responds to the `request` of the connection setup with `reaction`.
+    pub(crate) async fn connection_setup_error(
+        request: RequestOpcode,
+        reaction: RequestReaction,
+    ) -> ConnectionError {
+        let rules = iter::once(RequestRule(Condition::RequestOpcode(request), reaction))
+            .chain(handshake_rules())
+            .chain(generic_drop_queries_rules());
+
+        let (proxy_addr, proxy) = run_dry_proxy(rules).await;
+        let session = SessionBuilder::new()
+            .known_node_addr(proxy_addr)
+            .build()
+            .await;
+        let _ = proxy.finish().await;
+
+        match session {
+            Err(
+                NewSessionError::MetadataError(MetadataError::ConnectionPoolError(
+                    ConnectionPoolError::Broken {
+                        last_connection_error,
+                    },
+                ))
+                | NewSessionError::ConnectionPoolError(ConnectionPoolError::Broken {
+                    last_connection_error,
+                }),
+            ) => last_connection_error,
+            Err(err) => panic!("Unexpected error: {}", err),
+            Ok(_) => panic!("The session connected, despite the failed setup request"),
+        }
+    }
+
+    async fn run_dry_proxy(
+        rules: impl IntoIterator<Item = RequestRule>,
+    ) -> (SocketAddr, RunningProxy) {
+        let proxy_addr = SocketAddr::new(scylla_proxy::get_exclusive_local_address(), 9042);
+        let proxy = Proxy::builder()
+            .with_node(
+                Node::builder()
+                    .proxy_address(proxy_addr)
+                    .request_rules(rules.into_iter().collect())
+                    .build_dry_mode(),
+            )
+            .build()
+            .run()
+            .await
+            .unwrap();
+        (proxy_addr, proxy)
+    }
+
     #[tokio::test]
     #[ntest::timeout(5000)]
     async fn session_clones_and_freezes_exec_profiles_mapping() {
@@ -1316,4 +2188,166 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    #[ntest::timeout(5000)]
+    fn connect_error_details_list_contact_points() {
+        // IPs with very little chance of having a Scylla node listening.
+        // The driver reports a single error for the session, which is attributed
+        // to each contact point.
+        for (contact_points, expected_hosts) in [
+            ("127.0.1.232", &["127.0.1.232:9042"][..]),
+            (
+                "127.0.1.232,127.0.1.233",
+                &["127.0.1.232:9042", "127.0.1.233:9042"][..],
+            ),
+        ] {
+            let (c_ips, c_ips_len) = str_to_c_str_n(contact_points);
+
+            unsafe {
+                let cluster_raw = cass_cluster_new();
+                assert_cass_error_eq!(
+                    cass_cluster_set_contact_points_n(cluster_raw, c_ips, c_ips_len),
+                    CassError::CASS_OK
+                );
+                let session_raw = cass_session_new();
+
+                let cass_future = cass_session_connect(session_raw, cluster_raw);
+                assert_ne!(cass_future_error_code(cass_future), CassError::CASS_OK);
+
+                let mut hosts = Vec::new();
+                let iterator = cass_iterator_error_details_from_future(cass_future);
+                while cass_iterator_next(iterator) == true as cass_bool_t {
+                    let detail = cass_iterator_get_error_detail(iterator);
+                    assert_ne!(cass_error_detail_error_code(detail), CassError::CASS_OK);
+
+                    let mut host: *const c_char = std::ptr::null();
+                    let mut host_len: size_t = 0;
+                    cass_error_detail_host(detail, &mut host, &mut host_len);
+                    hosts.push(ptr_to_cstr_n(host, host_len).unwrap().to_owned());
+                }
+                assert_eq!(hosts, expected_hosts);
+
+                cass_iterator_free(iterator);
+                cass_future_free(cass_future);
+                cass_session_free(session_raw);
+                cass_cluster_free(cluster_raw);
+            }
+        }
+    }
+
+    #[tokio::test]
+    #[ntest::timeout(5000)]
+    async fn connect_fails_if_protocol_is_rejected() {
+        init_logger();
+        test_with_one_proxy_one(
+            connect_fails_if_protocol_is_rejected_do,
+            [RequestRule(
+                Condition::RequestOpcode(RequestOpcode::Options),
+                RequestReaction::forge().protocol_error(),
+            )],
+        )
+        .with_current_subscriber()
+        .await;
+    }
+
+    fn connect_fails_if_protocol_is_rejected_do(
+        node_addr: SocketAddr,
+        proxy: RunningProxy,
+    ) -> RunningProxy {
+        unsafe {
+            let cluster_raw = cass_cluster_new();
+            let ip = node_addr.ip().to_string();
+            let (c_ip, c_ip_len) = str_to_c_str_n(ip.as_str());
+
+            assert_cass_error_eq!(
+                cass_cluster_set_contact_points_n(cluster_raw, c_ip, c_ip_len),
+                CassError::CASS_OK
+            );
+            let session_raw = cass_session_new();
+
+            // The connection setup fails with a protocol error.
+            let cass_future = cass_session_connect(session_raw, cluster_raw);
+            assert_cass_error_eq!(
+                cass_future_error_code(cass_future),
+                CassError::CASS_ERROR_LIB_UNABLE_TO_DETERMINE_PROTOCOL
+            );
+
+            cass_future_free(cass_future);
+            cass_session_free(session_raw);
+            cass_cluster_free(cluster_raw);
+        }
+
+        proxy
+    }
+
+    #[test]
+    fn schema_change_statement_detection() {
+        for statement in [
+            "CREATE TABLE ks.t (a int PRIMARY KEY)",
+            "  alter keyspace ks WITH durable_writes = false",
+            "DROP INDEX ks.idx;",
+            "-- comment\n  Create TYPE ks.address (street text)",
+            "/* multi\nline */ DROP TABLE ks.t",
+            "// comment\nCREATE KEYSPACE ks WITH replication = {}",
+            "create materialized view ks.mv AS SELECT * FROM ks.t",
+            "CREATE CUSTOM INDEX ON ks.t (v) USING 'StorageAttachedIndex'",
+            "CREATE OR REPLACE FUNCTION ks.f (a int) RETURNS NULL ON NULL INPUT RETURNS int LANGUAGE lua AS 'return a'",
+            "DROP /* comment */ AGGREGATE IF EXISTS ks.agg",
+            "ALTER COLUMNFAMILY ks.t ADD v int",
+        ] {
+            assert!(is_schema_change_statement(statement), "{}", statement);
+        }
+
+        for statement in [
+            "SELECT * FROM ks.t",
+            "INSERT INTO ks.create (a) VALUES (1)",
+            "CREATED",
+            "-- DROP TABLE ks.t",
+            "/* CREATE */",
+            "",
+            "CREATE ROLE r WITH PASSWORD = 'p' AND LOGIN = true",
+            "ALTER ROLE r WITH SUPERUSER = false",
+            "DROP ROLE IF EXISTS r",
+            "CREATE USER u WITH PASSWORD 'p'",
+            "drop user u",
+            "CREATE SERVICE_LEVEL sl",
+            "GRANT SELECT ON ks.t TO r",
+            "CREATE OR",
+            "CREATE OR ALTER TABLE ks.t",
+        ] {
+            assert!(!is_schema_change_statement(statement), "{}", statement);
+        }
+    }
+
+    #[test]
+    fn views_are_attached_to_their_base_table() {
+        let view = |base_table_name: &str| MaterializedView {
+            view_metadata: scylla::transport::topology::Table {
+                columns: HashMap::new(),
+                partition_key: Vec::new(),
+                clustering_key: Vec::new(),
+                partitioner: None,
+            },
+            base_table_name: base_table_name.to_owned(),
+        };
+        let views: HashMap<String, MaterializedView> = [
+            ("t1_by_a".to_owned(), view("t1")),
+            ("t1_by_b".to_owned(), view("t1")),
+            ("t2_by_a".to_owned(), view("t2")),
+        ]
+        .into_iter()
+        .collect();
+
+        let view_names = |table_name: &str| {
+            let mut names: Vec<&str> = views_of_table(&views, table_name)
+                .map(|(view_name, _)| view_name.as_str())
+                .collect();
+            names.sort_unstable();
+            names
+        };
+        assert_eq!(vec!["t1_by_a", "t1_by_b"], view_names("t1"));
+        assert_eq!(vec!["t2_by_a"], view_names("t2"));
+        assert!(view_names("t3").is_empty());
+    }
 }
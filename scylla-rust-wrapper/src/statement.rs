@@ -169,7 +169,7 @@ pub struct SimpleQueryRowSerializer {
 
 #[derive(Debug, Error)]
 #[error("Unknown named parameter \"{0}\"")]
-pub struct UnknownNamedParameterError(String);
+pub struct UnknownNamedParameterError(pub(crate) String);
 
 impl SerializeRow for SimpleQueryRowSerializer {
     fn serialize(
@@ -214,6 +214,10 @@ pub struct CassStatement {
     pub request_timeout_ms: Option<cass_uint64_t>,
 
     pub(crate) exec_profile: Option<PerStatementExecProfile>,
+
+    // Whether to expose the attempts, speculative fibers and retry decisions
+    // made when executing the statement.
+    pub(crate) record_execution_history: bool,
 }
 
 impl BoxFFI for CassStatement {}
@@ -292,6 +296,7 @@ pub unsafe extern "C" fn cass_statement_new_n(
         paging_enabled: false,
         request_timeout_ms: None,
         exec_profile: None,
+        record_execution_history: false,
     }))
 }
 
@@ -490,6 +495,16 @@ pub unsafe extern "C" fn cass_statement_set_timestamp(
     CassError::CASS_OK
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn cass_statement_set_execution_history(
+    statement_raw: *mut CassStatement,
+    enabled: cass_bool_t,
+) -> CassError {
+    BoxFFI::as_mut_ref(statement_raw).record_execution_history = enabled != 0;
+
+    CassError::CASS_OK
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn cass_statement_set_request_timeout(
     statement: *mut CassStatement,
@@ -616,6 +631,18 @@ make_binders!(
     cass_statement_bind_decimal_by_name,
     cass_statement_bind_decimal_by_name_n
 );
+make_binders!(
+    varint_string,
+    cass_statement_bind_varint_string,
+    cass_statement_bind_varint_string_by_name,
+    cass_statement_bind_varint_string_by_name_n
+);
+make_binders!(
+    decimal_string,
+    cass_statement_bind_decimal_string,
+    cass_statement_bind_decimal_string_by_name,
+    cass_statement_bind_decimal_string_by_name_n
+);
 make_binders!(
     collection,
     cass_statement_bind_collection,
@@ -634,3 +661,123 @@ make_binders!(
     cass_statement_bind_user_type_by_name,
     cass_statement_bind_user_type_by_name_n
 );
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use scylla::frame::response::result::ColumnType;
+
+    use super::*;
+    use crate::cass_types::{CassDataType, CassDataTypeInner, CassValueType, UDTDataType};
+    use crate::prepared::{cass_prepared_bind, cass_prepared_free};
+    use crate::session::tests::{prepare_with_forged_metadata, ForgedPrepared};
+    use crate::testing::assert_cass_error_eq;
+    use crate::user_type::{
+        cass_user_type_free, cass_user_type_new_from_data_type, cass_user_type_set_int32_by_name,
+    };
+
+    #[tokio::test]
+    #[ntest::timeout(5000)]
+    async fn bind_user_type_by_field_name() {
+        // Server's definition: `CREATE TYPE ks.udt (a int, b int, c int)`.
+        let prepared = prepare_with_forged_metadata(
+            "INSERT INTO ks.tbl (pk, v) VALUES (?, ?)",
+            ForgedPrepared {
+                id: b"udt_insert",
+                variables: vec![
+                    ("pk", ColumnType::Int),
+                    (
+                        "v",
+                        ColumnType::UserDefinedType {
+                            type_name: "udt".into(),
+                            keyspace: "ks".into(),
+                            field_types: vec![
+                                ("a".into(), ColumnType::Int),
+                                ("b".into(), ColumnType::Int),
+                                ("c".into(), ColumnType::Int),
+                            ],
+                        },
+                    ),
+                ],
+                pk_indexes: vec![0],
+                result_columns: vec![],
+                is_lwt: false,
+            },
+        )
+        .await;
+        let prepared_raw = ArcFFI::into_ptr(Arc::new(CassPrepared::new_from_prepared_statement(
+            prepared,
+        )));
+
+        let value_type = |typ| CassDataType::new_arced(CassDataTypeInner::Value(typ));
+        let int_type = value_type(CassValueType::CASS_VALUE_TYPE_INT);
+        let text_type = value_type(CassValueType::CASS_VALUE_TYPE_TEXT);
+        let client_udt_type = |fields: &[(&str, &Arc<CassDataType>)]| {
+            CassDataType::new_arced(CassDataTypeInner::UDT(UDTDataType {
+                field_types: fields
+                    .iter()
+                    .map(|(name, typ)| (name.to_string(), Arc::clone(typ)))
+                    .collect(),
+                keyspace: "ks".to_owned(),
+                name: "udt".to_owned(),
+                frozen: false,
+            }))
+        };
+
+        let cases = [
+            // Fields in a different order than the server's.
+            (
+                client_udt_type(&[("c", &int_type), ("a", &int_type), ("b", &int_type)]),
+                CassError::CASS_OK,
+            ),
+            // Trailing field missing, e.g. added by ALTER TYPE.
+            (
+                client_udt_type(&[("a", &int_type), ("b", &int_type)]),
+                CassError::CASS_OK,
+            ),
+            // Unknown field.
+            (
+                client_udt_type(&[("a", &int_type), ("d", &int_type)]),
+                CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+            ),
+            // Field of a different type.
+            (
+                client_udt_type(&[("b", &text_type)]),
+                CassError::CASS_ERROR_LIB_INVALID_VALUE_TYPE,
+            ),
+        ];
+
+        unsafe {
+            let statement_raw = cass_prepared_bind(prepared_raw);
+
+            for (data_type, expected) in cases {
+                let user_type_raw = cass_user_type_new_from_data_type(ArcFFI::as_ptr(&data_type));
+                if let CassDataTypeInner::UDT(udt) = data_type.get_unchecked() {
+                    for (index, (name, typ)) in udt.field_types.iter().enumerate() {
+                        if Arc::ptr_eq(typ, &int_type) {
+                            let name = CString::new(name.as_str()).unwrap();
+                            assert_cass_error_eq!(
+                                cass_user_type_set_int32_by_name(
+                                    user_type_raw,
+                                    name.as_ptr(),
+                                    index as i32
+                                ),
+                                CassError::CASS_OK
+                            );
+                        }
+                    }
+                }
+
+                assert_cass_error_eq!(
+                    cass_statement_bind_user_type(statement_raw, 1, user_type_raw),
+                    expected
+                );
+                cass_user_type_free(user_type_raw);
+            }
+
+            cass_statement_free(statement_raw);
+            cass_prepared_free(prepared_raw);
+        }
+    }
+}
@@ -1,3 +1,13 @@
+use crate::cass_types::{CassColumnSpec, CassDataType};
+use crate::metadata::CassMetaFields;
+use crate::query_result::{
+    create_cass_rows_from_rows, get_column_value, CassResult, CassResultKind, CassResultMetadata,
+    CassRow, CassRowsResult, CassValue,
+};
+use scylla::frame::response::result::{ColumnSpec, ColumnType, CqlValue, Row, TableSpec};
+use scylla::transport::PagingStateResponse;
+use std::sync::Arc;
+
 macro_rules! assert_cass_error_eq {
     ($expr:expr, $error:expr $(,)?) => {{
         use crate::argconv::ptr_to_cstr;
@@ -26,3 +36,56 @@ macro_rules! assert_cass_future_error_message_eq {
     };
 }
 pub(crate) use assert_cass_future_error_message_eq;
+
+/// Creates raw schema fields, as if they were fetched from a `system_schema` table.
+pub(crate) fn make_fields(
+    columns: Vec<(&str, Arc<CassDataType>, Option<CqlValue>)>,
+) -> CassMetaFields {
+    let metadata = Arc::new(CassResultMetadata {
+        col_specs: columns
+            .iter()
+            .map(|(name, data_type, _)| CassColumnSpec {
+                name: (*name).to_owned(),
+                data_type: data_type.clone(),
+            })
+            .collect(),
+    });
+
+    CassMetaFields::new(CassRow {
+        columns: columns
+            .into_iter()
+            .map(|(_, data_type, value)| CassValue {
+                value: value.map(|value| get_column_value(value, &data_type)),
+                value_type: data_type,
+            })
+            .collect(),
+        result_metadata: metadata,
+    })
+}
+
+/// Creates a rows result with the given columns of `ks.tbl` table,
+/// as if it was received from the server.
+pub(crate) fn make_rows_result(
+    columns: &[(&'static str, ColumnType<'static>)],
+    rows: Vec<Vec<Option<CqlValue>>>,
+) -> CassResult {
+    let col_specs: Vec<_> = columns
+        .iter()
+        .map(|(name, typ)| {
+            ColumnSpec::borrowed(name, typ.clone(), TableSpec::borrowed("ks", "tbl"))
+        })
+        .collect();
+    let metadata = Arc::new(CassResultMetadata::from_column_specs(&col_specs));
+
+    let rows = create_cass_rows_from_rows(
+        rows.into_iter().map(|columns| Row { columns }).collect(),
+        &metadata,
+    );
+
+    CassResult {
+        tracing_id: None,
+        paging_state_response: PagingStateResponse::NoMorePages,
+        kind: CassResultKind::Rows(CassRowsResult { rows, metadata }),
+        schema_in_agreement: true,
+    }
+}
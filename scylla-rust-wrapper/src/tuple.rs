@@ -113,6 +113,8 @@ make_binders!(uuid, cass_tuple_set_uuid);
 make_binders!(inet, cass_tuple_set_inet);
 make_binders!(duration, cass_tuple_set_duration);
 make_binders!(decimal, cass_tuple_set_decimal);
+make_binders!(varint_string, cass_tuple_set_varint_string);
+make_binders!(decimal_string, cass_tuple_set_decimal_string);
 make_binders!(collection, cass_tuple_set_collection);
 make_binders!(tuple, cass_tuple_set_tuple);
 make_binders!(user_type, cass_tuple_set_user_type);
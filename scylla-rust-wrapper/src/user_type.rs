@@ -207,6 +207,18 @@ make_binders!(
     cass_user_type_set_decimal_by_name,
     cass_user_type_set_decimal_by_name_n
 );
+make_binders!(
+    varint_string,
+    cass_user_type_set_varint_string,
+    cass_user_type_set_varint_string_by_name,
+    cass_user_type_set_varint_string_by_name_n
+);
+make_binders!(
+    decimal_string,
+    cass_user_type_set_decimal_string,
+    cass_user_type_set_decimal_string_by_name,
+    cass_user_type_set_decimal_string_by_name_n
+);
 make_binders!(
     collection,
     cass_user_type_set_collection,
@@ -3,7 +3,7 @@ use std::{convert::TryInto, net::IpAddr, sync::Arc};
 use scylla::{
     frame::{
         response::result::ColumnType,
-        value::{CqlDate, CqlDecimal, CqlDuration},
+        value::{CqlDate, CqlDecimal, CqlDuration, CqlVarint},
     },
     serialize::{
         value::{
@@ -48,6 +48,7 @@ pub enum CassCqlValue {
     Inet(IpAddr),
     Duration(CqlDuration),
     Decimal(CqlDecimal),
+    Varint(CqlVarint),
     Tuple {
         data_type: Option<Arc<CassDataType>>,
         fields: Vec<Option<CassCqlValue>>,
@@ -66,9 +67,10 @@ pub enum CassCqlValue {
     },
     UserDefinedType {
         data_type: Arc<CassDataType>,
-        /// Order of `fields` vector must match the order of fields as defined in the UDT. The
-        /// driver does not check it by itself, so incorrect data will be written if the order is
-        /// wrong.
+        /// Order of `fields` vector matches the order of fields in `data_type`.
+        /// During serialization, fields are matched by name against the UDT definition
+        /// provided by the server (see `serialize_udt`), so `data_type` may
+        /// list the fields in a different order, or omit some of them.
         fields: Vec<(String, Option<CassCqlValue>)>,
     },
     // TODO: custom (?), duration and decimal
@@ -145,6 +147,9 @@ impl CassCqlValue {
             CassCqlValue::Decimal(_) => unsafe {
                 typ.get_unchecked().get_value_type() == CassValueType::CASS_VALUE_TYPE_DECIMAL
             },
+            CassCqlValue::Varint(_) => unsafe {
+                typ.get_unchecked().get_value_type() == CassValueType::CASS_VALUE_TYPE_VARINT
+            },
             CassCqlValue::Tuple { data_type, .. } => unsafe {
                 if let Some(dt) = data_type {
                     return dt.get_unchecked().typecheck_equals(typ.get_unchecked());
@@ -188,18 +193,32 @@ impl CassCqlValue {
 impl SerializeValue for CassCqlValue {
     fn serialize<'b>(
         &self,
-        _typ: &ColumnType,
+        typ: &ColumnType,
         writer: CellWriter<'b>,
     ) -> Result<WrittenCellProof<'b>, SerializationError> {
-        // _typ is not used, since we do the typechecks during binding.
+        // We don't typecheck against `typ`, since we do the typechecks during binding.
         // This is the same approach as cpp-driver.
-        self.do_serialize(writer)
+        // `typ` is only used to lay out the fields of UDT values (possibly nested
+        // in collections and tuples) according to the type definition known by the server.
+        self.do_serialize(Some(typ), writer)
     }
 }
 
 impl CassCqlValue {
+    /// Size of the value serialized as a cell, including the length prefix.
+    pub(crate) fn serialized_size(&self) -> usize {
+        let mut buf = Vec::new();
+        match self.do_serialize(None, CellWriter::new(&mut buf)) {
+            Ok(_proof) => buf.len(),
+            // Values which fail to serialize will fail the request anyway.
+            Err(_) => 0,
+        }
+    }
+
+    /// `typ` is the type expected by the server, if known.
     fn do_serialize<'b>(
         &self,
+        typ: Option<&ColumnType>,
         writer: CellWriter<'b>,
     ) -> Result<WrittenCellProof<'b>, SerializationError> {
         match self {
@@ -253,38 +272,98 @@ impl CassCqlValue {
             CassCqlValue::Decimal(v) => {
                 <CqlDecimal as SerializeValue>::serialize(v, &ColumnType::Decimal, writer)
             }
-            CassCqlValue::Tuple { fields, .. } => serialize_tuple_like(fields.iter(), writer),
+            CassCqlValue::Varint(v) => {
+                <CqlVarint as SerializeValue>::serialize(v, &ColumnType::Varint, writer)
+            }
+            CassCqlValue::Tuple { fields, .. } => {
+                let field_types = match typ {
+                    Some(ColumnType::Tuple(field_types)) => Some(field_types.as_slice()),
+                    _ => None,
+                };
+                serialize_tuple_like(fields.iter(), field_types, writer)
+            }
             CassCqlValue::List { values, .. } => {
-                serialize_sequence(values.len(), values.iter(), writer)
+                let elem_type = match typ {
+                    Some(ColumnType::List(elem_type)) => Some(elem_type.as_ref()),
+                    _ => None,
+                };
+                serialize_sequence(values.len(), values.iter(), elem_type, writer)
             }
             CassCqlValue::Map { values, .. } => {
-                serialize_mapping(values.len(), values.iter().map(|p| (&p.0, &p.1)), writer)
+                let (key_type, value_type) = match typ {
+                    Some(ColumnType::Map(key_type, value_type)) => {
+                        (Some(key_type.as_ref()), Some(value_type.as_ref()))
+                    }
+                    _ => (None, None),
+                };
+                serialize_mapping(
+                    values.len(),
+                    values.iter().map(|p| (&p.0, &p.1)),
+                    key_type,
+                    value_type,
+                    writer,
+                )
             }
             CassCqlValue::Set { values, .. } => {
-                serialize_sequence(values.len(), values.iter(), writer)
+                let elem_type = match typ {
+                    Some(ColumnType::Set(elem_type)) => Some(elem_type.as_ref()),
+                    _ => None,
+                };
+                serialize_sequence(values.len(), values.iter(), elem_type, writer)
             }
-            CassCqlValue::UserDefinedType { fields, .. } => serialize_udt(fields, writer),
+            CassCqlValue::UserDefinedType { fields, .. } => serialize_udt(fields, typ, writer),
         }
     }
 }
 
-/// Serialization of one of the built-in types failed.
+/// Serialization of a value failed.
 #[derive(Debug, Clone)]
-pub struct CassSerializationError {
-    /// Name of the Rust type being serialized.
-    pub rust_name: &'static str,
+pub enum CassSerializationError {
+    /// Serialization of one of the built-in types failed.
+    Builtin {
+        /// Name of the Rust type being serialized.
+        rust_name: &'static str,
 
-    /// Detailed information about the failure.
-    pub kind: BuiltinSerializationErrorKind,
+        /// Detailed information about the failure.
+        kind: BuiltinSerializationErrorKind,
+    },
+
+    /// UDT value has a field, which does not exist in the UDT definition
+    /// provided by the server.
+    NoSuchUdtField {
+        keyspace: String,
+        type_name: String,
+        field_name: String,
+    },
+
+    /// UDT value was serialized, but the server expects a value of other type.
+    NotUdt {
+        /// Debug representation of the expected type.
+        expected_type: String,
+    },
 }
 
 impl std::fmt::Display for CassSerializationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "Failed to serialize Rust type {}: {}",
-            self.rust_name, self.kind
-        )
+        match self {
+            CassSerializationError::Builtin { rust_name, kind } => {
+                write!(f, "Failed to serialize Rust type {}: {}", rust_name, kind)
+            }
+            CassSerializationError::NoSuchUdtField {
+                keyspace,
+                type_name,
+                field_name,
+            } => write!(
+                f,
+                "Field \"{}\" does not exist in user defined type {}.{}",
+                field_name, keyspace, type_name
+            ),
+            CassSerializationError::NotUdt { expected_type } => write!(
+                f,
+                "Tried to serialize a user defined type value, but the expected type is {}",
+                expected_type
+            ),
+        }
     }
 }
 
@@ -298,7 +377,7 @@ fn mk_ser_err_named(
     name: &'static str,
     kind: impl Into<BuiltinSerializationErrorKind>,
 ) -> SerializationError {
-    SerializationError::new(CassSerializationError {
+    SerializationError::new(CassSerializationError::Builtin {
         rust_name: name,
         kind: kind.into(),
     })
@@ -306,15 +385,17 @@ fn mk_ser_err_named(
 
 fn serialize_tuple_like<'t, 'b>(
     field_values: impl Iterator<Item = &'t Option<CassCqlValue>>,
+    field_types: Option<&[ColumnType]>,
     writer: CellWriter<'b>,
 ) -> Result<WrittenCellProof<'b>, SerializationError> {
     let mut builder = writer.into_value_builder();
 
     for (index, el) in field_values.enumerate() {
         let sub = builder.make_sub_writer();
+        let field_type = field_types.and_then(|types| types.get(index));
         match el {
             None => sub.set_null(),
-            Some(el) => el.do_serialize(sub).map_err(|err| {
+            Some(el) => el.do_serialize(field_type, sub).map_err(|err| {
                 mk_ser_err::<CassCqlValue>(
                     TupleSerializationErrorKind::ElementSerializationFailed { index, err },
                 )
@@ -330,6 +411,7 @@ fn serialize_tuple_like<'t, 'b>(
 fn serialize_sequence<'t, 'b>(
     len: usize,
     iter: impl Iterator<Item = &'t CassCqlValue>,
+    elem_type: Option<&ColumnType>,
     writer: CellWriter<'b>,
 ) -> Result<WrittenCellProof<'b>, SerializationError> {
     let rust_name = std::any::type_name::<CassCqlValue>();
@@ -342,12 +424,13 @@ fn serialize_sequence<'t, 'b>(
     builder.append_bytes(&element_count.to_be_bytes());
 
     for el in iter {
-        el.do_serialize(builder.make_sub_writer()).map_err(|err| {
-            mk_ser_err_named(
-                rust_name,
-                SetOrListSerializationErrorKind::ElementSerializationFailed(err),
-            )
-        })?;
+        el.do_serialize(elem_type, builder.make_sub_writer())
+            .map_err(|err| {
+                mk_ser_err_named(
+                    rust_name,
+                    SetOrListSerializationErrorKind::ElementSerializationFailed(err),
+                )
+            })?;
     }
 
     builder
@@ -358,6 +441,8 @@ fn serialize_sequence<'t, 'b>(
 fn serialize_mapping<'t, 'b>(
     len: usize,
     iter: impl Iterator<Item = (&'t CassCqlValue, &'t CassCqlValue)>,
+    key_type: Option<&ColumnType>,
+    value_type: Option<&ColumnType>,
     writer: CellWriter<'b>,
 ) -> Result<WrittenCellProof<'b>, SerializationError> {
     let rust_name = std::any::type_name::<CassCqlValue>();
@@ -370,18 +455,20 @@ fn serialize_mapping<'t, 'b>(
     builder.append_bytes(&element_count.to_be_bytes());
 
     for (k, v) in iter {
-        k.do_serialize(builder.make_sub_writer()).map_err(|err| {
-            mk_ser_err_named(
-                rust_name,
-                MapSerializationErrorKind::KeySerializationFailed(err),
-            )
-        })?;
-        v.do_serialize(builder.make_sub_writer()).map_err(|err| {
-            mk_ser_err_named(
-                rust_name,
-                MapSerializationErrorKind::ValueSerializationFailed(err),
-            )
-        })?;
+        k.do_serialize(key_type, builder.make_sub_writer())
+            .map_err(|err| {
+                mk_ser_err_named(
+                    rust_name,
+                    MapSerializationErrorKind::KeySerializationFailed(err),
+                )
+            })?;
+        v.do_serialize(value_type, builder.make_sub_writer())
+            .map_err(|err| {
+                mk_ser_err_named(
+                    rust_name,
+                    MapSerializationErrorKind::ValueSerializationFailed(err),
+                )
+            })?;
     }
 
     builder
@@ -389,18 +476,83 @@ fn serialize_mapping<'t, 'b>(
         .map_err(|_| mk_ser_err_named(rust_name, BuiltinSerializationErrorKind::SizeOverflow))
 }
 
+/// Serializes the UDT value.
+///
+/// If the UDT definition provided by the server is known, fields are matched by name against it.
+/// This way, values are serialized correctly even if the client-side type lists the fields in
+/// a different order, or lacks some of them (e.g. fields added with ALTER TYPE) - the missing fields
+/// are filled with nulls. Fields which are not present in the server's definition are rejected.
+///
+/// Otherwise, the fields are serialized in the order of the client-side type.
 fn serialize_udt<'b>(
     values: &[(String, Option<CassCqlValue>)],
+    typ: Option<&ColumnType>,
+    writer: CellWriter<'b>,
+) -> Result<WrittenCellProof<'b>, SerializationError> {
+    let Some(typ) = typ else {
+        return serialize_udt_fields(
+            values
+                .iter()
+                .map(|(fname, fvalue)| (fname.as_str(), fvalue.as_ref(), None)),
+            writer,
+        );
+    };
+
+    let ColumnType::UserDefinedType {
+        type_name,
+        keyspace,
+        field_types,
+    } = typ
+    else {
+        return Err(SerializationError::new(CassSerializationError::NotUdt {
+            expected_type: format!("{:?}", typ),
+        }));
+    };
+
+    if let Some((unknown_field, _)) = values.iter().find(|(fname, _)| {
+        !field_types
+            .iter()
+            .any(|(db_fname, _)| **db_fname == **fname)
+    }) {
+        return Err(SerializationError::new(
+            CassSerializationError::NoSuchUdtField {
+                keyspace: keyspace.to_string(),
+                type_name: type_name.to_string(),
+                field_name: unknown_field.clone(),
+            },
+        ));
+    }
+
+    serialize_udt_fields(
+        field_types.iter().map(|(db_fname, db_ftype)| {
+            let fvalue = values
+                .iter()
+                .find(|(fname, _)| **db_fname == **fname)
+                .and_then(|(_, fvalue)| fvalue.as_ref());
+            (&**db_fname, fvalue, Some(db_ftype))
+        }),
+        writer,
+    )
+}
+
+fn serialize_udt_fields<'t, 'b>(
+    fields: impl Iterator<
+        Item = (
+            &'t str,
+            Option<&'t CassCqlValue>,
+            Option<&'t ColumnType<'t>>,
+        ),
+    >,
     writer: CellWriter<'b>,
 ) -> Result<WrittenCellProof<'b>, SerializationError> {
     let mut builder = writer.into_value_builder();
-    for (fname, fvalue) in values {
+    for (fname, fvalue, ftype) in fields {
         let writer = builder.make_sub_writer();
         match fvalue {
             None => writer.set_null(),
-            Some(v) => v.do_serialize(writer).map_err(|err| {
+            Some(v) => v.do_serialize(ftype, writer).map_err(|err| {
                 mk_ser_err::<CassCqlValue>(UdtSerializationErrorKind::FieldSerializationFailed {
-                    field_name: fname.clone(),
+                    field_name: fname.to_owned(),
                     err,
                 })
             })?,
@@ -416,7 +568,11 @@ fn serialize_udt<'b>(
 mod tests {
     use std::{net::Ipv4Addr, sync::Arc};
 
-    use scylla::frame::value::{CqlDate, CqlDecimal, CqlDuration};
+    use scylla::frame::response::result::ColumnType;
+    use scylla::frame::value::{CqlDate, CqlDecimal, CqlDuration, CqlVarint};
+    use scylla::serialize::value::SerializeValue;
+    use scylla::serialize::writers::CellWriter;
+    use scylla::serialize::SerializationError;
 
     use crate::{
         cass_types::{CassDataType, CassDataTypeInner, CassValueType, MapDataType, UDTDataType},
@@ -564,6 +720,13 @@ mod tests {
                 )),
                 compatible_types: vec![from(CassValueType::CASS_VALUE_TYPE_DECIMAL)],
             },
+            // CqlVarint -> varint
+            TestCase {
+                value: Some(CassCqlValue::Varint(CqlVarint::from_signed_bytes_be(vec![
+                    0x00,
+                ]))),
+                compatible_types: vec![from(CassValueType::CASS_VALUE_TYPE_VARINT)],
+            },
         ];
         let all_simple_types = all_value_data_types();
 
@@ -1047,4 +1210,147 @@ mod tests {
             run_test_cases(test_cases)
         }
     }
+
+    fn serialize_to_bytes(
+        value: &CassCqlValue,
+        typ: &ColumnType,
+    ) -> Result<Vec<u8>, SerializationError> {
+        let mut data = Vec::new();
+        <CassCqlValue as SerializeValue>::serialize(value, typ, CellWriter::new(&mut data))?;
+        Ok(data)
+    }
+
+    fn int_field_bytes(v: i32) -> Vec<u8> {
+        let mut bytes = 4i32.to_be_bytes().to_vec();
+        bytes.extend_from_slice(&v.to_be_bytes());
+        bytes
+    }
+
+    #[test]
+    fn serialize_udt_by_name_test() {
+        let int_type =
+            CassDataType::new_arced(CassDataTypeInner::Value(CassValueType::CASS_VALUE_TYPE_INT));
+        let client_udt_type = |field_names: &[&str]| {
+            CassDataType::new_arced(CassDataTypeInner::UDT(UDTDataType {
+                field_types: field_names
+                    .iter()
+                    .map(|name| (name.to_string(), int_type.clone()))
+                    .collect(),
+                keyspace: "ks".to_owned(),
+                name: "udt".to_owned(),
+                frozen: false,
+            }))
+        };
+        let udt_value = |fields: &[(&str, Option<i32>)]| CassCqlValue::UserDefinedType {
+            data_type: client_udt_type(&fields.iter().map(|(n, _)| *n).collect::<Vec<_>>()),
+            fields: fields
+                .iter()
+                .map(|(name, v)| (name.to_string(), v.map(CassCqlValue::Int)))
+                .collect(),
+        };
+
+        // Server's definition: `CREATE TYPE ks.udt (a int, b int, c int)`.
+        let server_udt_type = ColumnType::UserDefinedType {
+            type_name: "udt".into(),
+            keyspace: "ks".into(),
+            field_types: vec![
+                ("a".into(), ColumnType::Int),
+                ("b".into(), ColumnType::Int),
+                ("c".into(), ColumnType::Int),
+            ],
+        };
+
+        let expected_udt_bytes = |fields: &[Option<i32>]| {
+            let mut content = Vec::new();
+            for field in fields {
+                match field {
+                    Some(v) => content.extend(int_field_bytes(*v)),
+                    None => content.extend_from_slice(&(-1i32).to_be_bytes()),
+                }
+            }
+            let mut bytes = (content.len() as i32).to_be_bytes().to_vec();
+            bytes.extend(content);
+            bytes
+        };
+
+        // Fields in the server's order.
+        {
+            let value = udt_value(&[("a", Some(1)), ("b", Some(2)), ("c", Some(3))]);
+            assert_eq!(
+                expected_udt_bytes(&[Some(1), Some(2), Some(3)]),
+                serialize_to_bytes(&value, &server_udt_type).unwrap()
+            );
+        }
+
+        // Fields in a different order are reordered.
+        {
+            let value = udt_value(&[("c", Some(3)), ("a", Some(1)), ("b", Some(2))]);
+            assert_eq!(
+                expected_udt_bytes(&[Some(1), Some(2), Some(3)]),
+                serialize_to_bytes(&value, &server_udt_type).unwrap()
+            );
+        }
+
+        // Missing fields (e.g. added by ALTER TYPE) are filled with nulls.
+        {
+            let value = udt_value(&[("b", Some(2))]);
+            assert_eq!(
+                expected_udt_bytes(&[None, Some(2), None]),
+                serialize_to_bytes(&value, &server_udt_type).unwrap()
+            );
+        }
+
+        // UDT nested in a list is matched against the nested type.
+        {
+            let value = CassCqlValue::List {
+                data_type: None,
+                values: vec![udt_value(&[("c", Some(3)), ("a", Some(1))])],
+            };
+            let list_type = ColumnType::List(Box::new(server_udt_type.clone()));
+
+            let udt_bytes = expected_udt_bytes(&[Some(1), None, Some(3)]);
+            let mut content = 1i32.to_be_bytes().to_vec();
+            content.extend(udt_bytes);
+            let mut expected = (content.len() as i32).to_be_bytes().to_vec();
+            expected.extend(content);
+
+            assert_eq!(expected, serialize_to_bytes(&value, &list_type).unwrap());
+        }
+
+        // Unknown fields are rejected.
+        {
+            let value = udt_value(&[("a", Some(1)), ("d", Some(4))]);
+            let err = serialize_to_bytes(&value, &server_udt_type).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains("Field \"d\" does not exist in user defined type ks.udt"),
+                "Unexpected error: {}",
+                err
+            );
+        }
+
+        // Serializing UDT value as a non-UDT type fails.
+        {
+            let value = udt_value(&[("a", Some(1))]);
+            assert!(serialize_to_bytes(&value, &ColumnType::Int).is_err());
+        }
+    }
+
+    #[test]
+    fn serialized_size_test() {
+        // Sizes include the 4-byte length prefix.
+        assert_eq!(8, CassCqlValue::Int(42).serialized_size());
+        assert_eq!(12, CassCqlValue::BigInt(42).serialized_size());
+        assert_eq!(7, CassCqlValue::Text("abc".to_owned()).serialized_size());
+        assert_eq!(4, CassCqlValue::Blob(Vec::new()).serialized_size());
+        assert_eq!(
+            // [int count] + 2 * [int length][int value]
+            4 + 4 + 2 * 8,
+            CassCqlValue::List {
+                data_type: None,
+                values: vec![CassCqlValue::Int(1), CassCqlValue::Int(2)],
+            }
+            .serialized_size()
+        );
+    }
 }
@@ -1,45 +1,6 @@
 #include "cassandra.h"
 #include <stdexcept>
 
-CASS_EXPORT size_t cass_aggregate_meta_argument_count(const CassAggregateMeta* aggregate_meta) {
-  throw std::runtime_error("UNIMPLEMENTED cass_aggregate_meta_argument_count\n");
-}
-CASS_EXPORT const CassDataType*
-cass_aggregate_meta_argument_type(const CassAggregateMeta* aggregate_meta, size_t index) {
-  throw std::runtime_error("UNIMPLEMENTED cass_aggregate_meta_argument_type\n");
-}
-CASS_EXPORT const CassValue*
-cass_aggregate_meta_field_by_name(const CassAggregateMeta* aggregate_meta, const char* name) {
-  throw std::runtime_error("UNIMPLEMENTED cass_aggregate_meta_field_by_name\n");
-}
-CASS_EXPORT const CassFunctionMeta*
-cass_aggregate_meta_final_func(const CassAggregateMeta* aggregate_meta) {
-  throw std::runtime_error("UNIMPLEMENTED cass_aggregate_meta_final_func\n");
-}
-CASS_EXPORT void cass_aggregate_meta_full_name(const CassAggregateMeta* aggregate_meta,
-                                               const char** full_name, size_t* full_name_length) {
-  throw std::runtime_error("UNIMPLEMENTED cass_aggregate_meta_full_name\n");
-}
-CASS_EXPORT const CassValue*
-cass_aggregate_meta_init_cond(const CassAggregateMeta* aggregate_meta) {
-  throw std::runtime_error("UNIMPLEMENTED cass_aggregate_meta_init_cond\n");
-}
-CASS_EXPORT void cass_aggregate_meta_name(const CassAggregateMeta* aggregate_meta,
-                                          const char** name, size_t* name_length) {
-  throw std::runtime_error("UNIMPLEMENTED cass_aggregate_meta_name\n");
-}
-CASS_EXPORT const CassDataType*
-cass_aggregate_meta_return_type(const CassAggregateMeta* aggregate_meta) {
-  throw std::runtime_error("UNIMPLEMENTED cass_aggregate_meta_return_type\n");
-}
-CASS_EXPORT const CassFunctionMeta*
-cass_aggregate_meta_state_func(const CassAggregateMeta* aggregate_meta) {
-  throw std::runtime_error("UNIMPLEMENTED cass_aggregate_meta_state_func\n");
-}
-CASS_EXPORT const CassDataType*
-cass_aggregate_meta_state_type(const CassAggregateMeta* aggregate_meta) {
-  throw std::runtime_error("UNIMPLEMENTED cass_aggregate_meta_state_type\n");
-}
 CASS_EXPORT void cass_authenticator_set_error(CassAuthenticator* auth, const char* message) {
   throw std::runtime_error("UNIMPLEMENTED cass_authenticator_set_error\n");
 }
@@ -106,10 +67,6 @@ CASS_EXPORT CassError cass_collection_append_custom(CassCollection* collection,
                                                     const cass_byte_t* value, size_t value_size) {
   throw std::runtime_error("UNIMPLEMENTED cass_collection_append_custom\n");
 }
-CASS_EXPORT const CassValue* cass_column_meta_field_by_name(const CassColumnMeta* column_meta,
-                                                            const char* name) {
-  throw std::runtime_error("UNIMPLEMENTED cass_column_meta_field_by_name\n");
-}
 CASS_EXPORT void cass_custom_payload_free(CassCustomPayload* payload) {
   throw std::runtime_error("UNIMPLEMENTED cass_custom_payload_free\n");
 }
@@ -133,81 +90,12 @@ CASS_EXPORT CassError cass_execution_profile_set_whitelist_filtering(CassExecPro
                                                                      const char* hosts) {
   throw std::runtime_error("UNIMPLEMENTED cass_execution_profile_set_whitelist_filtering\n");
 }
-CASS_EXPORT CassError cass_function_meta_argument(const CassFunctionMeta* function_meta,
-                                                  size_t index, const char** name,
-                                                  size_t* name_length, const CassDataType** type) {
-  throw std::runtime_error("UNIMPLEMENTED cass_function_meta_argument\n");
-}
-CASS_EXPORT size_t cass_function_meta_argument_count(const CassFunctionMeta* function_meta) {
-  throw std::runtime_error("UNIMPLEMENTED cass_function_meta_argument_count\n");
-}
-CASS_EXPORT const CassDataType*
-cass_function_meta_argument_type_by_name(const CassFunctionMeta* function_meta, const char* name) {
-  throw std::runtime_error("UNIMPLEMENTED cass_function_meta_argument_type_by_name\n");
-}
-CASS_EXPORT void cass_function_meta_body(const CassFunctionMeta* function_meta, const char** body,
-                                         size_t* body_length) {
-  throw std::runtime_error("UNIMPLEMENTED cass_function_meta_body\n");
-}
-CASS_EXPORT cass_bool_t
-cass_function_meta_called_on_null_input(const CassFunctionMeta* function_meta) {
-  throw std::runtime_error("UNIMPLEMENTED cass_function_meta_called_on_null_input\n");
-}
-CASS_EXPORT const CassValue* cass_function_meta_field_by_name(const CassFunctionMeta* function_meta,
-                                                              const char* name) {
-  throw std::runtime_error("UNIMPLEMENTED cass_function_meta_field_by_name\n");
-}
-CASS_EXPORT void cass_function_meta_full_name(const CassFunctionMeta* function_meta,
-                                              const char** full_name, size_t* full_name_length) {
-  throw std::runtime_error("UNIMPLEMENTED cass_function_meta_full_name\n");
-}
-CASS_EXPORT void cass_function_meta_language(const CassFunctionMeta* function_meta,
-                                             const char** language, size_t* language_length) {
-  throw std::runtime_error("UNIMPLEMENTED cass_function_meta_language\n");
-}
-CASS_EXPORT void cass_function_meta_name(const CassFunctionMeta* function_meta, const char** name,
-                                         size_t* name_length) {
-  throw std::runtime_error("UNIMPLEMENTED cass_function_meta_name\n");
-}
-CASS_EXPORT const CassDataType*
-cass_function_meta_return_type(const CassFunctionMeta* function_meta) {
-  throw std::runtime_error("UNIMPLEMENTED cass_function_meta_return_type\n");
-}
 CASS_EXPORT const CassNode* cass_future_coordinator(CassFuture* future) {
   throw std::runtime_error("UNIMPLEMENTED cass_future_coordinator\n");
 }
-CASS_EXPORT const CassValue* cass_index_meta_field_by_name(const CassIndexMeta* index_meta,
-                                                           const char* name) {
-  throw std::runtime_error("UNIMPLEMENTED cass_index_meta_field_by_name\n");
-}
-CASS_EXPORT const CassAggregateMeta*
-cass_keyspace_meta_aggregate_by_name(const CassKeyspaceMeta* keyspace_meta, const char* name,
-                                     const char* arguments) {
-  throw std::runtime_error("UNIMPLEMENTED cass_keyspace_meta_aggregate_by_name\n");
-}
-CASS_EXPORT const CassValue* cass_keyspace_meta_field_by_name(const CassKeyspaceMeta* keyspace_meta,
-                                                              const char* name) {
-  throw std::runtime_error("UNIMPLEMENTED cass_keyspace_meta_field_by_name\n");
-}
-CASS_EXPORT const CassFunctionMeta*
-cass_keyspace_meta_function_by_name(const CassKeyspaceMeta* keyspace_meta, const char* name,
-                                    const char* arguments) {
-  throw std::runtime_error("UNIMPLEMENTED cass_keyspace_meta_function_by_name\n");
-}
-CASS_EXPORT cass_bool_t cass_keyspace_meta_is_virtual(const CassKeyspaceMeta* keyspace_meta) {
-  throw std::runtime_error("UNIMPLEMENTED cass_keyspace_meta_is_virtual\n");
-}
-CASS_EXPORT const CassValue*
-cass_materialized_view_meta_field_by_name(const CassMaterializedViewMeta* view_meta,
-                                          const char* name) {
-  throw std::runtime_error("UNIMPLEMENTED cass_materialized_view_meta_field_by_name\n");
-}
 CASS_EXPORT CassRetryPolicy* cass_retry_policy_logging_new(CassRetryPolicy* child_retry_policy) {
   throw std::runtime_error("UNIMPLEMENTED cass_retry_policy_logging_new\n");
 }
-CASS_EXPORT CassVersion cass_schema_meta_version(const CassSchemaMeta* schema_meta) {
-  throw std::runtime_error("UNIMPLEMENTED cass_schema_meta_version\n");
-}
 CASS_EXPORT void cass_session_get_metrics(const CassSession* session, CassMetrics* output) {
   throw std::runtime_error("UNIMPLEMENTED cass_session_get_metrics\n");
 }
@@ -248,24 +136,6 @@ CASS_EXPORT CassError cass_statement_set_keyspace(CassStatement* statement, cons
 CASS_EXPORT CassError cass_statement_set_node(CassStatement* statement, const CassNode* node) {
   throw std::runtime_error("UNIMPLEMENTED cass_statement_set_node\n");
 }
-CASS_EXPORT CassClusteringOrder
-cass_table_meta_clustering_key_order(const CassTableMeta* table_meta, size_t index) {
-  throw std::runtime_error("UNIMPLEMENTED cass_table_meta_clustering_key_order\n");
-}
-CASS_EXPORT const CassValue* cass_table_meta_field_by_name(const CassTableMeta* table_meta,
-                                                           const char* name) {
-  throw std::runtime_error("UNIMPLEMENTED cass_table_meta_field_by_name\n");
-}
-CASS_EXPORT const CassIndexMeta* cass_table_meta_index_by_name(const CassTableMeta* table_meta,
-                                                               const char* index) {
-  throw std::runtime_error("UNIMPLEMENTED cass_table_meta_index_by_name\n");
-}
-CASS_EXPORT size_t cass_table_meta_index_count(const CassTableMeta* table_meta) {
-  throw std::runtime_error("UNIMPLEMENTED cass_table_meta_index_count\n");
-}
-CASS_EXPORT cass_bool_t cass_table_meta_is_virtual(const CassTableMeta* table_meta) {
-  throw std::runtime_error("UNIMPLEMENTED cass_table_meta_is_virtual\n");
-}
 CASS_EXPORT CassError cass_tuple_set_custom(CassTuple* tuple, size_t index, const char* class_name,
                                             const cass_byte_t* value, size_t value_size) {
   throw std::runtime_error("UNIMPLEMENTED cass_tuple_set_custom\n");